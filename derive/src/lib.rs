@@ -0,0 +1,347 @@
+//! `#[derive(DicomExtract)]` for the dicom-parser-rs crate.  Maps the fields
+//! of a struct to attributes so it can be filled by an ExtractHandler:
+//!
+//! ```ignore
+//! #[derive(Default, DicomExtract)]
+//! struct Study {
+//!     #[dicom(tag = "(0020,000D)")]
+//!     study_instance_uid: String,
+//!     #[dicom(keyword = "PatientWeight")]
+//!     patient_weight: Option<f64>,
+//!     #[dicom(keyword = "ReferencedSeriesSequence", sequence)]
+//!     referenced_series: Vec<ReferencedSeries>,
+//! }
+//! ```
+//!
+//! Fields are mapped with a tag in the (gggg,eeee) format or a keyword (which
+//! requires the dictionary feature of dicom-parser-rs).  Field types must
+//! implement FromValue, sequence fields are a Vec of a struct that derives
+//! DicomExtract too.  Fields without a dicom attribute are left as is.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Fields, GenericArgument, Ident, LitStr,
+    PathArguments, Type,
+};
+
+// a field mapped to an attribute
+struct MappedField {
+    ident: Ident,
+    ty: Type,
+    tag: TokenStream2,
+    // the type of the items for sequence fields
+    item: Option<Type>,
+}
+
+/// Derives the DicomExtract trait, see the crate documentation
+#[proc_macro_derive(DicomExtract, attributes(dicom))]
+pub fn derive_dicom_extract(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    input,
+                    "DicomExtract requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "DicomExtract can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut mapped_fields = vec![];
+    for field in fields {
+        if let Some(mapped_field) = parse_field(field)? {
+            mapped_fields.push(mapped_field);
+        }
+    }
+    let (values, sequences): (Vec<&MappedField>, Vec<&MappedField>) =
+        mapped_fields.iter().partition(|field| field.item.is_none());
+
+    let all_tags: Vec<&TokenStream2> = mapped_fields.iter().map(|field| &field.tag).collect();
+    let value_idents: Vec<&Ident> = values.iter().map(|field| &field.ident).collect();
+    let value_types: Vec<&Type> = values.iter().map(|field| &field.ty).collect();
+    let value_tags: Vec<&TokenStream2> = values.iter().map(|field| &field.tag).collect();
+    let sequence_idents: Vec<&Ident> = sequences.iter().map(|field| &field.ident).collect();
+    let sequence_items: Vec<&Type> = sequences
+        .iter()
+        .filter_map(|field| field.item.as_ref())
+        .collect();
+    let sequence_tags: Vec<&TokenStream2> = sequences.iter().map(|field| &field.tag).collect();
+
+    // the functions for attributes nested in sequence items
+    let (nested_is_mapped, nested_set_value, start_item) = if sequences.is_empty() {
+        (
+            quote!(Some(_) => false,),
+            quote!(Some(_) => {}),
+            quote!(let _ = sequences;),
+        )
+    } else {
+        (
+            quote! {
+                Some((sequence, sequences)) => {
+                    #(
+                        if *sequence == #sequence_tags {
+                            return <#sequence_items as ::dicomparser::extract::DicomExtract>::is_mapped(sequences, tag);
+                        }
+                    )*
+                    false
+                }
+            },
+            quote! {
+                Some((sequence, sequences)) => {
+                    #(
+                        if *sequence == #sequence_tags {
+                            if let Some(item) = self.#sequence_idents.last_mut() {
+                                return ::dicomparser::extract::DicomExtract::set_value::<E>(item, sequences, attribute, value);
+                            }
+                            return ::std::result::Result::Ok(());
+                        }
+                    )*
+                }
+            },
+            quote! {
+                if let Some((sequence, sequences)) = sequences.split_first() {
+                    #(
+                        if *sequence == #sequence_tags {
+                            if sequences.is_empty() {
+                                self.#sequence_idents.push(::std::default::Default::default());
+                            } else if let Some(item) = self.#sequence_idents.last_mut() {
+                                ::dicomparser::extract::DicomExtract::start_item(item, sequences);
+                            }
+                            return;
+                        }
+                    )*
+                }
+            },
+        )
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::dicomparser::extract::DicomExtract for #name #ty_generics #where_clause {
+            fn last_tag() -> ::std::option::Option<::dicomparser::tag::Tag> {
+                let tags: &[::dicomparser::tag::Tag] = &[#(#all_tags),*];
+                tags.iter().copied().max()
+            }
+
+            fn is_mapped(sequences: &[::dicomparser::tag::Tag], tag: ::dicomparser::tag::Tag) -> bool {
+                match sequences.split_first() {
+                    None => {
+                        let tags: &[::dicomparser::tag::Tag] = &[#(#all_tags),*];
+                        tags.contains(&tag)
+                    }
+                    #nested_is_mapped
+                }
+            }
+
+            fn set_value<E: ::dicomparser::encoding::Encoding>(
+                &mut self,
+                sequences: &[::dicomparser::tag::Tag],
+                attribute: &::dicomparser::attribute::Attribute,
+                value: &[u8],
+            ) -> ::std::result::Result<(), ::dicomparser::value::ValueError> {
+                match sequences.split_first() {
+                    None => {
+                        #(
+                            if attribute.tag == #value_tags {
+                                self.#value_idents = <#value_types as ::dicomparser::extract::FromValue>::from_value::<E>(attribute, value)?;
+                                return ::std::result::Result::Ok(());
+                            }
+                        )*
+                    }
+                    #nested_set_value
+                }
+                ::std::result::Result::Ok(())
+            }
+
+            fn start_item(&mut self, sequences: &[::dicomparser::tag::Tag]) {
+                #start_item
+            }
+        }
+    })
+}
+
+// returns the mapping of a field or None if it has no dicom attribute
+fn parse_field(field: &syn::Field) -> Result<Option<MappedField>, Error> {
+    let mut tag = None;
+    let mut sequence = false;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("dicom"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                let value: LitStr = meta.value()?.parse()?;
+                tag = Some(parse_tag(&value)?);
+            } else if meta.path.is_ident("keyword") {
+                let value: LitStr = meta.value()?.parse()?;
+                let name = constant_name(&value.value());
+                if syn::parse_str::<Ident>(&name).is_err() {
+                    return Err(Error::new(value.span(), "expected a keyword"));
+                }
+                let constant = Ident::new(&name, value.span());
+                tag = Some(quote!(::dicomparser::tags::#constant));
+            } else if meta.path.is_ident("sequence") {
+                sequence = true;
+            } else {
+                return Err(meta.error("expected tag, keyword or sequence"));
+            }
+            Ok(())
+        })?;
+    }
+    let ident = match &field.ident {
+        Some(ident) => ident.clone(),
+        None => return Ok(None),
+    };
+    let tag = match tag {
+        Some(tag) => tag,
+        None if sequence => {
+            return Err(Error::new_spanned(
+                field,
+                "sequence fields require a tag or keyword",
+            ))
+        }
+        None => return Ok(None),
+    };
+    let item = if sequence {
+        Some(vec_item(&field.ty).ok_or_else(|| {
+            Error::new_spanned(
+                &field.ty,
+                "sequence fields must be a Vec of the item struct",
+            )
+        })?)
+    } else {
+        None
+    };
+    Ok(Some(MappedField {
+        ident,
+        ty: field.ty.clone(),
+        tag,
+        item,
+    }))
+}
+
+// parses a tag in the (gggg,eeee) format to a Tag expression
+fn parse_tag(value: &LitStr) -> Result<TokenStream2, Error> {
+    match tag_numbers(&value.value()) {
+        Some((group, element)) => Ok(quote!(::dicomparser::tag::Tag::new(#group, #element))),
+        None => Err(Error::new(
+            value.span(),
+            "expected a tag in the (gggg,eeee) format",
+        )),
+    }
+}
+
+// returns the group and element of a tag in the (gggg,eeee) format, both
+// must be four hex digits
+fn tag_numbers(string: &str) -> Option<(u16, u16)> {
+    let numbers = string.strip_prefix('(')?.strip_suffix(')')?;
+    let mut numbers = numbers.split(',').map(|number| {
+        if number.len() == 4 && number.bytes().all(|b| b.is_ascii_hexdigit()) {
+            u16::from_str_radix(number, 16).ok()
+        } else {
+            None
+        }
+    });
+    match (numbers.next(), numbers.next(), numbers.next()) {
+        (Some(Some(group)), Some(Some(element)), None) => Some((group, element)),
+        _ => None,
+    }
+}
+
+// returns T for a Vec<T> type
+fn vec_item(ty: &Type) -> Option<Type> {
+    let segment = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != "Vec" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match arguments.args.first()? {
+                GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// converts a keyword to the name of its constant in dicomparser::tags (e.g.
+// SOPInstanceUID to SOP_INSTANCE_UID), see scripts/generate_dictionary.py
+fn constant_name(keyword: &str) -> String {
+    let chars: Vec<char> = keyword.chars().collect();
+    let is_upper = |index: usize| chars.get(index).is_some_and(|c| c.is_ascii_uppercase());
+    let is_lower = |index: usize| chars.get(index).is_some_and(|c| c.is_ascii_lowercase());
+    let is_s = |index: usize| chars.get(index) == Some(&'s');
+    let mut name = String::new();
+    for (index, c) in chars.iter().enumerate() {
+        // a plural acronym such as IDs is not split
+        let plural = is_s(index + 1) && (index + 2 >= chars.len() || is_upper(index + 2));
+        if c.is_ascii_uppercase()
+            && index > 0
+            && (is_lower(index - 1) || (is_upper(index - 1) && is_lower(index + 1) && !plural))
+        {
+            name.push('_');
+        }
+        name.push(c.to_ascii_uppercase());
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{constant_name, tag_numbers};
+
+    #[test]
+    fn converts_keywords_to_constant_names() {
+        assert_eq!(constant_name("SOPInstanceUID"), "SOP_INSTANCE_UID");
+        assert_eq!(constant_name("StudyInstanceUID"), "STUDY_INSTANCE_UID");
+        assert_eq!(
+            constant_name("ReferencedSOPClassUIDs"),
+            "REFERENCED_SOP_CLASS_UIDS"
+        );
+        assert_eq!(constant_name("Rows"), "ROWS");
+    }
+
+    #[test]
+    fn parses_tag_numbers() {
+        assert_eq!(tag_numbers("(0020,000D)"), Some((0x0020, 0x000D)));
+        assert_eq!(tag_numbers("(7fe0,0010)"), Some((0x7FE0, 0x0010)));
+        for string in &[
+            "(0020,000D,0010)",
+            "(0020)",
+            "(0020,000D",
+            "0020,000D",
+            "(+020,000D)",
+            "(0020,00D)",
+            "(0020,0000D)",
+            "(0020,,000D)",
+            "(002G,000D)",
+        ] {
+            assert_eq!(tag_numbers(string), None, "{}", string);
+        }
+    }
+}
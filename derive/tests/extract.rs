@@ -0,0 +1,131 @@
+use dicomparser::data_set::DataSetBuilder;
+use dicomparser::encoding::ImplicitLittleEndian;
+use dicomparser::extract::{DicomExtract, ExtractHandler};
+use dicomparser::handler::accumulate::AccumulatingHandler;
+use dicomparser::p10::parse;
+use dicomparser::tag::Tag;
+use dicomparser::value;
+use std::fs::File;
+use std::io::Read;
+
+fn read_file(filepath: &str) -> Vec<u8> {
+    let mut file = File::open(filepath).unwrap();
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer).unwrap();
+    buffer
+}
+
+fn extract<T: DicomExtract>(filepath: &str) -> (T, bool) {
+    let bytes = read_file(filepath);
+    let mut target = T::default();
+    let mut handler = ExtractHandler::new(&mut target);
+    let mut accumulating_handler = AccumulatingHandler::new(&mut handler, 1024);
+    match parse(&mut accumulating_handler, &bytes) {
+        Ok(_meta) => {}
+        Err(_error) => panic!("Let's play Global Thermonuclear War"),
+    }
+    let canceled = handler.canceled;
+    (target, canceled)
+}
+
+#[derive(Default, Debug, DicomExtract)]
+struct Image {
+    #[dicom(keyword = "SOPInstanceUID")]
+    sop_instance_uid: String,
+    #[dicom(tag = "(0008,0060)")]
+    modality: String,
+    #[dicom(keyword = "ImageType")]
+    image_type: Vec<String>,
+    #[dicom(tag = "(0028,0010)")]
+    rows: Option<u16>,
+    #[dicom(keyword = "PixelSpacing")]
+    pixel_spacing: Vec<f64>,
+    #[dicom(keyword = "SliceThickness")]
+    slice_thickness: Option<f64>,
+    // not mapped
+    comment: String,
+}
+
+#[derive(Default, Debug, DicomExtract)]
+struct UltrasoundRegion {
+    #[dicom(keyword = "RegionSpatialFormat")]
+    region_spatial_format: Option<u16>,
+    #[dicom(keyword = "RegionLocationMinX0")]
+    region_location_min_x0: Option<u32>,
+}
+
+#[derive(Default, Debug, DicomExtract)]
+struct Ultrasound {
+    #[dicom(keyword = "Modality")]
+    modality: String,
+    #[dicom(keyword = "SequenceOfUltrasoundRegions", sequence)]
+    regions: Vec<UltrasoundRegion>,
+}
+
+#[derive(Default, Debug, DicomExtract)]
+struct Unmapped {
+    // not mapped
+    comment: String,
+}
+
+#[test]
+fn extracts_values() {
+    let (image, canceled) =
+        extract::<Image>("../tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+    assert!(canceled);
+    assert_eq!(image.modality, "CT");
+    assert!(!image.sop_instance_uid.is_empty());
+    assert!(!image.sop_instance_uid.ends_with('\0'));
+    assert_eq!(image.image_type[0], "ORIGINAL");
+    assert_eq!(image.rows, Some(512));
+    assert_eq!(image.pixel_spacing.len(), 2);
+    assert!(image.slice_thickness.is_some());
+    assert!(image.comment.is_empty());
+    assert_eq!(Image::last_tag(), Some(Tag::new(0x0028, 0x0030)));
+}
+
+#[test]
+fn cancels_after_header_if_nothing_mapped() {
+    assert_eq!(Unmapped::last_tag(), None);
+    let (unmapped, canceled) =
+        extract::<Unmapped>("../tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+    assert!(canceled);
+    assert!(unmapped.comment.is_empty());
+}
+
+#[test]
+fn extracts_big_endian_values() {
+    let (image, _canceled) = extract::<Image>("../tests/fixtures/CT1_UNC.explicit_big_endian.dcm");
+    assert_eq!(image.modality, "CT");
+    assert_eq!(image.rows, Some(512));
+    assert_eq!(image.pixel_spacing.len(), 2);
+}
+
+#[test]
+fn extracts_sequences() {
+    let filepath = "../tests/fixtures/IM00001.implicit_little_endian.dcm";
+    let (ultrasound, canceled) = extract::<Ultrasound>(filepath);
+    assert!(canceled);
+    assert_eq!(ultrasound.modality, "US");
+
+    // compare with the DataSet
+    let bytes = read_file(filepath);
+    let mut builder = DataSetBuilder::default();
+    assert!(parse(&mut builder, &bytes).is_ok());
+    let data_set = builder.into_data_set();
+    let items = data_set
+        .get(Tag::new(0x0018, 0x6011))
+        .unwrap()
+        .items()
+        .unwrap();
+    assert_eq!(ultrasound.regions.len(), items.len());
+    for (region, item) in ultrasound.regions.iter().zip(items) {
+        let element = item.get(Tag::new(0x0018, 0x6012)).unwrap();
+        let format =
+            value::u16s::<ImplicitLittleEndian>(&element.attribute, element.bytes().unwrap())
+                .unwrap()
+                .next();
+        assert_eq!(region.region_spatial_format, format);
+        assert!(region.region_location_min_x0.is_some());
+    }
+}
@@ -1,88 +1,18 @@
-use dicomparser::attribute::Attribute;
-use dicomparser::handler::{Handler, HandlerResult};
+use dicomparser::extract::{DicomExtract, ExtractHandler};
+use dicomparser::handler::accumulate::AccumulatingHandler;
 use dicomparser::p10::parse;
-use dicomparser::tag::Tag;
 use std::env;
 use std::fs::File;
 use std::io::Read;
-use std::str;
 
-const STUDYINSTANCEUID: Tag = Tag {
-    group: 0x0020,
-    element: 0x000D,
-};
-
-const SERIESINSTANCEUID: Tag = Tag {
-    group: 0x0020,
-    element: 0x000E,
-};
-
-const SOPINSTANCEUID: Tag = Tag {
-    group: 0x0008,
-    element: 0x0018,
-};
-
-#[derive(Default, Debug)]
-pub struct SOPInstanceIdentificationHandler {
+#[derive(Default, Debug, DicomExtract)]
+pub struct SOPInstanceIdentification {
+    #[dicom(keyword = "StudyInstanceUID")]
     pub study_instance_uid: String,
+    #[dicom(keyword = "SeriesInstanceUID")]
     pub series_instance_uid: String,
+    #[dicom(keyword = "SOPInstanceUID")]
     pub sop_instance_uid: String,
-    // buffer to accumulate data for an attribute
-    data_buffer: Vec<u8>,
-}
-
-impl SOPInstanceIdentificationHandler {
-    fn is_tag_wanted(tag: Tag) -> bool {
-        match tag {
-            STUDYINSTANCEUID => true,
-            SERIESINSTANCEUID => true,
-            SOPINSTANCEUID => true,
-            _ => false,
-        }
-    }
-}
-
-impl Handler for SOPInstanceIdentificationHandler {
-    fn attribute(
-        &mut self,
-        attribute: &Attribute,
-        _position: usize,
-        _data_offset: usize,
-    ) -> HandlerResult {
-        if SOPInstanceIdentificationHandler::is_tag_wanted(attribute.tag) {
-            self.data_buffer.clear();
-        }
-        if attribute.tag > Tag::new(0x0020, 0x000E) {
-            HandlerResult::Cancel
-        } else {
-            HandlerResult::Continue
-        }
-    }
-
-    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) {
-        if attribute.length == 0 {
-            return;
-        }
-        if SOPInstanceIdentificationHandler::is_tag_wanted(attribute.tag) {
-            self.data_buffer.extend_from_slice(data);
-
-            if complete {
-                let bytes = if self.data_buffer[attribute.length - 1] != 0 {
-                    &self.data_buffer
-                } else {
-                    &self.data_buffer[0..(attribute.length - 1)]
-                };
-
-                if attribute.tag == STUDYINSTANCEUID {
-                    self.study_instance_uid = String::from(str::from_utf8(&bytes).unwrap());
-                } else if attribute.tag == SERIESINSTANCEUID {
-                    self.series_instance_uid = String::from(str::from_utf8(&bytes).unwrap());
-                } else if attribute.tag == SOPINSTANCEUID {
-                    self.sop_instance_uid = String::from(str::from_utf8(&bytes).unwrap());
-                }
-            }
-        }
-    }
 }
 
 pub fn read_file(filepath: &str) -> Vec<u8> {
@@ -94,14 +24,16 @@ pub fn read_file(filepath: &str) -> Vec<u8> {
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let mut bytes = if args.len() > 1 {
+    let bytes = if args.len() > 1 {
         read_file(&args[1])
     } else {
         read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm")
     };
-    let mut handler = SOPInstanceIdentificationHandler::default();
-    match parse(&mut handler, &mut bytes) {
-        Ok(_meta) => println!("{:?}", handler),
-        Err(_parse_error) => {}
+    let mut identification = SOPInstanceIdentification::default();
+    let mut handler = ExtractHandler::new(&mut identification);
+    let mut accumulating_handler = AccumulatingHandler::new(&mut handler, 64);
+    match parse(&mut accumulating_handler, &bytes) {
+        Ok(_meta) => println!("{:?}", identification),
+        Err(parse_error) => println!("{}", parse_error),
     }
 }
@@ -0,0 +1,160 @@
+#!/usr/bin/env python3
+"""Generates the data dictionary from the DocBook version of DICOM PS3.6.
+
+Usage:
+    curl -O https://dicom.nema.org/medical/dicom/current/source/docbook/part06/part06.xml
+    python3 scripts/generate_dictionary.py part06.xml
+
+Writes src/dictionary/entries.rs and src/dictionary/tags.rs, run cargo fmt
+afterwards.
+"""
+
+import os
+import re
+import sys
+import xml.etree.ElementTree as ElementTree
+
+NAMESPACE = "{http://docbook.org/ns/docbook}"
+
+# the tables of PS3.6 with data elements
+TABLES = ["table_6-1", "table_7-1", "table_8-1"]
+
+VRS = {
+    "AE", "AS", "AT", "CS", "DA", "DS", "DT", "FD", "FL", "IS", "LO", "LT",
+    "OB", "OD", "OF", "OL", "OV", "OW", "PN", "SH", "SL", "SQ", "SS", "ST",
+    "SV", "TM", "UC", "UI", "UL", "UN", "UR", "US", "UT", "UV",
+}
+
+OUTPUT_DIRECTORY = os.path.join(os.path.dirname(__file__), "..", "src", "dictionary")
+
+
+def text(cell):
+    """Returns the text of a table cell without zero width spaces"""
+    return "".join(cell.itertext()).replace("\u200b", "").strip()
+
+
+def parse_tag(tag):
+    """Returns the tag and mask for a tag such as (0008,0005) or (60xx,3000)"""
+    digits = tag.strip("()").replace(",", "")
+    value = int(re.sub("[xX]", "0", digits), 16)
+    mask = int("".join("0" if digit in "xX" else "F" for digit in digits), 16)
+    return value, mask
+
+
+def constant_name(keyword):
+    """Converts a keyword to a constant name (e.g. SOPInstanceUID to
+    SOP_INSTANCE_UID)"""
+    name = ""
+    for index, char in enumerate(keyword):
+        previous = keyword[index - 1] if index > 0 else ""
+        following = keyword[index + 1 : index + 2]
+        # a plural acronym such as IDs is not split
+        plural = keyword[index + 1 : index + 3] in ("s", "sS") or (
+            following == "s" and keyword[index + 2 : index + 3].isupper()
+        )
+        if char.isupper() and index > 0 and (
+            previous.islower() or (previous.isupper() and following.islower() and not plural)
+        ):
+            name += "_"
+        name += char.upper()
+    return name
+
+
+def read_entries(path):
+    entries = []
+    root = ElementTree.parse(path).getroot()
+    for table in root.iter(NAMESPACE + "table"):
+        if table.get("{http://www.w3.org/XML/1998/namespace}id") not in TABLES:
+            continue
+        for row in table.iter(NAMESPACE + "tr"):
+            cells = [text(cell) for cell in row.findall(NAMESPACE + "td")]
+            if len(cells) < 5 or not cells[2]:
+                continue
+            tag, mask = parse_tag(cells[0])
+            vrs = [vr for vr in re.split(" or ", cells[3]) if vr in VRS]
+            retired = len(cells) > 5 and cells[5].startswith("RET")
+            entries.append((tag, mask, cells[2], cells[1], vrs, cells[4], retired))
+    entries.sort()
+    return entries
+
+
+def rust_string(value):
+    return '"' + value.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def rust_entry(entry):
+    tag, mask, keyword, name, vrs, vm, retired = entry
+    return (
+        "    DictionaryEntry {\n"
+        "        tag: Tag {\n"
+        "            group: 0x%04X,\n"
+        "            element: 0x%04X,\n"
+        "        },\n"
+        "        mask: 0x%08X,\n"
+        "        keyword: %s,\n"
+        "        name: %s,\n"
+        "        vrs: &[%s],\n"
+        "        vm: %s,\n"
+        "        retired: %s,\n"
+        "    },\n"
+    ) % (
+        tag >> 16,
+        tag & 0xFFFF,
+        mask,
+        rust_string(keyword),
+        rust_string(name),
+        ", ".join("VR::" + vr for vr in vrs),
+        rust_string(vm),
+        "true" if retired else "false",
+    )
+
+
+def write_entries(entries, source):
+    exact = [entry for entry in entries if entry[1] == 0xFFFFFFFF]
+    repeating = [entry for entry in entries if entry[1] != 0xFFFFFFFF]
+    # indexes into ENTRIES followed by indexes into REPEATING_ENTRIES
+    keywords = [(entry[2], index) for index, entry in enumerate(exact)]
+    keywords += [(entry[2], len(exact) + index) for index, entry in enumerate(repeating)]
+    keywords.sort()
+
+    with open(os.path.join(OUTPUT_DIRECTORY, "entries.rs"), "w") as file:
+        file.write("// Generated by scripts/generate_dictionary.py from %s, do not edit\n" % source)
+        file.write("use crate::dictionary::DictionaryEntry;\n")
+        file.write("use crate::tag::Tag;\n")
+        file.write("use crate::vr::VR;\n\n")
+        file.write("/// The data elements with a single tag ordered by tag\n")
+        file.write("pub static ENTRIES: &[DictionaryEntry] = &[\n")
+        file.writelines(rust_entry(entry) for entry in exact)
+        file.write("];\n\n")
+        file.write("/// The data elements in repeating groups such as (60xx,3000)\n")
+        file.write("pub static REPEATING_ENTRIES: &[DictionaryEntry] = &[\n")
+        file.writelines(rust_entry(entry) for entry in repeating)
+        file.write("];\n\n")
+        file.write("// ENTRIES followed by REPEATING_ENTRIES ordered by keyword\n")
+        file.write("pub(crate) static KEYWORD_INDEX: &[u16] = &[\n")
+        file.writelines("    %d,\n" % index for _keyword, index in keywords)
+        file.write("];\n")
+
+    with open(os.path.join(OUTPUT_DIRECTORY, "tags.rs"), "w") as file:
+        file.write("// Generated by scripts/generate_dictionary.py from %s, do not edit\n" % source)
+        file.write("//! Tag constants named after the keywords of the data dictionary.  Data\n")
+        file.write("//! elements in repeating groups use the first group (e.g. 6000 for 60xx).\n\n")
+        file.write("use crate::tag::Tag;\n")
+        for entry in exact + repeating:
+            tag, _mask, keyword, name, _vrs, _vm, retired = entry
+            file.write("\n/// %s%s\n" % (name, " (retired)" if retired else ""))
+            file.write(
+                "pub const %s: Tag = Tag::new(0x%04X, 0x%04X);\n"
+                % (constant_name(keyword), tag >> 16, tag & 0xFFFF)
+            )
+
+
+def main():
+    if len(sys.argv) != 2:
+        print(__doc__)
+        sys.exit(1)
+    write_entries(read_entries(sys.argv[1]), os.path.basename(sys.argv[1]))
+
+
+if __name__ == "__main__":
+    main()
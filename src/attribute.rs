@@ -1,9 +1,12 @@
 use crate::tag::Tag;
 use crate::vr::VR;
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub struct Attribute {
     pub tag: Tag,
     pub vr: Option<VR>,
     pub length: usize,
+    /// True if the VR was not encoded in the DataSet but looked up in the
+    /// data dictionary (see ImplicitLittleEndianDictionary)
+    pub vr_inferred: bool,
 }
@@ -17,8 +17,16 @@ use crate::vr::VR;
 pub struct AttributeParser<T: Encoding> {
     attribute: Attribute,
     parser: Option<Box<dyn ValueParser<T>>>,
+    // true if the handler skipped the value field
+    skip: bool,
 }
 
+// Handler used in place of the caller's Handler when the value field is
+// skipped so no functions are invoked for it
+struct SkipHandler {}
+
+impl Handler for SkipHandler {}
+
 impl<T: 'static + Encoding> AttributeParser<T> {
     pub fn parse(
         &mut self,
@@ -26,6 +34,7 @@ impl<T: 'static + Encoding> AttributeParser<T> {
         bytes: &[u8],
         bytes_from_beginning: usize,
     ) -> Result<ParseResult, ParseError> {
+        let mut skip_handler = SkipHandler {};
         match &mut self.parser {
             None => {
                 // try to parse the attribute
@@ -43,10 +52,16 @@ impl<T: 'static + Encoding> AttributeParser<T> {
                 // cancels it
                 match handler.attribute(&self.attribute, bytes_from_beginning, bytes_consumed) {
                     HandlerResult::Continue => {}
+                    HandlerResult::Skip => self.skip = true,
                     HandlerResult::Cancel => {
                         return Ok(ParseResult::cancelled(0));
                     }
                 }
+                let handler: &mut dyn Handler = if self.skip {
+                    &mut skip_handler
+                } else {
+                    handler
+                };
 
                 // update internal state
                 let data_position = bytes_from_beginning + bytes_consumed;
@@ -61,8 +76,13 @@ impl<T: 'static + Encoding> AttributeParser<T> {
                     &remaining_bytes[0..attribute.length]
                 };
 
-                // create the appropriate value parser for this attribute.
-                self.parser = Some(make_parser::<T>(handler, &attribute, value_bytes));
+                // create the appropriate value parser for this attribute.  Skipped
+                // values with a known length are skipped without parsing them
+                self.parser = Some(if self.skip && attribute.length != 0xFFFF_FFFF {
+                    Box::new(DataParser::<T>::default())
+                } else {
+                    make_parser::<T>(handler, &attribute, value_bytes)
+                });
 
                 // parse the value bytes
                 let mut parse_result = self.parser.as_mut().unwrap().parse(
@@ -77,7 +97,14 @@ impl<T: 'static + Encoding> AttributeParser<T> {
                 parse_result.bytes_consumed += bytes_consumed;
                 Ok(parse_result)
             }
-            Some(parser) => parser.parse(handler, &self.attribute, bytes, bytes_from_beginning),
+            Some(parser) => {
+                let handler: &mut dyn Handler = if self.skip {
+                    &mut skip_handler
+                } else {
+                    handler
+                };
+                parser.parse(handler, &self.attribute, bytes, bytes_from_beginning)
+            }
         }
     }
 }
@@ -120,7 +147,7 @@ fn parse_attribute<T: Encoding>(bytes: &[u8]) -> Result<(usize, Attribute), ()>
         let length = T::u32(&bytes[4..8]) as usize;
         (None, length, 4)
     } else {
-        T::vr_and_length(&bytes)?
+        T::vr_and_length(bytes)?
     };
 
     // if we have undefined length, check to make sure we have an additional 8 bytes
@@ -134,6 +161,7 @@ fn parse_attribute<T: Encoding>(bytes: &[u8]) -> Result<(usize, Attribute), ()>
         tag: Tag::new(group, element),
         vr,
         length,
+        vr_inferred: vr.is_some() && T::vr_is_inferred(),
     };
     Ok((bytes_consumed, attribute))
 }
@@ -155,23 +183,25 @@ fn is_sequence<T: Encoding>(bytes: &[u8]) -> bool {
 mod tests {
 
     use super::AttributeParser;
+    use crate::attribute::Attribute;
     use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::{Handler, HandlerResult, SequenceItem};
     use crate::test::tests::TestHandler;
     use crate::value_parser::ParseState;
 
     fn make_encapsulated_pixel_data_empty_bot() -> Vec<u8> {
         let mut bytes = vec![];
         // Tag/VR/Length
-        bytes.extend_from_slice(&vec![
+        bytes.extend_from_slice(&[
             0xE0, 0x7F, 0x10, 0x00, b'O', b'B', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF,
         ]);
         // Basic Offset Table (Empty)
-        bytes.extend_from_slice(&vec![0xFE, 0xFF, 0x00, 0xE0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 0, 0, 0, 0]);
         // Fragment #1 (250 zeros)
-        bytes.extend_from_slice(&vec![0xFE, 0xFF, 0x00, 0xE0, 250, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 250, 0, 0, 0]);
         bytes.extend_from_slice(&vec![0; 250]);
         // end with sequence item delimeter
-        bytes.extend_from_slice(&vec![0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
 
         bytes
     }
@@ -188,7 +218,7 @@ mod tests {
                 assert_eq!(result.state, ParseState::Completed);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
             }
         };
     }
@@ -206,7 +236,7 @@ mod tests {
                 assert_eq!(result.state, ParseState::Incomplete);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
             }
         };
         match parser.parse(&mut handler, &bytes[100..], 0) {
@@ -215,8 +245,113 @@ mod tests {
                 assert_eq!(result.state, ParseState::Completed);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
+            }
+        };
+    }
+
+    // Handler that skips every attribute and counts all other calls
+    #[derive(Default)]
+    struct SkipAllHandler {
+        attribute_count: usize,
+        other_count: usize,
+    }
+
+    impl Handler for SkipAllHandler {
+        fn attribute(
+            &mut self,
+            _attribute: &Attribute,
+            _position: usize,
+            _data_offset: usize,
+        ) -> HandlerResult {
+            self.attribute_count += 1;
+            HandlerResult::Skip
+        }
+        fn data(&mut self, _attribute: &Attribute, _data: &[u8], _complete: bool) -> HandlerResult {
+            self.other_count += 1;
+            HandlerResult::Continue
+        }
+        fn start_sequence(&mut self, _attribute: &Attribute) {
+            self.other_count += 1;
+        }
+        fn start_sequence_item(&mut self, _attribute: &Attribute, _item: &SequenceItem) {
+            self.other_count += 1;
+        }
+        fn basic_offset_table(
+            &mut self,
+            _attribute: &Attribute,
+            _data: &[u8],
+            _complete: bool,
+        ) -> HandlerResult {
+            self.other_count += 1;
+            HandlerResult::Continue
+        }
+        fn pixel_data_fragment(
+            &mut self,
+            _attribute: &Attribute,
+            _fragment_number: usize,
+            _data: &[u8],
+            _complete: bool,
+        ) -> HandlerResult {
+            self.other_count += 1;
+            HandlerResult::Continue
+        }
+    }
+
+    fn make_undefined_length_sequence() -> Vec<u8> {
+        let mut bytes = vec![];
+        // Tag/VR/Length
+        bytes.extend_from_slice(&[
+            0x08, 0x00, 0x40, 0x11, b'S', b'Q', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF,
+        ]);
+        // Item with undefined length containing one attribute
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF]);
+        bytes.extend_from_slice(&[0x08, 0x00, 0x50, 0x11, b'U', b'I', 2, 0, b'1', 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0]);
+        // end with sequence delimitation item
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+
+        bytes
+    }
+
+    #[test]
+    fn skipped_encapsulated_pixel_data_completes() {
+        let mut parser = AttributeParser::<ExplicitLittleEndian>::default();
+        let mut handler = SkipAllHandler::default();
+        let bytes = make_encapsulated_pixel_data_empty_bot();
+
+        match parser.parse(&mut handler, &bytes[..], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, bytes.len());
+                assert_eq!(result.state, ParseState::Completed);
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+        assert_eq!(handler.attribute_count, 1);
+        assert_eq!(handler.other_count, 0);
+    }
+
+    #[test]
+    fn skipped_undefined_length_sequence_streaming_completes() {
+        let mut parser = AttributeParser::<ExplicitLittleEndian>::default();
+        let mut handler = SkipAllHandler::default();
+        let bytes = make_undefined_length_sequence();
+
+        let bytes_consumed = match parser.parse(&mut handler, &bytes[0..25], 0) {
+            Ok(result) => {
+                assert_eq!(result.state, ParseState::Incomplete);
+                result.bytes_consumed
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+        match parser.parse(&mut handler, &bytes[bytes_consumed..], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, bytes.len() - bytes_consumed);
+                assert_eq!(result.state, ParseState::Completed);
             }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
         };
+        assert_eq!(handler.attribute_count, 1);
+        assert_eq!(handler.other_count, 0);
     }
 }
@@ -0,0 +1,443 @@
+use crate::attribute::Attribute;
+use crate::handler::{Handler, HandlerResult, SequenceItem};
+use crate::tag::Tag;
+use crate::tag_path::TagPath;
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+
+/// An Item in a sequence is a nested DataSet
+pub type Item = DataSet;
+
+/// The value field of an Element
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// The bytes of the value field
+    Bytes(Vec<u8>),
+    /// The items of a sequence
+    Sequence(Vec<Item>),
+    /// Encapsulated pixel data
+    Fragments {
+        /// The basic offset table (empty if not present)
+        basic_offset_table: Vec<u8>,
+        /// The pixel data fragments
+        fragments: Vec<Vec<u8>>,
+    },
+}
+
+/// An Element is an Attribute with its value field
+#[derive(Debug, Clone, PartialEq)]
+pub struct Element {
+    /// The attribute (Tag, VR, Length)
+    pub attribute: Attribute,
+    /// The offset from the beginning of the stream of the attribute
+    pub position: usize,
+    /// The value field
+    pub value: Value,
+}
+
+impl Element {
+    /// Returns the Tag of the Element
+    pub fn tag(&self) -> Tag {
+        self.attribute.tag
+    }
+
+    /// Returns the bytes of the value field or None if the Element is a
+    /// sequence or encapsulated pixel data
+    pub fn bytes(&self) -> Option<&[u8]> {
+        match &self.value {
+            Value::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Returns the items or None if the Element is not a sequence
+    pub fn items(&self) -> Option<&[Item]> {
+        match &self.value {
+            Value::Sequence(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns the pixel data fragments or None if the Element is not
+    /// encapsulated pixel data
+    pub fn fragments(&self) -> Option<&[Vec<u8>]> {
+        match &self.value {
+            Value::Fragments { fragments, .. } => Some(fragments),
+            _ => None,
+        }
+    }
+}
+
+/// In memory DICOM DataSet.  Elements are ordered by Tag.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DataSet {
+    elements: BTreeMap<Tag, Element>,
+}
+
+impl DataSet {
+    /// Returns the Element with the Tag or None if it is not present
+    pub fn get(&self, tag: Tag) -> Option<&Element> {
+        self.elements.get(&tag)
+    }
+
+    /// Returns the Element with the Tag for modification
+    pub fn get_mut(&mut self, tag: Tag) -> Option<&mut Element> {
+        self.elements.get_mut(&tag)
+    }
+
+    /// Adds an Element replacing (and returning) any Element with the same Tag
+    pub fn insert(&mut self, element: Element) -> Option<Element> {
+        self.elements.insert(element.tag(), element)
+    }
+
+    /// Returns true if an Element with the Tag is present
+    pub fn contains(&self, tag: Tag) -> bool {
+        self.elements.contains_key(&tag)
+    }
+
+    /// Returns the number of Elements
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns true if there are no Elements
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns an iterator over the Elements in Tag order
+    pub fn iter(&self) -> btree_map::Values<'_, Tag, Element> {
+        self.elements.values()
+    }
+
+    /// Returns the Elements selected by the TagPath including those nested
+    /// in sequence items
+    pub fn select(&self, tag_path: &TagPath) -> Vec<&Element> {
+        let mut data_sets = vec![self];
+        for path_item in &tag_path.items {
+            data_sets = data_sets
+                .iter()
+                .filter_map(|data_set| data_set.get(path_item.tag)?.items())
+                .flat_map(|items| {
+                    items
+                        .iter()
+                        .enumerate()
+                        .filter(|(index, _item)| path_item.item.matches(*index))
+                        .map(|(_index, item)| item)
+                })
+                .collect();
+        }
+        data_sets
+            .iter()
+            .filter_map(|data_set| data_set.get(tag_path.tag))
+            .collect()
+    }
+}
+
+impl<'a> IntoIterator for &'a DataSet {
+    type Item = &'a Element;
+    type IntoIter = btree_map::Values<'a, Tag, Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Handler that builds a DataSet from the functions invoked during a parse.
+/// Streaming is supported as the value fields are accumulated until complete
+pub struct DataSetBuilder {
+    // the DataSets being built, the first is the root DataSet and the others
+    // are the sequence items currently being parsed
+    data_sets: Vec<DataSet>,
+    // the tags of the sequences currently being parsed
+    sequence_tags: Vec<Tag>,
+    // the tag of the current attribute in the last DataSet
+    tag: Tag,
+}
+
+impl Default for DataSetBuilder {
+    fn default() -> DataSetBuilder {
+        DataSetBuilder {
+            data_sets: vec![DataSet::default()],
+            sequence_tags: vec![],
+            tag: Tag::default(),
+        }
+    }
+}
+
+impl DataSetBuilder {
+    /// Returns the root DataSet built so far
+    pub fn data_set(&self) -> &DataSet {
+        &self.data_sets[0]
+    }
+
+    /// Returns the root DataSet
+    pub fn into_data_set(mut self) -> DataSet {
+        self.data_sets.swap_remove(0)
+    }
+
+    // returns the value of the current attribute
+    fn value(&mut self) -> Option<&mut Value> {
+        let tag = self.tag;
+        let data_set = self.data_sets.last_mut()?;
+        data_set.get_mut(tag).map(|element| &mut element.value)
+    }
+
+    // returns the value of the current attribute converted to Fragments
+    fn fragments(&mut self) -> Option<(&mut Vec<u8>, &mut Vec<Vec<u8>>)> {
+        let value = self.value()?;
+        if let Value::Bytes(_) = value {
+            *value = Value::Fragments {
+                basic_offset_table: vec![],
+                fragments: vec![],
+            };
+        }
+        match value {
+            Value::Fragments {
+                basic_offset_table,
+                fragments,
+            } => Some((basic_offset_table, fragments)),
+            _ => None,
+        }
+    }
+}
+
+impl Handler for DataSetBuilder {
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        position: usize,
+        _data_offset: usize,
+    ) -> HandlerResult {
+        self.tag = attribute.tag;
+        if let Some(data_set) = self.data_sets.last_mut() {
+            data_set.insert(Element {
+                attribute: *attribute,
+                position,
+                value: Value::Bytes(vec![]),
+            });
+        }
+        HandlerResult::Continue
+    }
+
+    fn data(&mut self, _attribute: &Attribute, data: &[u8], _complete: bool) -> HandlerResult {
+        if let Some(Value::Bytes(bytes)) = self.value() {
+            bytes.extend_from_slice(data);
+        }
+        HandlerResult::Continue
+    }
+
+    fn start_sequence(&mut self, attribute: &Attribute) {
+        if let Some(value) = self.value() {
+            *value = Value::Sequence(vec![]);
+        }
+        self.sequence_tags.push(attribute.tag);
+    }
+
+    fn start_sequence_item(&mut self, _attribute: &Attribute, _item: &SequenceItem) {
+        self.data_sets.push(DataSet::default());
+    }
+
+    fn end_sequence_item(&mut self, _attribute: &Attribute) {
+        // add the item to the sequence in the parent DataSet
+        if self.data_sets.len() < 2 {
+            return;
+        }
+        let item = self.data_sets.pop().unwrap();
+        if let Some(tag) = self.sequence_tags.last() {
+            self.tag = *tag;
+        }
+        if let Some(Value::Sequence(items)) = self.value() {
+            items.push(item);
+        }
+    }
+
+    fn end_sequence(&mut self, _attribute: &Attribute) {
+        self.sequence_tags.pop();
+    }
+
+    fn basic_offset_table(
+        &mut self,
+        _attribute: &Attribute,
+        data: &[u8],
+        _complete: bool,
+    ) -> HandlerResult {
+        if let Some((basic_offset_table, _fragments)) = self.fragments() {
+            basic_offset_table.extend_from_slice(data);
+        }
+        HandlerResult::Continue
+    }
+
+    fn pixel_data_fragment(
+        &mut self,
+        _attribute: &Attribute,
+        fragment_number: usize,
+        data: &[u8],
+        _complete: bool,
+    ) -> HandlerResult {
+        if let Some((_basic_offset_table, fragments)) = self.fragments() {
+            // fragment numbers start at 1 (the basic offset table is 0)
+            if fragments.len() < fragment_number {
+                fragments.push(vec![]);
+            }
+            if let Some(fragment) = fragments.last_mut() {
+                fragment.extend_from_slice(data);
+            }
+        }
+        HandlerResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DataSet, DataSetBuilder, Value};
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::{Encoding, ExplicitLittleEndian, ImplicitLittleEndian};
+    use crate::tag::Tag;
+    use crate::tag_path::{ItemSelector, TagPath, TagPathItem};
+    use crate::test::tests::read_data_set_bytes_from_file;
+    use crate::test::tests::TestHandler;
+
+    fn build<T: 'static + Encoding>(filepath: &str, chunk_size: usize) -> DataSet {
+        let (meta, bytes) = read_data_set_bytes_from_file(filepath);
+        let mut builder = DataSetBuilder::default();
+        let mut parser = DataSetParser::<T>::default();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let end = std::cmp::min(offset + chunk_size, bytes.len());
+            match parser.parse(
+                &mut builder,
+                &bytes[offset..end],
+                meta.end_position + offset,
+            ) {
+                Ok(result) => offset += result.bytes_consumed,
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+            if end == bytes.len() && offset < bytes.len() {
+                // not enough bytes for the parser to make progress
+                break;
+            }
+        }
+        assert_eq!(offset, bytes.len());
+        builder.into_data_set()
+    }
+
+    #[test]
+    fn builds_data_set() {
+        let data_set = build::<ExplicitLittleEndian>(
+            "tests/fixtures/CT1_UNC.explicit_little_endian.dcm",
+            usize::MAX / 2,
+        );
+        assert_eq!(data_set.len(), 257);
+        let patient_name = data_set.get(Tag::new(0x0010, 0x0010)).unwrap();
+        assert_eq!(patient_name.bytes().unwrap(), b"CompressedSamples^CT1 ");
+        assert!(data_set.contains(Tag::new(0x7fe0, 0x0010)));
+        assert!(data_set.get(Tag::new(0x0010, 0x0011)).is_none());
+    }
+
+    #[test]
+    fn iterates_in_tag_order() {
+        let data_set = build::<ExplicitLittleEndian>(
+            "tests/fixtures/CT1_UNC.explicit_little_endian.dcm",
+            usize::MAX / 2,
+        );
+        let tags: Vec<Tag> = data_set.iter().map(|element| element.tag()).collect();
+        let mut sorted_tags = tags.clone();
+        sorted_tags.sort();
+        assert_eq!(tags, sorted_tags);
+        assert_eq!((&data_set).into_iter().count(), data_set.len());
+    }
+
+    #[test]
+    fn streaming_build_matches_full_build() {
+        let full = build::<ExplicitLittleEndian>(
+            "tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm",
+            usize::MAX / 2,
+        );
+        let streamed = build::<ExplicitLittleEndian>(
+            "tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm",
+            97,
+        );
+        assert_eq!(full, streamed);
+    }
+
+    #[test]
+    fn builds_nested_sequences() {
+        let data_set = build::<ImplicitLittleEndian>(
+            "tests/fixtures/IM00001.implicit_little_endian.dcm",
+            usize::MAX / 2,
+        );
+        let sequence = data_set.get(Tag::new(0x0018, 0x6011)).unwrap();
+        let items = sequence.items().unwrap();
+        assert!(!items.is_empty());
+        assert!(!items[0].is_empty());
+        // nested elements are not in the root DataSet
+        let nested_tag = items[0].iter().next().unwrap().tag();
+        assert!(!data_set.contains(nested_tag));
+    }
+
+    #[test]
+    fn selects_nested_elements() {
+        let data_set = build::<ImplicitLittleEndian>(
+            "tests/fixtures/IM00001.implicit_little_endian.dcm",
+            usize::MAX / 2,
+        );
+        let items = data_set
+            .get(Tag::new(0x0018, 0x6011))
+            .unwrap()
+            .items()
+            .unwrap();
+        let nested_tag = items[0].iter().next().unwrap().tag();
+        let tag_path = TagPath {
+            items: vec![TagPathItem {
+                tag: Tag::new(0x0018, 0x6011),
+                item: ItemSelector::All,
+            }],
+            tag: nested_tag,
+        };
+        let elements = data_set.select(&tag_path);
+        assert_eq!(
+            elements.len(),
+            items
+                .iter()
+                .filter(|item| item.contains(nested_tag))
+                .count()
+        );
+        assert_eq!(elements[0], items[0].get(nested_tag).unwrap());
+
+        let tag_path = TagPath::parse("(0018,6011)[0].(0018,6011)[*].(0018,6012)").unwrap();
+        assert!(data_set.select(&tag_path).is_empty());
+        let tag_path = TagPath::parse("(0008,0060)").unwrap();
+        assert_eq!(data_set.select(&tag_path).len(), 1);
+    }
+
+    #[test]
+    fn builds_encapsulated_pixel_data() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
+        let mut handler = TestHandler::default();
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        assert!(parser
+            .parse(&mut handler, &bytes, meta.end_position)
+            .is_ok());
+
+        let data_set = build::<ExplicitLittleEndian>(
+            "tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm",
+            usize::MAX / 2,
+        );
+        let pixel_data = data_set.get(Tag::new(0x7fe0, 0x0010)).unwrap();
+        match &pixel_data.value {
+            Value::Fragments {
+                basic_offset_table,
+                fragments,
+            } => {
+                assert!(basic_offset_table.is_empty());
+                assert!(!fragments.is_empty());
+                // the TestHandler collects the fragments after the other data
+                let fragment_data = &handler.data[handler.data.len() - fragments.len()..];
+                assert_eq!(fragments.as_slice(), fragment_data);
+            }
+            _ => panic!("Let's play Global Thermonuclear War"),
+        }
+    }
+}
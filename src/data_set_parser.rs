@@ -1,25 +1,51 @@
 use crate::attribute_parser::AttributeParser;
+use crate::deflate::DeflatedDataSetParser;
+use crate::encoding::DynEncoding;
 use crate::encoding::Encoding;
+use crate::encoding::ExplicitBigEndian;
+use crate::encoding::ExplicitLittleEndian;
+use crate::encoding::ImplicitLittleEndian;
+#[cfg(feature = "dictionary")]
+use crate::encoding::ImplicitLittleEndianDictionary;
 use crate::handler::Handler;
 use crate::value_parser::ParseError;
+use crate::value_parser::ParseErrorKind;
 use crate::value_parser::ParseResult;
 use crate::value_parser::ParseState;
 
 pub struct DataSetParser<T: Encoding> {
     parser: AttributeParser<T>,
     total_bytes_consumed: usize,
+    // true if the last parse was cancelled
+    cancelled: bool,
 }
 
-impl<T: 'static + Encoding> DataSetParser<T> {
-    pub fn default() -> DataSetParser<T> {
+impl<T: 'static + Encoding> Default for DataSetParser<T> {
+    fn default() -> DataSetParser<T> {
         DataSetParser {
             parser: AttributeParser::<T>::default(),
             total_bytes_consumed: 0,
+            cancelled: false,
         }
     }
 }
 
 impl<T: 'static + Encoding> DataSetParser<T> {
+    /// Parses the bytes of a DataSet invoking the Handler for each attribute
+    /// found.  The bytes not consumed must be provided again on the next call.
+    ///
+    /// A Cancelled parse can be resumed by calling parse() again with the
+    /// bytes that were not consumed.  If the Handler cancelled from
+    /// attribute(), the attribute that triggered the cancel is delivered
+    /// again.  If it cancelled from data(), basic_offset_table() or
+    /// pixel_data_fragment(), the parse continues with the rest of the
+    /// value field.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler`              - The Handler to invoke when parsing the DataSet
+    /// * `bytes`                - The next bytes of the DataSet
+    /// * `bytes_from_beginning` - The position of the first byte in bytes
     pub fn parse(
         &mut self,
         handler: &mut dyn Handler,
@@ -30,8 +56,14 @@ impl<T: 'static + Encoding> DataSetParser<T> {
         let mut remaining_bytes = bytes;
         let mut bytes_consumed = 0;
 
+        // a resumed attribute is parsed even if there are no bytes so any
+        // events that follow its cancelled value (e.g. end_sequence) occur
+        let mut resume = std::mem::take(&mut self.cancelled);
+
         // iterate over remaining bytes until empty
-        while !remaining_bytes.is_empty() {
+        while !remaining_bytes.is_empty() || resume {
+            resume = false;
+
             // initialize position
             let position = bytes_from_beginning + bytes_consumed;
 
@@ -46,6 +78,7 @@ impl<T: 'static + Encoding> DataSetParser<T> {
             // handle the parse result state
             match result.state {
                 ParseState::Cancelled => {
+                    self.cancelled = true;
                     return Ok(ParseResult::cancelled(bytes_consumed));
                 }
                 ParseState::Incomplete => {
@@ -63,6 +96,102 @@ impl<T: 'static + Encoding> DataSetParser<T> {
     }
 }
 
+/// DataSetParser with the Encoding selected at runtime rather than with a
+/// generic parameter.  Dispatches to the DataSetParser<T> for the selected
+/// Encoding (or the DeflatedDataSetParser) so callers can hold a single parser
+/// type regardless of the transfer syntax
+pub enum DynDataSetParser {
+    ImplicitLittleEndian(DataSetParser<ImplicitLittleEndian>),
+    ExplicitLittleEndian(DataSetParser<ExplicitLittleEndian>),
+    ExplicitBigEndian(DataSetParser<ExplicitBigEndian>),
+    DeflatedExplicitLittleEndian(DeflatedDataSetParser),
+    #[cfg(feature = "dictionary")]
+    ImplicitLittleEndianDictionary(DataSetParser<ImplicitLittleEndianDictionary>),
+}
+
+impl DynDataSetParser {
+    /// Creates a DynDataSetParser for the given encoding
+    pub fn new(encoding: DynEncoding) -> DynDataSetParser {
+        match encoding {
+            DynEncoding::ImplicitLittleEndian => {
+                DynDataSetParser::ImplicitLittleEndian(DataSetParser::default())
+            }
+            DynEncoding::ExplicitLittleEndian => {
+                DynDataSetParser::ExplicitLittleEndian(DataSetParser::default())
+            }
+            DynEncoding::ExplicitBigEndian => {
+                DynDataSetParser::ExplicitBigEndian(DataSetParser::default())
+            }
+            DynEncoding::DeflatedExplicitLittleEndian => {
+                DynDataSetParser::DeflatedExplicitLittleEndian(DeflatedDataSetParser::default())
+            }
+            #[cfg(feature = "dictionary")]
+            DynEncoding::ImplicitLittleEndianDictionary => {
+                DynDataSetParser::ImplicitLittleEndianDictionary(DataSetParser::default())
+            }
+        }
+    }
+
+    /// Creates a DynDataSetParser for the encoding of a transfer syntax UID
+    pub fn from_transfer_syntax_uid(transfer_syntax_uid: &str) -> DynDataSetParser {
+        DynDataSetParser::new(DynEncoding::from_transfer_syntax_uid(transfer_syntax_uid))
+    }
+
+    /// Creates a DynDataSetParser for the encoding of a transfer syntax UID
+    /// looking up the VRs of implicit little endian attributes in the data
+    /// dictionary
+    #[cfg(feature = "dictionary")]
+    pub fn from_transfer_syntax_uid_with_dictionary(transfer_syntax_uid: &str) -> DynDataSetParser {
+        DynDataSetParser::new(DynEncoding::from_transfer_syntax_uid_with_dictionary(
+            transfer_syntax_uid,
+        ))
+    }
+
+    /// Returns the encoding this parser was created with
+    pub fn encoding(&self) -> DynEncoding {
+        match self {
+            DynDataSetParser::ImplicitLittleEndian(_) => DynEncoding::ImplicitLittleEndian,
+            DynDataSetParser::ExplicitLittleEndian(_) => DynEncoding::ExplicitLittleEndian,
+            DynDataSetParser::ExplicitBigEndian(_) => DynEncoding::ExplicitBigEndian,
+            DynDataSetParser::DeflatedExplicitLittleEndian(_) => {
+                DynEncoding::DeflatedExplicitLittleEndian
+            }
+            #[cfg(feature = "dictionary")]
+            DynDataSetParser::ImplicitLittleEndianDictionary(_) => {
+                DynEncoding::ImplicitLittleEndianDictionary
+            }
+        }
+    }
+
+    /// Parses the bytes with the DataSetParser for the selected encoding.  See
+    /// DataSetParser::parse() and DeflatedDataSetParser::parse()
+    pub fn parse(
+        &mut self,
+        handler: &mut dyn Handler,
+        bytes: &[u8],
+        bytes_from_beginning: usize,
+    ) -> Result<ParseResult, ParseError> {
+        match self {
+            DynDataSetParser::ImplicitLittleEndian(parser) => {
+                parser.parse(handler, bytes, bytes_from_beginning)
+            }
+            DynDataSetParser::ExplicitLittleEndian(parser) => {
+                parser.parse(handler, bytes, bytes_from_beginning)
+            }
+            DynDataSetParser::ExplicitBigEndian(parser) => {
+                parser.parse(handler, bytes, bytes_from_beginning)
+            }
+            DynDataSetParser::DeflatedExplicitLittleEndian(parser) => {
+                parser.parse(handler, bytes, bytes_from_beginning)
+            }
+            #[cfg(feature = "dictionary")]
+            DynDataSetParser::ImplicitLittleEndianDictionary(parser) => {
+                parser.parse(handler, bytes, bytes_from_beginning)
+            }
+        }
+    }
+}
+
 // returns
 //  number of bytes parsed
 //  true if cancelled, false, if not
@@ -75,10 +204,10 @@ pub fn parse_full<T: 'static + Encoding>(
     match parser.parse(handler, bytes, bytes_from_beginning) {
         Ok(parse_result) => match parse_result.state {
             ParseState::Cancelled => Ok((parse_result.bytes_consumed, true)),
-            ParseState::Incomplete => Err(ParseError {
-                reason: "unexpected EOF",
-                position: parse_result.bytes_consumed + bytes_from_beginning,
-            }),
+            ParseState::Incomplete => Err(ParseError::new(
+                ParseErrorKind::UnexpectedEOF,
+                parse_result.bytes_consumed + bytes_from_beginning,
+            )),
             ParseState::Completed => Ok((parse_result.bytes_consumed, false)),
         },
         Err(parse_error) => Err(parse_error),
@@ -89,8 +218,13 @@ pub fn parse_full<T: 'static + Encoding>(
 mod tests {
 
     use super::DataSetParser;
-    use crate::encoding::{ExplicitLittleEndian, ImplicitLittleEndian};
+    use super::DynDataSetParser;
+    #[cfg(feature = "dictionary")]
+    use crate::dictionary::lookup;
+    use crate::encoding::Encoding;
+    use crate::encoding::{DynEncoding, ExplicitLittleEndian, ImplicitLittleEndian};
     use crate::test::tests::read_data_set_bytes_from_file;
+    use crate::test::tests::ResumeHandler;
     use crate::test::tests::TestHandler;
     use crate::value_parser::ParseError;
     use crate::value_parser::ParseState;
@@ -184,4 +318,142 @@ mod tests {
         }
         assert_eq!(94, handler.attributes.len());
     }
+
+    #[test]
+    fn dyn_data_set_parser_from_transfer_syntax_uid() {
+        let parser = DynDataSetParser::from_transfer_syntax_uid("1.2.840.10008.1.2");
+        assert_eq!(parser.encoding(), DynEncoding::ImplicitLittleEndian);
+    }
+
+    #[test]
+    fn dyn_data_set_parser_parses_implicit_little_endian() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.implicit_little_endian.dcm");
+        let mut handler = TestHandler::default();
+        let mut parser = DynDataSetParser::from_transfer_syntax_uid(&meta.transfer_syntax_uid);
+        match parser.parse(&mut handler, &bytes, meta.end_position) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, bytes.len());
+                assert_eq!(result.state, ParseState::Completed);
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert_eq!(257, handler.attributes.len());
+    }
+
+    #[test]
+    fn dyn_data_set_parser_streaming_parse_ok() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
+        let mut handler = TestHandler::default();
+        let mut parser = DynDataSetParser::new(DynEncoding::ExplicitLittleEndian);
+        let mut offset = 0;
+        for i in 0..bytes.len() {
+            match parser.parse(
+                &mut handler,
+                &bytes[offset..i + 1],
+                meta.end_position + offset,
+            ) {
+                Ok(parse_result) => offset += parse_result.bytes_consumed,
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+        assert_eq!(157, handler.attributes.len());
+    }
+
+    // parses the file resuming after every cancel and checks that the
+    // callbacks match those from a parse that was not cancelled
+    fn resume_after_cancel<T: 'static + Encoding>(filepath: &str) {
+        let (meta, bytes) = read_data_set_bytes_from_file(filepath);
+        let mut expected = TestHandler::default();
+        let mut parser = DataSetParser::<T>::default();
+        let result = parser.parse(&mut expected, &bytes, meta.end_position);
+        assert!(result.is_ok());
+
+        let mut handler = ResumeHandler::default();
+        let mut parser = DataSetParser::<T>::default();
+        let mut offset = 0;
+        loop {
+            match parser.parse(&mut handler, &bytes[offset..], meta.end_position + offset) {
+                Ok(parse_result) => {
+                    offset += parse_result.bytes_consumed;
+                    if parse_result.state != ParseState::Cancelled {
+                        assert_eq!(parse_result.state, ParseState::Completed);
+                        break;
+                    }
+                }
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+        assert_eq!(offset, bytes.len());
+        assert!(handler.cancel_count > expected.attributes.len());
+        assert_eq!(handler.handler.attributes, expected.attributes);
+        assert_eq!(handler.handler.positions, expected.positions);
+        assert_eq!(handler.handler.data, expected.data);
+        assert_eq!(handler.handler.depth, 0);
+    }
+
+    #[test]
+    fn explicit_little_endian_resume_after_cancel() {
+        resume_after_cancel::<ExplicitLittleEndian>(
+            "tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm",
+        );
+    }
+
+    #[test]
+    fn implicit_little_endian_resume_after_cancel() {
+        resume_after_cancel::<ImplicitLittleEndian>(
+            "tests/fixtures/IM00001.implicit_little_endian.dcm",
+        );
+    }
+
+    #[cfg(feature = "dictionary")]
+    #[test]
+    fn implicit_little_endian_dictionary_infers_vrs() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.implicit_little_endian.dcm");
+        let mut handler = TestHandler::default();
+        let mut parser = DynDataSetParser::new(DynEncoding::ImplicitLittleEndianDictionary);
+        match parser.parse(&mut handler, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert_eq!(257, handler.attributes.len());
+
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut expected = TestHandler::default();
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        match parser.parse(&mut expected, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert_eq!(expected.attributes.len(), handler.attributes.len());
+
+        // every standard attribute is inferred, the private ones are not
+        for (attribute, expected) in handler.attributes.iter().zip(&expected.attributes) {
+            assert_eq!(attribute.tag, expected.tag);
+            assert_eq!(attribute.vr_inferred, attribute.vr.is_some());
+            if attribute.tag.is_private() {
+                assert_eq!(attribute.vr, None, "{:?}", attribute.tag);
+                continue;
+            }
+            let entry = match lookup(attribute.tag) {
+                Some(entry) => entry,
+                None => panic!("Let's play Global Thermonuclear War"),
+            };
+            // data elements with multiple VRs (e.g. US or SS) are inferred as
+            // one of them
+            if entry.vrs.len() == 1 {
+                assert_eq!(attribute.vr, expected.vr, "{:?}", attribute.tag);
+            } else {
+                assert!(attribute.vr.is_some());
+                assert!(entry.vrs.contains(&expected.vr.unwrap()));
+            }
+        }
+        assert!(expected
+            .attributes
+            .iter()
+            .all(|attribute| !attribute.vr_inferred));
+    }
 }
@@ -0,0 +1,451 @@
+use crate::attribute::Attribute;
+use crate::handler::{Handler, HandlerResult, SequenceItem};
+use crate::tag::Tag;
+use std::ops::Range;
+
+// The location of the value field of an indexed attribute
+#[derive(Debug, Clone, PartialEq)]
+enum IndexValue {
+    // the range of the value field bytes
+    Bytes(Range<usize>),
+    // the items of a sequence
+    Sequence(Vec<DataSetIndex>),
+    // encapsulated pixel data
+    Fragments {
+        basic_offset_table: Range<usize>,
+        fragments: Vec<Range<usize>>,
+    },
+}
+
+// An indexed attribute
+#[derive(Debug, Clone, PartialEq)]
+struct IndexEntry {
+    attribute: Attribute,
+    position: usize,
+    value: IndexValue,
+}
+
+/// Index of the attributes in a DataSet that records where the value field of
+/// each attribute is instead of copying it.  Use data_set() to access the
+/// attributes in the buffer that was parsed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DataSetIndex {
+    entries: Vec<IndexEntry>,
+}
+
+impl DataSetIndex {
+    /// Returns a DataSetRef for the buffer that was parsed to create this
+    /// index.  The first byte of bytes must be at position 0 in the stream
+    /// (e.g. the whole DICOM P10 file when parsed with P10Parser).  Positions
+    /// of deflated DataSets are in the inflated stream so they cannot be
+    /// used with the deflated bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes that were parsed
+    pub fn data_set<'a>(&'a self, bytes: &'a [u8]) -> DataSetRef<'a> {
+        DataSetRef { index: self, bytes }
+    }
+}
+
+/// A DataSet whose values are slices of the buffer that was parsed
+#[derive(Debug, Clone, Copy)]
+pub struct DataSetRef<'a> {
+    index: &'a DataSetIndex,
+    bytes: &'a [u8],
+}
+
+impl<'a> DataSetRef<'a> {
+    /// Returns the ElementRef with the Tag or None if it is not present.  The
+    /// Elements are searched in the order they were parsed (a linear scan)
+    pub fn get(&self, tag: Tag) -> Option<ElementRef<'a>> {
+        self.iter().find(|element| element.tag() == tag)
+    }
+
+    /// Returns the number of Elements
+    pub fn len(&self) -> usize {
+        self.index.entries.len()
+    }
+
+    /// Returns true if there are no Elements
+    pub fn is_empty(&self) -> bool {
+        self.index.entries.is_empty()
+    }
+
+    /// Returns an iterator over the Elements in the order they were parsed
+    pub fn iter(&self) -> impl Iterator<Item = ElementRef<'a>> + 'a {
+        let bytes = self.bytes;
+        self.index
+            .entries
+            .iter()
+            .map(move |entry| ElementRef { entry, bytes })
+    }
+}
+
+/// An Element whose value is a slice of the buffer that was parsed
+#[derive(Debug, Clone, Copy)]
+pub struct ElementRef<'a> {
+    entry: &'a IndexEntry,
+    bytes: &'a [u8],
+}
+
+impl<'a> ElementRef<'a> {
+    /// Returns the attribute (Tag, VR, Length)
+    pub fn attribute(&self) -> &'a Attribute {
+        &self.entry.attribute
+    }
+
+    /// Returns the Tag of the Element
+    pub fn tag(&self) -> Tag {
+        self.entry.attribute.tag
+    }
+
+    /// Returns the offset from the beginning of the stream of the attribute
+    pub fn position(&self) -> usize {
+        self.entry.position
+    }
+
+    /// Returns the bytes of the value field or None if the Element is a
+    /// sequence or encapsulated pixel data or is outside of the buffer
+    pub fn bytes(&self) -> Option<&'a [u8]> {
+        match &self.entry.value {
+            IndexValue::Bytes(range) => self.bytes.get(range.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the items or None if the Element is not a sequence
+    pub fn items(&self) -> Option<impl Iterator<Item = DataSetRef<'a>> + 'a> {
+        let bytes = self.bytes;
+        match &self.entry.value {
+            IndexValue::Sequence(items) => {
+                Some(items.iter().map(move |index| DataSetRef { index, bytes }))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the basic offset table or None if the Element is not
+    /// encapsulated pixel data or is outside of the buffer
+    pub fn basic_offset_table(&self) -> Option<&'a [u8]> {
+        match &self.entry.value {
+            IndexValue::Fragments {
+                basic_offset_table, ..
+            } => self.bytes.get(basic_offset_table.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns the pixel data fragments or None if the Element is not
+    /// encapsulated pixel data.  Fragments outside of the buffer are empty
+    pub fn fragments(&self) -> Option<impl Iterator<Item = &'a [u8]> + 'a> {
+        let bytes = self.bytes;
+        match &self.entry.value {
+            IndexValue::Fragments { fragments, .. } => Some(
+                fragments
+                    .iter()
+                    .map(move |range| bytes.get(range.clone()).unwrap_or(&[])),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Handler that builds a DataSetIndex from the functions invoked during a
+/// parse.  The positions passed to the Handler must be offsets into the
+/// buffer that DataSetIndex::data_set() is called with.
+pub struct DataSetIndexer {
+    // the indexes being built, the first is the root DataSet and the others
+    // are the sequence items currently being parsed
+    indexes: Vec<DataSetIndex>,
+    // the entries of the sequences currently being parsed in their parent
+    sequence_entries: Vec<usize>,
+    // position of the next byte of encapsulated pixel data
+    fragment_position: usize,
+    // the number of the current item of encapsulated pixel data
+    fragment_number: Option<usize>,
+}
+
+impl Default for DataSetIndexer {
+    fn default() -> DataSetIndexer {
+        DataSetIndexer {
+            indexes: vec![DataSetIndex::default()],
+            sequence_entries: vec![],
+            fragment_position: 0,
+            fragment_number: None,
+        }
+    }
+}
+
+impl DataSetIndexer {
+    /// Returns the index of the root DataSet
+    pub fn into_index(mut self) -> DataSetIndex {
+        self.indexes.swap_remove(0)
+    }
+
+    // returns the value of the current attribute
+    fn value(&mut self) -> Option<&mut IndexValue> {
+        let index = self.indexes.last_mut()?;
+        index.entries.last_mut().map(|entry| &mut entry.value)
+    }
+
+    // adds the length of an encapsulated pixel data item's data to its range
+    // starting a new item if the number changed
+    fn fragment(&mut self, fragment_number: usize, length: usize) {
+        if self.fragment_number != Some(fragment_number) {
+            // skip the item tag and length
+            self.fragment_position += 8;
+            self.fragment_number = Some(fragment_number);
+        }
+        let position = self.fragment_position;
+        self.fragment_position += length;
+
+        let value = match self.value() {
+            Some(value) => value,
+            None => return,
+        };
+        if let IndexValue::Bytes(_) = value {
+            *value = IndexValue::Fragments {
+                basic_offset_table: position..position,
+                fragments: vec![],
+            };
+        }
+        if let IndexValue::Fragments {
+            basic_offset_table,
+            fragments,
+        } = value
+        {
+            let range = if fragment_number == 0 {
+                basic_offset_table
+            } else {
+                // fragment numbers start at 1 (the basic offset table is 0)
+                if fragments.len() < fragment_number {
+                    fragments.push(position..position);
+                }
+                fragments.last_mut().unwrap()
+            };
+            range.end += length;
+        }
+    }
+}
+
+impl Handler for DataSetIndexer {
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        position: usize,
+        data_offset: usize,
+    ) -> HandlerResult {
+        let data_position = position + data_offset;
+        self.fragment_position = data_position;
+        self.fragment_number = None;
+        if let Some(index) = self.indexes.last_mut() {
+            let entry = IndexEntry {
+                attribute: *attribute,
+                position,
+                value: IndexValue::Bytes(data_position..data_position),
+            };
+            // a resumed parse delivers the attribute that cancelled again
+            match index.entries.last_mut() {
+                Some(last) if last.position == position => *last = entry,
+                _ => index.entries.push(entry),
+            }
+        }
+        HandlerResult::Continue
+    }
+
+    fn data(&mut self, _attribute: &Attribute, data: &[u8], _complete: bool) -> HandlerResult {
+        if let Some(IndexValue::Bytes(range)) = self.value() {
+            range.end += data.len();
+        }
+        HandlerResult::Continue
+    }
+
+    fn start_sequence(&mut self, _attribute: &Attribute) {
+        if let Some(value) = self.value() {
+            *value = IndexValue::Sequence(vec![]);
+        }
+        if let Some(index) = self.indexes.last() {
+            self.sequence_entries.push(index.entries.len() - 1);
+        }
+    }
+
+    fn start_sequence_item(&mut self, _attribute: &Attribute, _item: &SequenceItem) {
+        self.indexes.push(DataSetIndex::default());
+    }
+
+    fn end_sequence_item(&mut self, _attribute: &Attribute) {
+        // add the item to the sequence in the parent DataSet
+        if self.indexes.len() < 2 {
+            return;
+        }
+        let item = self.indexes.pop().unwrap();
+        let entry = match self.sequence_entries.last() {
+            Some(entry) => *entry,
+            None => return,
+        };
+        if let Some(index) = self.indexes.last_mut() {
+            if let IndexValue::Sequence(items) = &mut index.entries[entry].value {
+                items.push(item);
+            }
+        }
+    }
+
+    fn end_sequence(&mut self, _attribute: &Attribute) {
+        self.sequence_entries.pop();
+    }
+
+    fn basic_offset_table(
+        &mut self,
+        _attribute: &Attribute,
+        data: &[u8],
+        _complete: bool,
+    ) -> HandlerResult {
+        self.fragment(0, data.len());
+        HandlerResult::Continue
+    }
+
+    fn pixel_data_fragment(
+        &mut self,
+        _attribute: &Attribute,
+        fragment_number: usize,
+        data: &[u8],
+        _complete: bool,
+    ) -> HandlerResult {
+        self.fragment(fragment_number, data.len());
+        HandlerResult::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DataSetIndex, DataSetIndexer, DataSetRef};
+    use crate::data_set::{DataSet, DataSetBuilder, Value};
+    use crate::handler::tee::TeeHandler;
+    use crate::p10_parser::P10Parser;
+    use crate::tag::Tag;
+    use crate::test::tests::read_file;
+    use crate::test::tests::ResumeHandler;
+
+    // parses the file in chunks building both an index and a DataSet
+    fn index_file(bytes: &[u8], chunk_size: usize) -> (DataSetIndex, DataSet) {
+        let mut indexer = DataSetIndexer::default();
+        let mut builder = DataSetBuilder::default();
+        {
+            let mut tee_handler = TeeHandler::default();
+            tee_handler.handlers.push(&mut indexer);
+            tee_handler.handlers.push(&mut builder);
+            let mut parser = P10Parser::default();
+            let mut offset = 0;
+            let mut end = 0;
+            while end < bytes.len() {
+                end = std::cmp::min(end + chunk_size, bytes.len());
+                match parser.parse(&mut tee_handler, &bytes[offset..end]) {
+                    Ok(result) => offset += result.bytes_consumed,
+                    Err(_error) => panic!("Let's play Global Thermonuclear War"),
+                }
+            }
+            assert_eq!(offset, bytes.len());
+        }
+        (indexer.into_index(), builder.into_data_set())
+    }
+
+    // checks that the values referenced match the values copied
+    fn assert_same(data_set_ref: DataSetRef, data_set: &DataSet) {
+        assert_eq!(data_set_ref.len(), data_set.len());
+        for element_ref in data_set_ref.iter() {
+            let element = data_set.get(element_ref.tag()).unwrap();
+            assert_eq!(element_ref.position(), element.position);
+            match &element.value {
+                Value::Bytes(bytes) => assert_eq!(element_ref.bytes().unwrap(), &bytes[..]),
+                Value::Sequence(items) => {
+                    let items_ref: Vec<DataSetRef> = element_ref.items().unwrap().collect();
+                    assert_eq!(items_ref.len(), items.len());
+                    for (item_ref, item) in items_ref.into_iter().zip(items.iter()) {
+                        assert_same(item_ref, item);
+                    }
+                }
+                Value::Fragments {
+                    basic_offset_table,
+                    fragments,
+                } => {
+                    assert_eq!(
+                        element_ref.basic_offset_table().unwrap(),
+                        &basic_offset_table[..]
+                    );
+                    let fragments_ref: Vec<&[u8]> = element_ref.fragments().unwrap().collect();
+                    assert_eq!(fragments_ref.len(), fragments.len());
+                    for (fragment_ref, fragment) in fragments_ref.iter().zip(fragments.iter()) {
+                        assert_eq!(*fragment_ref, &fragment[..]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn values_reference_buffer() {
+        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let (index, data_set) = index_file(&bytes, bytes.len());
+        let data_set_ref = index.data_set(&bytes);
+        let patient_name = data_set_ref.get(Tag::new(0x0010, 0x0010)).unwrap();
+        assert_eq!(patient_name.bytes().unwrap(), b"CompressedSamples^CT1 ");
+        // the value is a slice of the buffer, not a copy
+        let buffer = bytes.as_ptr_range();
+        assert!(buffer.contains(&patient_name.bytes().unwrap().as_ptr()));
+        assert_same(data_set_ref, &data_set);
+    }
+
+    #[test]
+    fn sequences_and_fragments_reference_buffer() {
+        let bytes = read_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
+        let (index, data_set) = index_file(&bytes, 97);
+        let data_set_ref = index.data_set(&bytes);
+        let pixel_data = data_set_ref.get(Tag::new(0x7fe0, 0x0010)).unwrap();
+        assert!(pixel_data.bytes().is_none());
+        assert!(pixel_data.fragments().unwrap().count() > 0);
+        assert_same(data_set_ref, &data_set);
+    }
+
+    #[test]
+    fn undefined_length_sequences_reference_buffer() {
+        let bytes = read_file("tests/fixtures/IM00001.implicit_little_endian.dcm");
+        let (index, data_set) = index_file(&bytes, bytes.len());
+        let data_set_ref = index.data_set(&bytes);
+        let sequence = data_set_ref.get(Tag::new(0x0018, 0x6011)).unwrap();
+        assert!(sequence.items().unwrap().count() > 0);
+        assert_same(data_set_ref, &data_set);
+    }
+
+    #[test]
+    fn resumed_parse_does_not_repeat_elements() {
+        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let (expected, _data_set) = index_file(&bytes, bytes.len());
+        let mut indexer = DataSetIndexer::default();
+        let mut resume_handler = ResumeHandler::default();
+        {
+            let mut tee_handler = TeeHandler::default();
+            tee_handler.handlers.push(&mut indexer);
+            tee_handler.handlers.push(&mut resume_handler);
+            let mut parser = P10Parser::default();
+            let mut offset = 0;
+            while offset < bytes.len() {
+                match parser.parse(&mut tee_handler, &bytes[offset..]) {
+                    Ok(result) => offset += result.bytes_consumed,
+                    Err(_error) => panic!("Let's play Global Thermonuclear War"),
+                }
+            }
+        }
+        assert!(resume_handler.cancel_count > 0);
+        assert_eq!(indexer.into_index(), expected);
+    }
+
+    #[test]
+    fn values_outside_of_buffer_are_none() {
+        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let (index, _data_set) = index_file(&bytes, bytes.len());
+        let data_set_ref = index.data_set(&bytes[..1024]);
+        let pixel_data = data_set_ref.get(Tag::new(0x7fe0, 0x0010)).unwrap();
+        assert!(pixel_data.bytes().is_none());
+    }
+}
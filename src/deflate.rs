@@ -0,0 +1,241 @@
+use crate::data_set_parser::DataSetParser;
+use crate::encoding::ExplicitLittleEndian;
+use crate::handler::Handler;
+use crate::value_parser::ParseError;
+use crate::value_parser::ParseErrorKind;
+use crate::value_parser::ParseResult;
+use crate::value_parser::ParseState;
+use miniz_oxide::inflate::stream::{inflate, InflateState};
+use miniz_oxide::{DataFormat, MZError, MZFlush, MZStatus};
+
+// number of inflated bytes produced per call to the inflater
+const INFLATE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Parses a DataSet encoded with the Deflated Explicit VR Little Endian
+/// transfer syntax.  The bytes provided are raw DEFLATE (no zlib header)
+/// compressed bytes which are inflated incrementally and forwarded to a
+/// DataSetParser<ExplicitLittleEndian>.
+///
+/// Positions reported to the Handler are offsets in the inflated stream.
+/// The inflated stream starts at the bytes_from_beginning passed to the
+/// first call of parse() so positions look as if the DataSet had never been
+/// deflated (e.g. the first attribute is at MetaInformation::end_position)
+pub struct DeflatedDataSetParser {
+    inflater: Box<InflateState>,
+    parser: DataSetParser<ExplicitLittleEndian>,
+    // buffer the inflater writes each chunk of inflated bytes to
+    chunk: Vec<u8>,
+    // inflated bytes not yet consumed by the parser
+    inflated: Vec<u8>,
+    // position in the inflated stream of the first byte in inflated
+    inflated_position: Option<usize>,
+    // true once the end of the deflate stream has been reached
+    stream_end: bool,
+    // true if the handler cancelled the parse.  The inflated bytes may
+    // contain attributes that have not been parsed yet
+    cancelled: bool,
+}
+
+impl Default for DeflatedDataSetParser {
+    fn default() -> DeflatedDataSetParser {
+        DeflatedDataSetParser {
+            inflater: InflateState::new_boxed(DataFormat::Raw),
+            parser: DataSetParser::<ExplicitLittleEndian>::default(),
+            chunk: vec![],
+            inflated: vec![],
+            inflated_position: None,
+            stream_end: false,
+            cancelled: false,
+        }
+    }
+}
+
+impl DeflatedDataSetParser {
+    /// Parses the deflated bytes.  The bytes_consumed in the returned
+    /// ParseResult is the number of deflated bytes consumed.  Inflated
+    /// bytes that could not be parsed yet are buffered internally so all of
+    /// the provided bytes are normally consumed.  If the parse was cancelled,
+    /// the inflated bytes buffered are parsed first when it is resumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler`              - The Handler to invoke when parsing the DataSet
+    /// * `bytes`                - The deflated bytes
+    /// * `bytes_from_beginning` - The position of the first inflated byte.
+    ///   Only used on the first call
+    pub fn parse(
+        &mut self,
+        handler: &mut dyn Handler,
+        bytes: &[u8],
+        bytes_from_beginning: usize,
+    ) -> Result<ParseResult, ParseError> {
+        let mut inflated_position = *self.inflated_position.get_or_insert(bytes_from_beginning);
+        let mut remaining_bytes = bytes;
+        let mut bytes_consumed = 0;
+        let mut state = ParseState::Completed;
+        self.chunk.resize(INFLATE_CHUNK_SIZE, 0);
+
+        // resume parsing the inflated bytes buffered when the parse was
+        // cancelled before inflating any more
+        if self.cancelled {
+            self.cancelled = false;
+            state = self.parse_inflated(handler, &mut inflated_position)?;
+            if state == ParseState::Cancelled {
+                return Ok(ParseResult::cancelled(0));
+            }
+        }
+
+        while !self.stream_end {
+            // inflate the next chunk
+            let result = inflate(
+                &mut self.inflater,
+                remaining_bytes,
+                &mut self.chunk,
+                MZFlush::None,
+            );
+            match result.status {
+                Ok(MZStatus::StreamEnd) => self.stream_end = true,
+                // Buf means no progress was possible which occurs when more
+                // bytes are needed
+                Ok(_) | Err(MZError::Buf) => {}
+                Err(_) => {
+                    return Err(ParseError::new(
+                        ParseErrorKind::InvalidDeflateStream,
+                        inflated_position + self.inflated.len(),
+                    ));
+                }
+            }
+
+            // update internal state
+            bytes_consumed += result.bytes_consumed;
+            remaining_bytes = &remaining_bytes[result.bytes_consumed..];
+            if result.bytes_written == 0 {
+                break;
+            }
+            self.inflated
+                .extend_from_slice(&self.chunk[..result.bytes_written]);
+
+            // parse the inflated bytes
+            state = self.parse_inflated(handler, &mut inflated_position)?;
+            if state == ParseState::Cancelled {
+                return Ok(ParseResult::cancelled(bytes_consumed));
+            }
+        }
+
+        Ok(ParseResult {
+            bytes_consumed,
+            state,
+        })
+    }
+
+    // Parses the buffered inflated bytes and discards the ones consumed
+    fn parse_inflated(
+        &mut self,
+        handler: &mut dyn Handler,
+        inflated_position: &mut usize,
+    ) -> Result<ParseState, ParseError> {
+        let parse_result = self
+            .parser
+            .parse(handler, &self.inflated, *inflated_position)?;
+        self.inflated.drain(..parse_result.bytes_consumed);
+        *inflated_position += parse_result.bytes_consumed;
+        self.inflated_position = Some(*inflated_position);
+        self.cancelled = parse_result.state == ParseState::Cancelled;
+        Ok(parse_result.state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::DeflatedDataSetParser;
+    use crate::test::tests::read_data_set_bytes_from_file;
+    use crate::test::tests::ResumeHandler;
+    use crate::test::tests::TestHandler;
+    use crate::value_parser::ParseState;
+    use miniz_oxide::deflate::compress_to_vec;
+
+    #[test]
+    fn full_parse_completes() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let deflated = compress_to_vec(&bytes, 6);
+        let mut handler = TestHandler::default();
+        let mut parser = DeflatedDataSetParser::default();
+        match parser.parse(&mut handler, &deflated, meta.end_position) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, deflated.len());
+                assert_eq!(result.state, ParseState::Completed);
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert_eq!(257, handler.attributes.len());
+    }
+
+    #[test]
+    fn streaming_parse_completes() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
+        let deflated = compress_to_vec(&bytes, 6);
+        let mut handler = TestHandler::default();
+        let mut parser = DeflatedDataSetParser::default();
+        // feed one byte at a time to exercise buffering of inflated bytes
+        for i in 0..deflated.len() {
+            match parser.parse(&mut handler, &deflated[i..i + 1], meta.end_position) {
+                Ok(result) => assert_eq!(result.bytes_consumed, 1),
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+        assert_eq!(157, handler.attributes.len());
+    }
+
+    #[test]
+    fn positions_are_in_inflated_stream() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let deflated = compress_to_vec(&bytes, 6);
+        let mut handler = TestHandler::default();
+        let mut parser = DeflatedDataSetParser::default();
+        let result = parser.parse(&mut handler, &deflated, meta.end_position);
+        assert!(result.is_ok());
+        assert_eq!(handler.positions[0], meta.end_position);
+        assert_eq!(
+            *handler.positions.last().unwrap() + 12 + handler.attributes.last().unwrap().length,
+            meta.end_position + bytes.len()
+        );
+    }
+
+    #[test]
+    fn invalid_deflate_stream_returns_error() {
+        let bytes = vec![0xFF; 64];
+        let mut handler = TestHandler::default();
+        let mut parser = DeflatedDataSetParser::default();
+        let result = parser.parse(&mut handler, &bytes, 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resume_after_cancel_completes() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
+        let deflated = compress_to_vec(&bytes, 6);
+        let mut handler = ResumeHandler::default();
+        let mut parser = DeflatedDataSetParser::default();
+        let mut offset = 0;
+        loop {
+            match parser.parse(&mut handler, &deflated[offset..], meta.end_position) {
+                Ok(result) => {
+                    offset += result.bytes_consumed;
+                    if result.state != ParseState::Cancelled {
+                        assert_eq!(result.state, ParseState::Completed);
+                        break;
+                    }
+                }
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+        assert_eq!(offset, deflated.len());
+        assert_eq!(157, handler.handler.attributes.len());
+        assert_eq!(handler.handler.positions[0], meta.end_position);
+    }
+}
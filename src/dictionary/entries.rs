@@ -0,0 +1,4272 @@
+// Subset of the PS3.6 data dictionary with the commonly used data elements in
+// the format written by scripts/generate_dictionary.py.  Run the script on
+// part06.xml to replace it with the full dictionary
+use crate::dictionary::DictionaryEntry;
+use crate::tag::Tag;
+use crate::vr::VR;
+
+/// The data elements with a single tag ordered by tag
+pub static ENTRIES: &[DictionaryEntry] = &[
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0000,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FileMetaInformationGroupLength",
+        name: "File Meta Information Group Length",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0001,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FileMetaInformationVersion",
+        name: "File Meta Information Version",
+        vrs: &[VR::OB],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0002,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "MediaStorageSOPClassUID",
+        name: "Media Storage SOP Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0003,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "MediaStorageSOPInstanceUID",
+        name: "Media Storage SOP Instance UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TransferSyntaxUID",
+        name: "Transfer Syntax UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0012,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImplementationClassUID",
+        name: "Implementation Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0013,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImplementationVersionName",
+        name: "Implementation Version Name",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0016,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SourceApplicationEntityTitle",
+        name: "Source Application Entity Title",
+        vrs: &[VR::AE],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0017,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SendingApplicationEntityTitle",
+        name: "Sending Application Entity Title",
+        vrs: &[VR::AE],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0018,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReceivingApplicationEntityTitle",
+        name: "Receiving Application Entity Title",
+        vrs: &[VR::AE],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0026,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SourcePresentationAddress",
+        name: "Source Presentation Address",
+        vrs: &[VR::UR],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0027,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SendingPresentationAddress",
+        name: "Sending Presentation Address",
+        vrs: &[VR::UR],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0028,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReceivingPresentationAddress",
+        name: "Receiving Presentation Address",
+        vrs: &[VR::UR],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0031,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RTVMetaInformationVersion",
+        name: "RTV Meta Information Version",
+        vrs: &[VR::OB],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0032,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RTVCommunicationSOPClassUID",
+        name: "RTV Communication SOP Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0033,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RTVCommunicationSOPInstanceUID",
+        name: "RTV Communication SOP Instance UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0035,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RTVSourceIdentifier",
+        name: "RTV Source Identifier",
+        vrs: &[VR::OB],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0036,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RTVFlowIdentifier",
+        name: "RTV Flow Identifier",
+        vrs: &[VR::OB],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0037,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RTVFlowRTPSamplingRate",
+        name: "RTV Flow RTP Sampling Rate",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0038,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RTVFlowActualFrameDuration",
+        name: "RTV Flow Actual Frame Duration",
+        vrs: &[VR::FD],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0100,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PrivateInformationCreatorUID",
+        name: "Private Information Creator UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0002,
+            element: 0x0102,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PrivateInformation",
+        name: "Private Information",
+        vrs: &[VR::OB],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0004,
+            element: 0x1130,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FileSetID",
+        name: "File-set ID",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0004,
+            element: 0x1200,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "OffsetOfTheFirstDirectoryRecordOfTheRootDirectoryEntity",
+        name: "Offset of the First Directory Record of the Root Directory Entity",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0004,
+            element: 0x1220,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DirectoryRecordSequence",
+        name: "Directory Record Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0004,
+            element: 0x1430,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DirectoryRecordType",
+        name: "Directory Record Type",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0004,
+            element: 0x1500,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedFileID",
+        name: "Referenced File ID",
+        vrs: &[VR::CS],
+        vm: "1-8",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0004,
+            element: 0x1510,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedSOPClassUIDInFile",
+        name: "Referenced SOP Class UID in File",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0004,
+            element: 0x1511,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedSOPInstanceUIDInFile",
+        name: "Referenced SOP Instance UID in File",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0004,
+            element: 0x1512,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedTransferSyntaxUIDInFile",
+        name: "Referenced Transfer Syntax UID in File",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0001,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LengthToEnd",
+        name: "Length to End",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0005,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SpecificCharacterSet",
+        name: "Specific Character Set",
+        vrs: &[VR::CS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0006,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LanguageCodeSequence",
+        name: "Language Code Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0008,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImageType",
+        name: "Image Type",
+        vrs: &[VR::CS],
+        vm: "2-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RecognitionCode",
+        name: "Recognition Code",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0012,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstanceCreationDate",
+        name: "Instance Creation Date",
+        vrs: &[VR::DA],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0013,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstanceCreationTime",
+        name: "Instance Creation Time",
+        vrs: &[VR::TM],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0014,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstanceCreatorUID",
+        name: "Instance Creator UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0015,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstanceCoercionDateTime",
+        name: "Instance Coercion DateTime",
+        vrs: &[VR::DT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0016,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SOPClassUID",
+        name: "SOP Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0018,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SOPInstanceUID",
+        name: "SOP Instance UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x001A,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RelatedGeneralSOPClassUID",
+        name: "Related General SOP Class UID",
+        vrs: &[VR::UI],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x001B,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "OriginalSpecializedSOPClassUID",
+        name: "Original Specialized SOP Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0020,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StudyDate",
+        name: "Study Date",
+        vrs: &[VR::DA],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0021,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SeriesDate",
+        name: "Series Date",
+        vrs: &[VR::DA],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0022,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AcquisitionDate",
+        name: "Acquisition Date",
+        vrs: &[VR::DA],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0023,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContentDate",
+        name: "Content Date",
+        vrs: &[VR::DA],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0024,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "OverlayDate",
+        name: "Overlay Date",
+        vrs: &[VR::DA],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0025,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "CurveDate",
+        name: "Curve Date",
+        vrs: &[VR::DA],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x002A,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AcquisitionDateTime",
+        name: "Acquisition DateTime",
+        vrs: &[VR::DT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0030,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StudyTime",
+        name: "Study Time",
+        vrs: &[VR::TM],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0031,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SeriesTime",
+        name: "Series Time",
+        vrs: &[VR::TM],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0032,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AcquisitionTime",
+        name: "Acquisition Time",
+        vrs: &[VR::TM],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0033,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContentTime",
+        name: "Content Time",
+        vrs: &[VR::TM],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0034,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "OverlayTime",
+        name: "Overlay Time",
+        vrs: &[VR::TM],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0035,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "CurveTime",
+        name: "Curve Time",
+        vrs: &[VR::TM],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0040,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DataSetType",
+        name: "Data Set Type",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0041,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DataSetSubtype",
+        name: "Data Set Subtype",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0042,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "NuclearMedicineSeriesType",
+        name: "Nuclear Medicine Series Type",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0050,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AccessionNumber",
+        name: "Accession Number",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0051,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "IssuerOfAccessionNumberSequence",
+        name: "Issuer of Accession Number Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0052,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "QueryRetrieveLevel",
+        name: "Query/Retrieve Level",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0053,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "QueryRetrieveView",
+        name: "Query/Retrieve View",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0054,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RetrieveAETitle",
+        name: "Retrieve AE Title",
+        vrs: &[VR::AE],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0055,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StationAETitle",
+        name: "Station AE Title",
+        vrs: &[VR::AE],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0056,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstanceAvailability",
+        name: "Instance Availability",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0058,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FailedSOPInstanceUIDList",
+        name: "Failed SOP Instance UID List",
+        vrs: &[VR::UI],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0060,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "Modality",
+        name: "Modality",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0061,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ModalitiesInStudy",
+        name: "Modalities in Study",
+        vrs: &[VR::CS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0062,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SOPClassesInStudy",
+        name: "SOP Classes in Study",
+        vrs: &[VR::UI],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0064,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ConversionType",
+        name: "Conversion Type",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0068,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PresentationIntentType",
+        name: "Presentation Intent Type",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0070,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "Manufacturer",
+        name: "Manufacturer",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0080,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstitutionName",
+        name: "Institution Name",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0081,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstitutionAddress",
+        name: "Institution Address",
+        vrs: &[VR::ST],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0082,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstitutionCodeSequence",
+        name: "Institution Code Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0090,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferringPhysicianName",
+        name: "Referring Physician's Name",
+        vrs: &[VR::PN],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0092,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferringPhysicianAddress",
+        name: "Referring Physician's Address",
+        vrs: &[VR::ST],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0094,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferringPhysicianTelephoneNumbers",
+        name: "Referring Physician's Telephone Numbers",
+        vrs: &[VR::SH],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0096,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferringPhysicianIdentificationSequence",
+        name: "Referring Physician Identification Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0100,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "CodeValue",
+        name: "Code Value",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0102,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "CodingSchemeDesignator",
+        name: "Coding Scheme Designator",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0103,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "CodingSchemeVersion",
+        name: "Coding Scheme Version",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0104,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "CodeMeaning",
+        name: "Code Meaning",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0105,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "MappingResource",
+        name: "Mapping Resource",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0106,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContextGroupVersion",
+        name: "Context Group Version",
+        vrs: &[VR::DT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0107,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContextGroupLocalVersion",
+        name: "Context Group Local Version",
+        vrs: &[VR::DT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x010F,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContextIdentifier",
+        name: "Context Identifier",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0110,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "CodingSchemeIdentificationSequence",
+        name: "Coding Scheme Identification Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0119,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LongCodeValue",
+        name: "Long Code Value",
+        vrs: &[VR::UC],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0120,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "URNCodeValue",
+        name: "URN Code Value",
+        vrs: &[VR::UR],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x0201,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TimezoneOffsetFromUTC",
+        name: "Timezone Offset From UTC",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StationName",
+        name: "Station Name",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1030,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StudyDescription",
+        name: "Study Description",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1032,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ProcedureCodeSequence",
+        name: "Procedure Code Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x103E,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SeriesDescription",
+        name: "Series Description",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1040,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstitutionalDepartmentName",
+        name: "Institutional Department Name",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1048,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PhysiciansOfRecord",
+        name: "Physician(s) of Record",
+        vrs: &[VR::PN],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1050,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PerformingPhysicianName",
+        name: "Performing Physician's Name",
+        vrs: &[VR::PN],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1060,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "NameOfPhysiciansReadingStudy",
+        name: "Name of Physician(s) Reading Study",
+        vrs: &[VR::PN],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1070,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "OperatorsName",
+        name: "Operators' Name",
+        vrs: &[VR::PN],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1080,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AdmittingDiagnosesDescription",
+        name: "Admitting Diagnoses Description",
+        vrs: &[VR::LO],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1090,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ManufacturerModelName",
+        name: "Manufacturer's Model Name",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1110,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedStudySequence",
+        name: "Referenced Study Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1111,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedPerformedProcedureStepSequence",
+        name: "Referenced Performed Procedure Step Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1115,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedSeriesSequence",
+        name: "Referenced Series Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1120,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedPatientSequence",
+        name: "Referenced Patient Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1140,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedImageSequence",
+        name: "Referenced Image Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1150,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedSOPClassUID",
+        name: "Referenced SOP Class UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1155,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedSOPInstanceUID",
+        name: "Referenced SOP Instance UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1160,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedFrameNumber",
+        name: "Referenced Frame Number",
+        vrs: &[VR::IS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x1199,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedSOPSequence",
+        name: "Referenced SOP Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2111,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DerivationDescription",
+        name: "Derivation Description",
+        vrs: &[VR::ST],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2112,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SourceImageSequence",
+        name: "Source Image Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2120,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StageName",
+        name: "Stage Name",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2122,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StageNumber",
+        name: "Stage Number",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2124,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "NumberOfStages",
+        name: "Number of Stages",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2128,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ViewNumber",
+        name: "View Number",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2129,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "NumberOfEventTimers",
+        name: "Number of Event Timers",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x212A,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "NumberOfViewsInStage",
+        name: "Number of Views in Stage",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2130,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "EventElapsedTimes",
+        name: "Event Elapsed Time(s)",
+        vrs: &[VR::DS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2132,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "EventTimerNames",
+        name: "Event Timer Name(s)",
+        vrs: &[VR::LO],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2142,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StartTrim",
+        name: "Start Trim",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2143,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StopTrim",
+        name: "Stop Trim",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x2218,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AnatomicRegionSequence",
+        name: "Anatomic Region Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x3001,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AlternateRepresentationSequence",
+        name: "Alternate Representation Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x3010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "IrradiationEventUID",
+        name: "Irradiation Event UID",
+        vrs: &[VR::UI],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x9007,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FrameType",
+        name: "Frame Type",
+        vrs: &[VR::CS],
+        vm: "4",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x9121,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencedRawDataSequence",
+        name: "Referenced Raw Data Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x9205,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelPresentation",
+        name: "Pixel Presentation",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x9206,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "VolumetricProperties",
+        name: "Volumetric Properties",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0008,
+            element: 0x9207,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "VolumeBasedCalculationTechnique",
+        name: "Volume Based Calculation Technique",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x0010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientName",
+        name: "Patient's Name",
+        vrs: &[VR::PN],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x0020,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientID",
+        name: "Patient ID",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x0021,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "IssuerOfPatientID",
+        name: "Issuer of Patient ID",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x0022,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TypeOfPatientID",
+        name: "Type of Patient ID",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x0024,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "IssuerOfPatientIDQualifiersSequence",
+        name: "Issuer of Patient ID Qualifiers Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x0030,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientBirthDate",
+        name: "Patient's Birth Date",
+        vrs: &[VR::DA],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x0032,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientBirthTime",
+        name: "Patient's Birth Time",
+        vrs: &[VR::TM],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x0040,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientSex",
+        name: "Patient's Sex",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x1000,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "OtherPatientIDs",
+        name: "Other Patient IDs",
+        vrs: &[VR::LO],
+        vm: "1-n",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x1001,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "OtherPatientNames",
+        name: "Other Patient Names",
+        vrs: &[VR::PN],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x1002,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "OtherPatientIDsSequence",
+        name: "Other Patient IDs Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x1005,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientBirthName",
+        name: "Patient's Birth Name",
+        vrs: &[VR::PN],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x1010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientAge",
+        name: "Patient's Age",
+        vrs: &[VR::AS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x1020,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientSize",
+        name: "Patient's Size",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x1030,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientWeight",
+        name: "Patient's Weight",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x1040,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientAddress",
+        name: "Patient's Address",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x1060,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientMotherBirthName",
+        name: "Patient's Mother's Birth Name",
+        vrs: &[VR::PN],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x2154,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientTelephoneNumbers",
+        name: "Patient's Telephone Numbers",
+        vrs: &[VR::SH],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x2160,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "EthnicGroup",
+        name: "Ethnic Group",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x2180,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "Occupation",
+        name: "Occupation",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x21B0,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AdditionalPatientHistory",
+        name: "Additional Patient History",
+        vrs: &[VR::LT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0010,
+            element: 0x4000,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientComments",
+        name: "Patient Comments",
+        vrs: &[VR::LT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusAgent",
+        name: "Contrast/Bolus Agent",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0012,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusAgentSequence",
+        name: "Contrast/Bolus Agent Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0014,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusAdministrationRouteSequence",
+        name: "Contrast/Bolus Administration Route Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0015,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "BodyPartExamined",
+        name: "Body Part Examined",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0020,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ScanningSequence",
+        name: "Scanning Sequence",
+        vrs: &[VR::CS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0021,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SequenceVariant",
+        name: "Sequence Variant",
+        vrs: &[VR::CS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0022,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ScanOptions",
+        name: "Scan Options",
+        vrs: &[VR::CS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0023,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "MRAcquisitionType",
+        name: "MR Acquisition Type",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0024,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SequenceName",
+        name: "Sequence Name",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0050,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SliceThickness",
+        name: "Slice Thickness",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0060,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "KVP",
+        name: "KVP",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0080,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RepetitionTime",
+        name: "Repetition Time",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0081,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "EchoTime",
+        name: "Echo Time",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0082,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InversionTime",
+        name: "Inversion Time",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0083,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "NumberOfAverages",
+        name: "Number of Averages",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0084,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImagingFrequency",
+        name: "Imaging Frequency",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0085,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImagedNucleus",
+        name: "Imaged Nucleus",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0086,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "EchoNumbers",
+        name: "Echo Number(s)",
+        vrs: &[VR::IS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0087,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "MagneticFieldStrength",
+        name: "Magnetic Field Strength",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0088,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SpacingBetweenSlices",
+        name: "Spacing Between Slices",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0090,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DataCollectionDiameter",
+        name: "Data Collection Diameter",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x0091,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "EchoTrainLength",
+        name: "Echo Train Length",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1000,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DeviceSerialNumber",
+        name: "Device Serial Number",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1020,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SoftwareVersions",
+        name: "Software Versions",
+        vrs: &[VR::LO],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1030,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ProtocolName",
+        name: "Protocol Name",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1040,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusRoute",
+        name: "Contrast/Bolus Route",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1041,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusVolume",
+        name: "Contrast/Bolus Volume",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1049,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusIngredientConcentration",
+        name: "Contrast/Bolus Ingredient Concentration",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1060,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TriggerTime",
+        name: "Trigger Time",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1065,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FrameTimeVector",
+        name: "Frame Time Vector",
+        vrs: &[VR::DS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1088,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "HeartRate",
+        name: "Heart Rate",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1100,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReconstructionDiameter",
+        name: "Reconstruction Diameter",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1110,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DistanceSourceToDetector",
+        name: "Distance Source to Detector",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1111,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DistanceSourceToPatient",
+        name: "Distance Source to Patient",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1120,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "GantryDetectorTilt",
+        name: "Gantry/Detector Tilt",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1130,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TableHeight",
+        name: "Table Height",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1140,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RotationDirection",
+        name: "Rotation Direction",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1150,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ExposureTime",
+        name: "Exposure Time",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1151,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "XRayTubeCurrent",
+        name: "X-Ray Tube Current",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1152,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "Exposure",
+        name: "Exposure",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1160,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FilterType",
+        name: "Filter Type",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1164,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImagerPixelSpacing",
+        name: "Imager Pixel Spacing",
+        vrs: &[VR::DS],
+        vm: "2",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1170,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "GeneratorPower",
+        name: "Generator Power",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1190,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FocalSpots",
+        name: "Focal Spot(s)",
+        vrs: &[VR::DS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1210,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ConvolutionKernel",
+        name: "Convolution Kernel",
+        vrs: &[VR::SH],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1250,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReceiveCoilName",
+        name: "Receive Coil Name",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1251,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TransmitCoilName",
+        name: "Transmit Coil Name",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1310,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AcquisitionMatrix",
+        name: "Acquisition Matrix",
+        vrs: &[VR::US],
+        vm: "4",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1312,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InPlanePhaseEncodingDirection",
+        name: "In-plane Phase Encoding Direction",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x1314,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FlipAngle",
+        name: "Flip Angle",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x5100,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientPosition",
+        name: "Patient Position",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6011,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SequenceOfUltrasoundRegions",
+        name: "Sequence of Ultrasound Regions",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6012,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RegionSpatialFormat",
+        name: "Region Spatial Format",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6014,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RegionDataType",
+        name: "Region Data Type",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6016,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RegionFlags",
+        name: "Region Flags",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6018,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RegionLocationMinX0",
+        name: "Region Location Min X0",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x601A,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RegionLocationMinY0",
+        name: "Region Location Min Y0",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x601C,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RegionLocationMaxX1",
+        name: "Region Location Max X1",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x601E,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RegionLocationMaxY1",
+        name: "Region Location Max Y1",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6020,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencePixelX0",
+        name: "Reference Pixel X0",
+        vrs: &[VR::SL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6022,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencePixelY0",
+        name: "Reference Pixel Y0",
+        vrs: &[VR::SL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6024,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PhysicalUnitsXDirection",
+        name: "Physical Units X Direction",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6026,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PhysicalUnitsYDirection",
+        name: "Physical Units Y Direction",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6028,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencePixelPhysicalValueX",
+        name: "Reference Pixel Physical Value X",
+        vrs: &[VR::FD],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x602A,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ReferencePixelPhysicalValueY",
+        name: "Reference Pixel Physical Value Y",
+        vrs: &[VR::FD],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x602C,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PhysicalDeltaX",
+        name: "Physical Delta X",
+        vrs: &[VR::FD],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x602E,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PhysicalDeltaY",
+        name: "Physical Delta Y",
+        vrs: &[VR::FD],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6030,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TransducerFrequency",
+        name: "Transducer Frequency",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6031,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TransducerType",
+        name: "Transducer Type",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x6032,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PulseRepetitionFrequency",
+        name: "Pulse Repetition Frequency",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x9004,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContentQualification",
+        name: "Content Qualification",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x9329,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "CTTableDynamicsSequence",
+        name: "CT Table Dynamics Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x9337,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusAgentNumber",
+        name: "Contrast/Bolus Agent Number",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x9338,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusIngredientCodeSequence",
+        name: "Contrast/Bolus Ingredient Code Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x9341,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusUsageSequence",
+        name: "Contrast/Bolus Usage Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x9342,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusAgentAdministered",
+        name: "Contrast/Bolus Agent Administered",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x9343,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusAgentDetected",
+        name: "Contrast/Bolus Agent Detected",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x9344,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContrastBolusAgentPhase",
+        name: "Contrast/Bolus Agent Phase",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0018,
+            element: 0x9477,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "IrradiationEventIdentificationSequence",
+        name: "Irradiation Event Identification Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x000D,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StudyInstanceUID",
+        name: "Study Instance UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x000E,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SeriesInstanceUID",
+        name: "Series Instance UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StudyID",
+        name: "Study ID",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0011,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SeriesNumber",
+        name: "Series Number",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0012,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AcquisitionNumber",
+        name: "Acquisition Number",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0013,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InstanceNumber",
+        name: "Instance Number",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0014,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "IsotopeNumber",
+        name: "Isotope Number",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0019,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ItemNumber",
+        name: "Item Number",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0020,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PatientOrientation",
+        name: "Patient Orientation",
+        vrs: &[VR::CS],
+        vm: "2",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0030,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImagePosition",
+        name: "Image Position",
+        vrs: &[VR::DS],
+        vm: "3",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0032,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImagePositionPatient",
+        name: "Image Position (Patient)",
+        vrs: &[VR::DS],
+        vm: "3",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0035,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImageOrientation",
+        name: "Image Orientation",
+        vrs: &[VR::DS],
+        vm: "6",
+        retired: true,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0037,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImageOrientationPatient",
+        name: "Image Orientation (Patient)",
+        vrs: &[VR::DS],
+        vm: "6",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0052,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FrameOfReferenceUID",
+        name: "Frame of Reference UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0060,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "Laterality",
+        name: "Laterality",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0062,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImageLaterality",
+        name: "Image Laterality",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0100,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TemporalPositionIdentifier",
+        name: "Temporal Position Identifier",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x0105,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "NumberOfTemporalPositions",
+        name: "Number of Temporal Positions",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x1002,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImagesInAcquisition",
+        name: "Images in Acquisition",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x1040,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PositionReferenceIndicator",
+        name: "Position Reference Indicator",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x1041,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SliceLocation",
+        name: "Slice Location",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x4000,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ImageComments",
+        name: "Image Comments",
+        vrs: &[VR::LT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9056,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "StackID",
+        name: "Stack ID",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9057,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "InStackPositionNumber",
+        name: "In-Stack Position Number",
+        vrs: &[VR::UL],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9071,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FrameAnatomySequence",
+        name: "Frame Anatomy Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9072,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FrameLaterality",
+        name: "Frame Laterality",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9111,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FrameContentSequence",
+        name: "Frame Content Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9113,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PlanePositionSequence",
+        name: "Plane Position Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9116,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PlaneOrientationSequence",
+        name: "Plane Orientation Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9156,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FrameAcquisitionNumber",
+        name: "Frame Acquisition Number",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9157,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DimensionIndexValues",
+        name: "Dimension Index Values",
+        vrs: &[VR::UL],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9164,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DimensionOrganizationUID",
+        name: "Dimension Organization UID",
+        vrs: &[VR::UI],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9165,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DimensionIndexPointer",
+        name: "Dimension Index Pointer",
+        vrs: &[VR::AT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9167,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FunctionalGroupPointer",
+        name: "Functional Group Pointer",
+        vrs: &[VR::AT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9221,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DimensionOrganizationSequence",
+        name: "Dimension Organization Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0020,
+            element: 0x9222,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DimensionIndexSequence",
+        name: "Dimension Index Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0002,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SamplesPerPixel",
+        name: "Samples per Pixel",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0003,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SamplesPerPixelUsed",
+        name: "Samples per Pixel Used",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0004,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PhotometricInterpretation",
+        name: "Photometric Interpretation",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0006,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PlanarConfiguration",
+        name: "Planar Configuration",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0008,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "NumberOfFrames",
+        name: "Number of Frames",
+        vrs: &[VR::IS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0009,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FrameIncrementPointer",
+        name: "Frame Increment Pointer",
+        vrs: &[VR::AT],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "Rows",
+        name: "Rows",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0011,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "Columns",
+        name: "Columns",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0030,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelSpacing",
+        name: "Pixel Spacing",
+        vrs: &[VR::DS],
+        vm: "2",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0034,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelAspectRatio",
+        name: "Pixel Aspect Ratio",
+        vrs: &[VR::IS],
+        vm: "2",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0100,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "BitsAllocated",
+        name: "Bits Allocated",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0101,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "BitsStored",
+        name: "Bits Stored",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0102,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "HighBit",
+        name: "High Bit",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0103,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelRepresentation",
+        name: "Pixel Representation",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0106,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SmallestImagePixelValue",
+        name: "Smallest Image Pixel Value",
+        vrs: &[VR::US, VR::SS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0107,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LargestImagePixelValue",
+        name: "Largest Image Pixel Value",
+        vrs: &[VR::US, VR::SS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0120,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelPaddingValue",
+        name: "Pixel Padding Value",
+        vrs: &[VR::US, VR::SS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0121,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelPaddingRangeLimit",
+        name: "Pixel Padding Range Limit",
+        vrs: &[VR::US, VR::SS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x0301,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "BurnedInAnnotation",
+        name: "Burned In Annotation",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1040,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelIntensityRelationship",
+        name: "Pixel Intensity Relationship",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1050,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "WindowCenter",
+        name: "Window Center",
+        vrs: &[VR::DS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1051,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "WindowWidth",
+        name: "Window Width",
+        vrs: &[VR::DS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1052,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RescaleIntercept",
+        name: "Rescale Intercept",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1053,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RescaleSlope",
+        name: "Rescale Slope",
+        vrs: &[VR::DS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1054,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RescaleType",
+        name: "Rescale Type",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1055,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "WindowCenterWidthExplanation",
+        name: "Window Center &amp; Width Explanation",
+        vrs: &[VR::LO],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1101,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RedPaletteColorLookupTableDescriptor",
+        name: "Red Palette Color Lookup Table Descriptor",
+        vrs: &[VR::US, VR::SS],
+        vm: "3",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1102,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "GreenPaletteColorLookupTableDescriptor",
+        name: "Green Palette Color Lookup Table Descriptor",
+        vrs: &[VR::US, VR::SS],
+        vm: "3",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1103,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "BluePaletteColorLookupTableDescriptor",
+        name: "Blue Palette Color Lookup Table Descriptor",
+        vrs: &[VR::US, VR::SS],
+        vm: "3",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1201,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RedPaletteColorLookupTableData",
+        name: "Red Palette Color Lookup Table Data",
+        vrs: &[VR::OW],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1202,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "GreenPaletteColorLookupTableData",
+        name: "Green Palette Color Lookup Table Data",
+        vrs: &[VR::OW],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x1203,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "BluePaletteColorLookupTableData",
+        name: "Blue Palette Color Lookup Table Data",
+        vrs: &[VR::OW],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x2110,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LossyImageCompression",
+        name: "Lossy Image Compression",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x2112,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LossyImageCompressionRatio",
+        name: "Lossy Image Compression Ratio",
+        vrs: &[VR::DS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x2114,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LossyImageCompressionMethod",
+        name: "Lossy Image Compression Method",
+        vrs: &[VR::CS],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x3000,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ModalityLUTSequence",
+        name: "Modality LUT Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x3002,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LUTDescriptor",
+        name: "LUT Descriptor",
+        vrs: &[VR::US, VR::SS],
+        vm: "3",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x3003,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LUTExplanation",
+        name: "LUT Explanation",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x3006,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LUTData",
+        name: "LUT Data",
+        vrs: &[VR::US, VR::OW],
+        vm: "1-n",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x3010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "VOILUTSequence",
+        name: "VOI LUT Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x9110,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelMeasuresSequence",
+        name: "Pixel Measures Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x9132,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FrameVOILUTSequence",
+        name: "Frame VOI LUT Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0028,
+            element: 0x9145,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelValueTransformationSequence",
+        name: "Pixel Value Transformation Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0032,
+            element: 0x1032,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RequestingPhysician",
+        name: "Requesting Physician",
+        vrs: &[VR::PN],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0032,
+            element: 0x1060,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RequestedProcedureDescription",
+        name: "Requested Procedure Description",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x0244,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PerformedProcedureStepStartDate",
+        name: "Performed Procedure Step Start Date",
+        vrs: &[VR::DA],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x0245,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PerformedProcedureStepStartTime",
+        name: "Performed Procedure Step Start Time",
+        vrs: &[VR::TM],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x0253,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PerformedProcedureStepID",
+        name: "Performed Procedure Step ID",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x0254,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PerformedProcedureStepDescription",
+        name: "Performed Procedure Step Description",
+        vrs: &[VR::LO],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x0275,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RequestAttributesSequence",
+        name: "Request Attributes Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x0555,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "AcquisitionContextSequence",
+        name: "Acquisition Context Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x08EA,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "MeasurementUnitsCodeSequence",
+        name: "Measurement Units Code Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x1001,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RequestedProcedureID",
+        name: "Requested Procedure ID",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x9096,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RealWorldValueMappingSequence",
+        name: "Real World Value Mapping Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x9210,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "LUTLabel",
+        name: "LUT Label",
+        vrs: &[VR::SH],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x9211,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RealWorldValueLastValueMapped",
+        name: "Real World Value Last Value Mapped",
+        vrs: &[VR::US, VR::SS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x9216,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RealWorldValueFirstValueMapped",
+        name: "Real World Value First Value Mapped",
+        vrs: &[VR::US, VR::SS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x9224,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RealWorldValueIntercept",
+        name: "Real World Value Intercept",
+        vrs: &[VR::FD],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0x9225,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "RealWorldValueSlope",
+        name: "Real World Value Slope",
+        vrs: &[VR::FD],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0xA040,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ValueType",
+        name: "Value Type",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0xA043,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ConceptNameCodeSequence",
+        name: "Concept Name Code Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0xA160,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "TextValue",
+        name: "Text Value",
+        vrs: &[VR::UT],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0xA168,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ConceptCodeSequence",
+        name: "Concept Code Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x0040,
+            element: 0xA730,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ContentSequence",
+        name: "Content Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x2050,
+            element: 0x0020,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PresentationLUTShape",
+        name: "Presentation LUT Shape",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x5200,
+            element: 0x9229,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SharedFunctionalGroupsSequence",
+        name: "Shared Functional Groups Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x5200,
+            element: 0x9230,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PerFrameFunctionalGroupsSequence",
+        name: "Per-frame Functional Groups Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x7FE0,
+            element: 0x0001,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ExtendedOffsetTable",
+        name: "Extended Offset Table",
+        vrs: &[VR::OV],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x7FE0,
+            element: 0x0002,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ExtendedOffsetTableLengths",
+        name: "Extended Offset Table Lengths",
+        vrs: &[VR::OV],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x7FE0,
+            element: 0x0008,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "FloatPixelData",
+        name: "Float Pixel Data",
+        vrs: &[VR::OF],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x7FE0,
+            element: 0x0009,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DoubleFloatPixelData",
+        name: "Double Float Pixel Data",
+        vrs: &[VR::OD],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x7FE0,
+            element: 0x0010,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "PixelData",
+        name: "Pixel Data",
+        vrs: &[VR::OB, VR::OW],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0xFFFA,
+            element: 0xFFFA,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DigitalSignaturesSequence",
+        name: "Digital Signatures Sequence",
+        vrs: &[VR::SQ],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0xFFFC,
+            element: 0xFFFC,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "DataSetTrailingPadding",
+        name: "Data Set Trailing Padding",
+        vrs: &[VR::OB],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0xFFFE,
+            element: 0xE000,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "Item",
+        name: "Item",
+        vrs: &[],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0xFFFE,
+            element: 0xE00D,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "ItemDelimitationItem",
+        name: "Item Delimitation Item",
+        vrs: &[],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0xFFFE,
+            element: 0xE0DD,
+        },
+        mask: 0xFFFFFFFF,
+        keyword: "SequenceDelimitationItem",
+        name: "Sequence Delimitation Item",
+        vrs: &[],
+        vm: "1",
+        retired: false,
+    },
+];
+
+/// The data elements in repeating groups such as (60xx,3000)
+pub static REPEATING_ENTRIES: &[DictionaryEntry] = &[
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x6000,
+            element: 0x0010,
+        },
+        mask: 0xFF00FFFF,
+        keyword: "OverlayRows",
+        name: "Overlay Rows",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x6000,
+            element: 0x0011,
+        },
+        mask: 0xFF00FFFF,
+        keyword: "OverlayColumns",
+        name: "Overlay Columns",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x6000,
+            element: 0x0040,
+        },
+        mask: 0xFF00FFFF,
+        keyword: "OverlayType",
+        name: "Overlay Type",
+        vrs: &[VR::CS],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x6000,
+            element: 0x0050,
+        },
+        mask: 0xFF00FFFF,
+        keyword: "OverlayOrigin",
+        name: "Overlay Origin",
+        vrs: &[VR::SS],
+        vm: "2",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x6000,
+            element: 0x0100,
+        },
+        mask: 0xFF00FFFF,
+        keyword: "OverlayBitsAllocated",
+        name: "Overlay Bits Allocated",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x6000,
+            element: 0x0102,
+        },
+        mask: 0xFF00FFFF,
+        keyword: "OverlayBitPosition",
+        name: "Overlay Bit Position",
+        vrs: &[VR::US],
+        vm: "1",
+        retired: false,
+    },
+    DictionaryEntry {
+        tag: Tag {
+            group: 0x6000,
+            element: 0x3000,
+        },
+        mask: 0xFF00FFFF,
+        keyword: "OverlayData",
+        name: "Overlay Data",
+        vrs: &[VR::OB, VR::OW],
+        vm: "1",
+        retired: false,
+    },
+];
+
+// ENTRIES followed by REPEATING_ENTRIES ordered by keyword
+pub(crate) static KEYWORD_INDEX: &[u16] = &[
+    59, 319, 45, 49, 201, 237, 52, 152, 101, 125, 124, 279, 280, 300, 297, 157, 287, 225, 83, 80,
+    81, 88, 82, 276, 331, 329, 46, 224, 332, 53, 86, 85, 87, 156, 154, 229, 230, 226, 231, 155,
+    227, 181, 179, 228, 180, 70, 198, 48, 55, 174, 57, 342, 56, 112, 176, 341, 265, 268, 263, 267,
+    264, 24, 25, 186, 187, 339, 171, 166, 175, 150, 120, 121, 193, 191, 336, 337, 66, 0, 1, 22,
+    194, 203, 338, 197, 262, 257, 259, 274, 258, 246, 183, 127, 310, 266, 188, 196, 299, 296, 184,
+    281, 254, 248, 244, 245, 242, 243, 33, 170, 195, 251, 169, 5, 6, 202, 256, 65, 38, 35, 36, 37,
+    238, 74, 75, 73, 96, 167, 232, 126, 239, 60, 134, 136, 343, 344, 240, 164, 307, 305, 306, 323,
+    32, 284, 247, 30, 89, 301, 303, 302, 161, 172, 72, 102, 84, 320, 2, 3, 68, 67, 304, 99, 58,
+    168, 118, 273, 116, 250, 119, 151, 23, 100, 42, 140, 142, 141, 351, 350, 347, 352, 47, 349,
+    346, 54, 348, 147, 144, 137, 143, 138, 153, 133, 148, 132, 241, 204, 139, 145, 149, 146, 335,
+    317, 316, 314, 315, 98, 271, 219, 220, 215, 216, 97, 278, 340, 288, 309, 286, 285, 129, 282,
+    277, 311, 272, 261, 260, 252, 71, 333, 21, 20, 94, 178, 223, 61, 62, 14, 15, 19, 17, 18, 13,
+    16, 325, 326, 324, 322, 327, 199, 9, 12, 34, 185, 298, 295, 217, 218, 213, 214, 26, 110, 107,
+    106, 104, 128, 108, 27, 109, 28, 111, 105, 103, 29, 77, 79, 76, 78, 207, 208, 211, 212, 209,
+    210, 206, 41, 165, 318, 313, 321, 312, 291, 292, 293, 63, 190, 275, 39, 69, 40, 269, 270, 160,
+    158, 8, 11, 345, 162, 205, 159, 44, 95, 234, 236, 51, 334, 253, 163, 283, 177, 7, 113, 10, 173,
+    31, 255, 114, 115, 122, 64, 92, 123, 43, 93, 235, 233, 50, 189, 249, 330, 91, 221, 222, 4, 200,
+    182, 135, 90, 308, 328, 117, 131, 130, 289, 294, 290, 192,
+];
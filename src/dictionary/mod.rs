@@ -0,0 +1,157 @@
+use crate::tag::Tag;
+use crate::vr::VR;
+
+mod entries;
+pub mod tags;
+
+pub use entries::{ENTRIES, REPEATING_ENTRIES};
+
+/// An entry of the DICOM data dictionary (PS3.6) describing a data element
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DictionaryEntry {
+    /// The tag of the data element.  For data elements in repeating groups
+    /// such as (60xx,3000) the repeating digits are zero
+    pub tag: Tag,
+    /// The bits of the tag (group << 16 | element) that identify the data
+    /// element, the repeating digits are zero
+    pub mask: u32,
+    /// The keyword (e.g. PatientName)
+    pub keyword: &'static str,
+    /// The name (e.g. Patient's Name)
+    pub name: &'static str,
+    /// The VRs the data element may be encoded with, usually one.  Empty for
+    /// the item and delimitation data elements which have no VR
+    pub vrs: &'static [VR],
+    /// The value multiplicity (e.g. 1, 2-n)
+    pub vm: &'static str,
+    /// True if the data element is retired
+    pub retired: bool,
+}
+
+fn tag_bits(tag: Tag) -> u32 {
+    (u32::from(tag.group) << 16) | u32::from(tag.element)
+}
+
+impl DictionaryEntry {
+    /// Returns true if the tag is this data element.  Repeating groups only
+    /// include the even (public) groups
+    pub fn matches(&self, tag: Tag) -> bool {
+        tag_bits(tag) & self.mask == tag_bits(self.tag) && !tag.is_private()
+    }
+
+    /// Returns the VR of the data element or None if it has no VR.  For data
+    /// elements with multiple VRs (e.g. US or SS) the first one is returned
+    pub fn vr(&self) -> Option<VR> {
+        self.vrs.first().copied()
+    }
+}
+
+/// Returns the dictionary entry for a tag or None if the tag is not in the
+/// dictionary (e.g. private tags or standard tags outside the checked in
+/// subset of PS3.6)
+pub fn lookup(tag: Tag) -> Option<&'static DictionaryEntry> {
+    match ENTRIES.binary_search_by(|entry| entry.tag.cmp(&tag)) {
+        Ok(index) => Some(&ENTRIES[index]),
+        Err(_) => REPEATING_ENTRIES.iter().find(|entry| entry.matches(tag)),
+    }
+}
+
+/// Returns the VR of a tag for the implicit VR encoding or None if the tag
+/// is not in the dictionary.  Group length attributes (gggg,0000) are UL and
+/// data elements that may be OB or OW (e.g. pixel data) are OW
+pub fn implicit_vr(tag: Tag) -> Option<VR> {
+    if tag.element == 0x0000 {
+        return Some(VR::UL);
+    }
+    let entry = lookup(tag)?;
+    if entry.vrs.contains(&VR::OW) {
+        Some(VR::OW)
+    } else {
+        entry.vr()
+    }
+}
+
+/// Returns the dictionary entry for a keyword (e.g. PatientName) or None if
+/// the keyword is not in the dictionary
+pub fn lookup_keyword(keyword: &str) -> Option<&'static DictionaryEntry> {
+    let entry = |index: u16| {
+        let index = usize::from(index);
+        if index < ENTRIES.len() {
+            &ENTRIES[index]
+        } else {
+            &REPEATING_ENTRIES[index - ENTRIES.len()]
+        }
+    };
+    entries::KEYWORD_INDEX
+        .binary_search_by(|index| entry(*index).keyword.cmp(keyword))
+        .ok()
+        .map(|index| entry(entries::KEYWORD_INDEX[index]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{implicit_vr, lookup, lookup_keyword, tags, ENTRIES, REPEATING_ENTRIES};
+    use crate::tag::Tag;
+    use crate::vr::VR;
+
+    #[test]
+    fn entries_are_ordered_by_tag() {
+        assert!(ENTRIES.windows(2).all(|pair| pair[0].tag < pair[1].tag));
+    }
+
+    #[test]
+    fn lookup_returns_entry() {
+        let entry = lookup(Tag::new(0x0010, 0x0010)).unwrap();
+        assert_eq!(entry.keyword, "PatientName");
+        assert_eq!(entry.name, "Patient's Name");
+        assert_eq!(entry.vr(), Some(VR::PN));
+        assert_eq!(entry.vm, "1");
+        assert!(!entry.retired);
+
+        let entry = lookup(tags::PIXEL_DATA).unwrap();
+        assert_eq!(entry.vrs, &[VR::OB, VR::OW]);
+        assert!(lookup(tags::LENGTH_TO_END).unwrap().retired);
+        assert_eq!(lookup(tags::ITEM).unwrap().vr(), None);
+    }
+
+    #[test]
+    fn lookup_repeating_group() {
+        let entry = lookup(Tag::new(0x6002, 0x3000)).unwrap();
+        assert_eq!(entry.keyword, "OverlayData");
+        assert_eq!(entry.tag, tags::OVERLAY_DATA);
+        assert!(lookup(Tag::new(0x6003, 0x3000)).is_none());
+    }
+
+    #[test]
+    fn lookup_unknown_tag_returns_none() {
+        assert!(lookup(Tag::new(0x0009, 0x0010)).is_none());
+        assert!(lookup(Tag::new(0x0010, 0x0011)).is_none());
+    }
+
+    #[test]
+    fn lookup_keyword_returns_entry() {
+        assert_eq!(
+            lookup_keyword("StudyInstanceUID").unwrap().tag,
+            tags::STUDY_INSTANCE_UID
+        );
+        assert_eq!(
+            lookup_keyword("OverlayRows").unwrap().tag,
+            Tag::new(0x6000, 0x0010)
+        );
+        assert!(lookup_keyword("NotAKeyword").is_none());
+        for entry in ENTRIES.iter().chain(REPEATING_ENTRIES) {
+            assert_eq!(lookup_keyword(entry.keyword), Some(entry));
+        }
+    }
+
+    #[test]
+    fn implicit_vr_returns_vr() {
+        assert_eq!(implicit_vr(tags::PATIENT_NAME), Some(VR::PN));
+        assert_eq!(implicit_vr(Tag::new(0x0028, 0x0000)), Some(VR::UL));
+        assert_eq!(implicit_vr(Tag::new(0x0009, 0x0000)), Some(VR::UL));
+        assert_eq!(implicit_vr(Tag::new(0x6002, 0x3000)), Some(VR::OW));
+        assert_eq!(implicit_vr(tags::PIXEL_DATA), Some(VR::OW));
+        assert_eq!(implicit_vr(tags::ITEM), None);
+        assert_eq!(implicit_vr(Tag::new(0x0009, 0x1010)), None);
+    }
+}
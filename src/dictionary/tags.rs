@@ -0,0 +1,1067 @@
+// Subset of the PS3.6 data dictionary with the commonly used data elements in
+// the format written by scripts/generate_dictionary.py.  Run the script on
+// part06.xml to replace it with the full dictionary
+//! Tag constants named after the keywords of the data dictionary.  Data
+//! elements in repeating groups use the first group (e.g. 6000 for 60xx).
+
+use crate::tag::Tag;
+
+/// File Meta Information Group Length
+pub const FILE_META_INFORMATION_GROUP_LENGTH: Tag = Tag::new(0x0002, 0x0000);
+
+/// File Meta Information Version
+pub const FILE_META_INFORMATION_VERSION: Tag = Tag::new(0x0002, 0x0001);
+
+/// Media Storage SOP Class UID
+pub const MEDIA_STORAGE_SOP_CLASS_UID: Tag = Tag::new(0x0002, 0x0002);
+
+/// Media Storage SOP Instance UID
+pub const MEDIA_STORAGE_SOP_INSTANCE_UID: Tag = Tag::new(0x0002, 0x0003);
+
+/// Transfer Syntax UID
+pub const TRANSFER_SYNTAX_UID: Tag = Tag::new(0x0002, 0x0010);
+
+/// Implementation Class UID
+pub const IMPLEMENTATION_CLASS_UID: Tag = Tag::new(0x0002, 0x0012);
+
+/// Implementation Version Name
+pub const IMPLEMENTATION_VERSION_NAME: Tag = Tag::new(0x0002, 0x0013);
+
+/// Source Application Entity Title
+pub const SOURCE_APPLICATION_ENTITY_TITLE: Tag = Tag::new(0x0002, 0x0016);
+
+/// Sending Application Entity Title
+pub const SENDING_APPLICATION_ENTITY_TITLE: Tag = Tag::new(0x0002, 0x0017);
+
+/// Receiving Application Entity Title
+pub const RECEIVING_APPLICATION_ENTITY_TITLE: Tag = Tag::new(0x0002, 0x0018);
+
+/// Source Presentation Address
+pub const SOURCE_PRESENTATION_ADDRESS: Tag = Tag::new(0x0002, 0x0026);
+
+/// Sending Presentation Address
+pub const SENDING_PRESENTATION_ADDRESS: Tag = Tag::new(0x0002, 0x0027);
+
+/// Receiving Presentation Address
+pub const RECEIVING_PRESENTATION_ADDRESS: Tag = Tag::new(0x0002, 0x0028);
+
+/// RTV Meta Information Version
+pub const RTV_META_INFORMATION_VERSION: Tag = Tag::new(0x0002, 0x0031);
+
+/// RTV Communication SOP Class UID
+pub const RTV_COMMUNICATION_SOP_CLASS_UID: Tag = Tag::new(0x0002, 0x0032);
+
+/// RTV Communication SOP Instance UID
+pub const RTV_COMMUNICATION_SOP_INSTANCE_UID: Tag = Tag::new(0x0002, 0x0033);
+
+/// RTV Source Identifier
+pub const RTV_SOURCE_IDENTIFIER: Tag = Tag::new(0x0002, 0x0035);
+
+/// RTV Flow Identifier
+pub const RTV_FLOW_IDENTIFIER: Tag = Tag::new(0x0002, 0x0036);
+
+/// RTV Flow RTP Sampling Rate
+pub const RTV_FLOW_RTP_SAMPLING_RATE: Tag = Tag::new(0x0002, 0x0037);
+
+/// RTV Flow Actual Frame Duration
+pub const RTV_FLOW_ACTUAL_FRAME_DURATION: Tag = Tag::new(0x0002, 0x0038);
+
+/// Private Information Creator UID
+pub const PRIVATE_INFORMATION_CREATOR_UID: Tag = Tag::new(0x0002, 0x0100);
+
+/// Private Information
+pub const PRIVATE_INFORMATION: Tag = Tag::new(0x0002, 0x0102);
+
+/// File-set ID
+pub const FILE_SET_ID: Tag = Tag::new(0x0004, 0x1130);
+
+/// Offset of the First Directory Record of the Root Directory Entity
+pub const OFFSET_OF_THE_FIRST_DIRECTORY_RECORD_OF_THE_ROOT_DIRECTORY_ENTITY: Tag =
+    Tag::new(0x0004, 0x1200);
+
+/// Directory Record Sequence
+pub const DIRECTORY_RECORD_SEQUENCE: Tag = Tag::new(0x0004, 0x1220);
+
+/// Directory Record Type
+pub const DIRECTORY_RECORD_TYPE: Tag = Tag::new(0x0004, 0x1430);
+
+/// Referenced File ID
+pub const REFERENCED_FILE_ID: Tag = Tag::new(0x0004, 0x1500);
+
+/// Referenced SOP Class UID in File
+pub const REFERENCED_SOP_CLASS_UID_IN_FILE: Tag = Tag::new(0x0004, 0x1510);
+
+/// Referenced SOP Instance UID in File
+pub const REFERENCED_SOP_INSTANCE_UID_IN_FILE: Tag = Tag::new(0x0004, 0x1511);
+
+/// Referenced Transfer Syntax UID in File
+pub const REFERENCED_TRANSFER_SYNTAX_UID_IN_FILE: Tag = Tag::new(0x0004, 0x1512);
+
+/// Length to End (retired)
+pub const LENGTH_TO_END: Tag = Tag::new(0x0008, 0x0001);
+
+/// Specific Character Set
+pub const SPECIFIC_CHARACTER_SET: Tag = Tag::new(0x0008, 0x0005);
+
+/// Language Code Sequence
+pub const LANGUAGE_CODE_SEQUENCE: Tag = Tag::new(0x0008, 0x0006);
+
+/// Image Type
+pub const IMAGE_TYPE: Tag = Tag::new(0x0008, 0x0008);
+
+/// Recognition Code (retired)
+pub const RECOGNITION_CODE: Tag = Tag::new(0x0008, 0x0010);
+
+/// Instance Creation Date
+pub const INSTANCE_CREATION_DATE: Tag = Tag::new(0x0008, 0x0012);
+
+/// Instance Creation Time
+pub const INSTANCE_CREATION_TIME: Tag = Tag::new(0x0008, 0x0013);
+
+/// Instance Creator UID
+pub const INSTANCE_CREATOR_UID: Tag = Tag::new(0x0008, 0x0014);
+
+/// Instance Coercion DateTime
+pub const INSTANCE_COERCION_DATE_TIME: Tag = Tag::new(0x0008, 0x0015);
+
+/// SOP Class UID
+pub const SOP_CLASS_UID: Tag = Tag::new(0x0008, 0x0016);
+
+/// SOP Instance UID
+pub const SOP_INSTANCE_UID: Tag = Tag::new(0x0008, 0x0018);
+
+/// Related General SOP Class UID
+pub const RELATED_GENERAL_SOP_CLASS_UID: Tag = Tag::new(0x0008, 0x001A);
+
+/// Original Specialized SOP Class UID
+pub const ORIGINAL_SPECIALIZED_SOP_CLASS_UID: Tag = Tag::new(0x0008, 0x001B);
+
+/// Study Date
+pub const STUDY_DATE: Tag = Tag::new(0x0008, 0x0020);
+
+/// Series Date
+pub const SERIES_DATE: Tag = Tag::new(0x0008, 0x0021);
+
+/// Acquisition Date
+pub const ACQUISITION_DATE: Tag = Tag::new(0x0008, 0x0022);
+
+/// Content Date
+pub const CONTENT_DATE: Tag = Tag::new(0x0008, 0x0023);
+
+/// Overlay Date (retired)
+pub const OVERLAY_DATE: Tag = Tag::new(0x0008, 0x0024);
+
+/// Curve Date (retired)
+pub const CURVE_DATE: Tag = Tag::new(0x0008, 0x0025);
+
+/// Acquisition DateTime
+pub const ACQUISITION_DATE_TIME: Tag = Tag::new(0x0008, 0x002A);
+
+/// Study Time
+pub const STUDY_TIME: Tag = Tag::new(0x0008, 0x0030);
+
+/// Series Time
+pub const SERIES_TIME: Tag = Tag::new(0x0008, 0x0031);
+
+/// Acquisition Time
+pub const ACQUISITION_TIME: Tag = Tag::new(0x0008, 0x0032);
+
+/// Content Time
+pub const CONTENT_TIME: Tag = Tag::new(0x0008, 0x0033);
+
+/// Overlay Time (retired)
+pub const OVERLAY_TIME: Tag = Tag::new(0x0008, 0x0034);
+
+/// Curve Time (retired)
+pub const CURVE_TIME: Tag = Tag::new(0x0008, 0x0035);
+
+/// Data Set Type (retired)
+pub const DATA_SET_TYPE: Tag = Tag::new(0x0008, 0x0040);
+
+/// Data Set Subtype (retired)
+pub const DATA_SET_SUBTYPE: Tag = Tag::new(0x0008, 0x0041);
+
+/// Nuclear Medicine Series Type (retired)
+pub const NUCLEAR_MEDICINE_SERIES_TYPE: Tag = Tag::new(0x0008, 0x0042);
+
+/// Accession Number
+pub const ACCESSION_NUMBER: Tag = Tag::new(0x0008, 0x0050);
+
+/// Issuer of Accession Number Sequence
+pub const ISSUER_OF_ACCESSION_NUMBER_SEQUENCE: Tag = Tag::new(0x0008, 0x0051);
+
+/// Query/Retrieve Level
+pub const QUERY_RETRIEVE_LEVEL: Tag = Tag::new(0x0008, 0x0052);
+
+/// Query/Retrieve View
+pub const QUERY_RETRIEVE_VIEW: Tag = Tag::new(0x0008, 0x0053);
+
+/// Retrieve AE Title
+pub const RETRIEVE_AE_TITLE: Tag = Tag::new(0x0008, 0x0054);
+
+/// Station AE Title
+pub const STATION_AE_TITLE: Tag = Tag::new(0x0008, 0x0055);
+
+/// Instance Availability
+pub const INSTANCE_AVAILABILITY: Tag = Tag::new(0x0008, 0x0056);
+
+/// Failed SOP Instance UID List
+pub const FAILED_SOP_INSTANCE_UID_LIST: Tag = Tag::new(0x0008, 0x0058);
+
+/// Modality
+pub const MODALITY: Tag = Tag::new(0x0008, 0x0060);
+
+/// Modalities in Study
+pub const MODALITIES_IN_STUDY: Tag = Tag::new(0x0008, 0x0061);
+
+/// SOP Classes in Study
+pub const SOP_CLASSES_IN_STUDY: Tag = Tag::new(0x0008, 0x0062);
+
+/// Conversion Type
+pub const CONVERSION_TYPE: Tag = Tag::new(0x0008, 0x0064);
+
+/// Presentation Intent Type
+pub const PRESENTATION_INTENT_TYPE: Tag = Tag::new(0x0008, 0x0068);
+
+/// Manufacturer
+pub const MANUFACTURER: Tag = Tag::new(0x0008, 0x0070);
+
+/// Institution Name
+pub const INSTITUTION_NAME: Tag = Tag::new(0x0008, 0x0080);
+
+/// Institution Address
+pub const INSTITUTION_ADDRESS: Tag = Tag::new(0x0008, 0x0081);
+
+/// Institution Code Sequence
+pub const INSTITUTION_CODE_SEQUENCE: Tag = Tag::new(0x0008, 0x0082);
+
+/// Referring Physician's Name
+pub const REFERRING_PHYSICIAN_NAME: Tag = Tag::new(0x0008, 0x0090);
+
+/// Referring Physician's Address
+pub const REFERRING_PHYSICIAN_ADDRESS: Tag = Tag::new(0x0008, 0x0092);
+
+/// Referring Physician's Telephone Numbers
+pub const REFERRING_PHYSICIAN_TELEPHONE_NUMBERS: Tag = Tag::new(0x0008, 0x0094);
+
+/// Referring Physician Identification Sequence
+pub const REFERRING_PHYSICIAN_IDENTIFICATION_SEQUENCE: Tag = Tag::new(0x0008, 0x0096);
+
+/// Code Value
+pub const CODE_VALUE: Tag = Tag::new(0x0008, 0x0100);
+
+/// Coding Scheme Designator
+pub const CODING_SCHEME_DESIGNATOR: Tag = Tag::new(0x0008, 0x0102);
+
+/// Coding Scheme Version
+pub const CODING_SCHEME_VERSION: Tag = Tag::new(0x0008, 0x0103);
+
+/// Code Meaning
+pub const CODE_MEANING: Tag = Tag::new(0x0008, 0x0104);
+
+/// Mapping Resource
+pub const MAPPING_RESOURCE: Tag = Tag::new(0x0008, 0x0105);
+
+/// Context Group Version
+pub const CONTEXT_GROUP_VERSION: Tag = Tag::new(0x0008, 0x0106);
+
+/// Context Group Local Version
+pub const CONTEXT_GROUP_LOCAL_VERSION: Tag = Tag::new(0x0008, 0x0107);
+
+/// Context Identifier
+pub const CONTEXT_IDENTIFIER: Tag = Tag::new(0x0008, 0x010F);
+
+/// Coding Scheme Identification Sequence
+pub const CODING_SCHEME_IDENTIFICATION_SEQUENCE: Tag = Tag::new(0x0008, 0x0110);
+
+/// Long Code Value
+pub const LONG_CODE_VALUE: Tag = Tag::new(0x0008, 0x0119);
+
+/// URN Code Value
+pub const URN_CODE_VALUE: Tag = Tag::new(0x0008, 0x0120);
+
+/// Timezone Offset From UTC
+pub const TIMEZONE_OFFSET_FROM_UTC: Tag = Tag::new(0x0008, 0x0201);
+
+/// Station Name
+pub const STATION_NAME: Tag = Tag::new(0x0008, 0x1010);
+
+/// Study Description
+pub const STUDY_DESCRIPTION: Tag = Tag::new(0x0008, 0x1030);
+
+/// Procedure Code Sequence
+pub const PROCEDURE_CODE_SEQUENCE: Tag = Tag::new(0x0008, 0x1032);
+
+/// Series Description
+pub const SERIES_DESCRIPTION: Tag = Tag::new(0x0008, 0x103E);
+
+/// Institutional Department Name
+pub const INSTITUTIONAL_DEPARTMENT_NAME: Tag = Tag::new(0x0008, 0x1040);
+
+/// Physician(s) of Record
+pub const PHYSICIANS_OF_RECORD: Tag = Tag::new(0x0008, 0x1048);
+
+/// Performing Physician's Name
+pub const PERFORMING_PHYSICIAN_NAME: Tag = Tag::new(0x0008, 0x1050);
+
+/// Name of Physician(s) Reading Study
+pub const NAME_OF_PHYSICIANS_READING_STUDY: Tag = Tag::new(0x0008, 0x1060);
+
+/// Operators' Name
+pub const OPERATORS_NAME: Tag = Tag::new(0x0008, 0x1070);
+
+/// Admitting Diagnoses Description
+pub const ADMITTING_DIAGNOSES_DESCRIPTION: Tag = Tag::new(0x0008, 0x1080);
+
+/// Manufacturer's Model Name
+pub const MANUFACTURER_MODEL_NAME: Tag = Tag::new(0x0008, 0x1090);
+
+/// Referenced Study Sequence
+pub const REFERENCED_STUDY_SEQUENCE: Tag = Tag::new(0x0008, 0x1110);
+
+/// Referenced Performed Procedure Step Sequence
+pub const REFERENCED_PERFORMED_PROCEDURE_STEP_SEQUENCE: Tag = Tag::new(0x0008, 0x1111);
+
+/// Referenced Series Sequence
+pub const REFERENCED_SERIES_SEQUENCE: Tag = Tag::new(0x0008, 0x1115);
+
+/// Referenced Patient Sequence
+pub const REFERENCED_PATIENT_SEQUENCE: Tag = Tag::new(0x0008, 0x1120);
+
+/// Referenced Image Sequence
+pub const REFERENCED_IMAGE_SEQUENCE: Tag = Tag::new(0x0008, 0x1140);
+
+/// Referenced SOP Class UID
+pub const REFERENCED_SOP_CLASS_UID: Tag = Tag::new(0x0008, 0x1150);
+
+/// Referenced SOP Instance UID
+pub const REFERENCED_SOP_INSTANCE_UID: Tag = Tag::new(0x0008, 0x1155);
+
+/// Referenced Frame Number
+pub const REFERENCED_FRAME_NUMBER: Tag = Tag::new(0x0008, 0x1160);
+
+/// Referenced SOP Sequence
+pub const REFERENCED_SOP_SEQUENCE: Tag = Tag::new(0x0008, 0x1199);
+
+/// Derivation Description
+pub const DERIVATION_DESCRIPTION: Tag = Tag::new(0x0008, 0x2111);
+
+/// Source Image Sequence
+pub const SOURCE_IMAGE_SEQUENCE: Tag = Tag::new(0x0008, 0x2112);
+
+/// Stage Name
+pub const STAGE_NAME: Tag = Tag::new(0x0008, 0x2120);
+
+/// Stage Number
+pub const STAGE_NUMBER: Tag = Tag::new(0x0008, 0x2122);
+
+/// Number of Stages
+pub const NUMBER_OF_STAGES: Tag = Tag::new(0x0008, 0x2124);
+
+/// View Number
+pub const VIEW_NUMBER: Tag = Tag::new(0x0008, 0x2128);
+
+/// Number of Event Timers
+pub const NUMBER_OF_EVENT_TIMERS: Tag = Tag::new(0x0008, 0x2129);
+
+/// Number of Views in Stage
+pub const NUMBER_OF_VIEWS_IN_STAGE: Tag = Tag::new(0x0008, 0x212A);
+
+/// Event Elapsed Time(s)
+pub const EVENT_ELAPSED_TIMES: Tag = Tag::new(0x0008, 0x2130);
+
+/// Event Timer Name(s)
+pub const EVENT_TIMER_NAMES: Tag = Tag::new(0x0008, 0x2132);
+
+/// Start Trim
+pub const START_TRIM: Tag = Tag::new(0x0008, 0x2142);
+
+/// Stop Trim
+pub const STOP_TRIM: Tag = Tag::new(0x0008, 0x2143);
+
+/// Anatomic Region Sequence
+pub const ANATOMIC_REGION_SEQUENCE: Tag = Tag::new(0x0008, 0x2218);
+
+/// Alternate Representation Sequence
+pub const ALTERNATE_REPRESENTATION_SEQUENCE: Tag = Tag::new(0x0008, 0x3001);
+
+/// Irradiation Event UID
+pub const IRRADIATION_EVENT_UID: Tag = Tag::new(0x0008, 0x3010);
+
+/// Frame Type
+pub const FRAME_TYPE: Tag = Tag::new(0x0008, 0x9007);
+
+/// Referenced Raw Data Sequence
+pub const REFERENCED_RAW_DATA_SEQUENCE: Tag = Tag::new(0x0008, 0x9121);
+
+/// Pixel Presentation
+pub const PIXEL_PRESENTATION: Tag = Tag::new(0x0008, 0x9205);
+
+/// Volumetric Properties
+pub const VOLUMETRIC_PROPERTIES: Tag = Tag::new(0x0008, 0x9206);
+
+/// Volume Based Calculation Technique
+pub const VOLUME_BASED_CALCULATION_TECHNIQUE: Tag = Tag::new(0x0008, 0x9207);
+
+/// Patient's Name
+pub const PATIENT_NAME: Tag = Tag::new(0x0010, 0x0010);
+
+/// Patient ID
+pub const PATIENT_ID: Tag = Tag::new(0x0010, 0x0020);
+
+/// Issuer of Patient ID
+pub const ISSUER_OF_PATIENT_ID: Tag = Tag::new(0x0010, 0x0021);
+
+/// Type of Patient ID
+pub const TYPE_OF_PATIENT_ID: Tag = Tag::new(0x0010, 0x0022);
+
+/// Issuer of Patient ID Qualifiers Sequence
+pub const ISSUER_OF_PATIENT_ID_QUALIFIERS_SEQUENCE: Tag = Tag::new(0x0010, 0x0024);
+
+/// Patient's Birth Date
+pub const PATIENT_BIRTH_DATE: Tag = Tag::new(0x0010, 0x0030);
+
+/// Patient's Birth Time
+pub const PATIENT_BIRTH_TIME: Tag = Tag::new(0x0010, 0x0032);
+
+/// Patient's Sex
+pub const PATIENT_SEX: Tag = Tag::new(0x0010, 0x0040);
+
+/// Other Patient IDs (retired)
+pub const OTHER_PATIENT_IDS: Tag = Tag::new(0x0010, 0x1000);
+
+/// Other Patient Names
+pub const OTHER_PATIENT_NAMES: Tag = Tag::new(0x0010, 0x1001);
+
+/// Other Patient IDs Sequence
+pub const OTHER_PATIENT_IDS_SEQUENCE: Tag = Tag::new(0x0010, 0x1002);
+
+/// Patient's Birth Name
+pub const PATIENT_BIRTH_NAME: Tag = Tag::new(0x0010, 0x1005);
+
+/// Patient's Age
+pub const PATIENT_AGE: Tag = Tag::new(0x0010, 0x1010);
+
+/// Patient's Size
+pub const PATIENT_SIZE: Tag = Tag::new(0x0010, 0x1020);
+
+/// Patient's Weight
+pub const PATIENT_WEIGHT: Tag = Tag::new(0x0010, 0x1030);
+
+/// Patient's Address
+pub const PATIENT_ADDRESS: Tag = Tag::new(0x0010, 0x1040);
+
+/// Patient's Mother's Birth Name
+pub const PATIENT_MOTHER_BIRTH_NAME: Tag = Tag::new(0x0010, 0x1060);
+
+/// Patient's Telephone Numbers
+pub const PATIENT_TELEPHONE_NUMBERS: Tag = Tag::new(0x0010, 0x2154);
+
+/// Ethnic Group
+pub const ETHNIC_GROUP: Tag = Tag::new(0x0010, 0x2160);
+
+/// Occupation
+pub const OCCUPATION: Tag = Tag::new(0x0010, 0x2180);
+
+/// Additional Patient History
+pub const ADDITIONAL_PATIENT_HISTORY: Tag = Tag::new(0x0010, 0x21B0);
+
+/// Patient Comments
+pub const PATIENT_COMMENTS: Tag = Tag::new(0x0010, 0x4000);
+
+/// Contrast/Bolus Agent
+pub const CONTRAST_BOLUS_AGENT: Tag = Tag::new(0x0018, 0x0010);
+
+/// Contrast/Bolus Agent Sequence
+pub const CONTRAST_BOLUS_AGENT_SEQUENCE: Tag = Tag::new(0x0018, 0x0012);
+
+/// Contrast/Bolus Administration Route Sequence
+pub const CONTRAST_BOLUS_ADMINISTRATION_ROUTE_SEQUENCE: Tag = Tag::new(0x0018, 0x0014);
+
+/// Body Part Examined
+pub const BODY_PART_EXAMINED: Tag = Tag::new(0x0018, 0x0015);
+
+/// Scanning Sequence
+pub const SCANNING_SEQUENCE: Tag = Tag::new(0x0018, 0x0020);
+
+/// Sequence Variant
+pub const SEQUENCE_VARIANT: Tag = Tag::new(0x0018, 0x0021);
+
+/// Scan Options
+pub const SCAN_OPTIONS: Tag = Tag::new(0x0018, 0x0022);
+
+/// MR Acquisition Type
+pub const MR_ACQUISITION_TYPE: Tag = Tag::new(0x0018, 0x0023);
+
+/// Sequence Name
+pub const SEQUENCE_NAME: Tag = Tag::new(0x0018, 0x0024);
+
+/// Slice Thickness
+pub const SLICE_THICKNESS: Tag = Tag::new(0x0018, 0x0050);
+
+/// KVP
+pub const KVP: Tag = Tag::new(0x0018, 0x0060);
+
+/// Repetition Time
+pub const REPETITION_TIME: Tag = Tag::new(0x0018, 0x0080);
+
+/// Echo Time
+pub const ECHO_TIME: Tag = Tag::new(0x0018, 0x0081);
+
+/// Inversion Time
+pub const INVERSION_TIME: Tag = Tag::new(0x0018, 0x0082);
+
+/// Number of Averages
+pub const NUMBER_OF_AVERAGES: Tag = Tag::new(0x0018, 0x0083);
+
+/// Imaging Frequency
+pub const IMAGING_FREQUENCY: Tag = Tag::new(0x0018, 0x0084);
+
+/// Imaged Nucleus
+pub const IMAGED_NUCLEUS: Tag = Tag::new(0x0018, 0x0085);
+
+/// Echo Number(s)
+pub const ECHO_NUMBERS: Tag = Tag::new(0x0018, 0x0086);
+
+/// Magnetic Field Strength
+pub const MAGNETIC_FIELD_STRENGTH: Tag = Tag::new(0x0018, 0x0087);
+
+/// Spacing Between Slices
+pub const SPACING_BETWEEN_SLICES: Tag = Tag::new(0x0018, 0x0088);
+
+/// Data Collection Diameter
+pub const DATA_COLLECTION_DIAMETER: Tag = Tag::new(0x0018, 0x0090);
+
+/// Echo Train Length
+pub const ECHO_TRAIN_LENGTH: Tag = Tag::new(0x0018, 0x0091);
+
+/// Device Serial Number
+pub const DEVICE_SERIAL_NUMBER: Tag = Tag::new(0x0018, 0x1000);
+
+/// Software Versions
+pub const SOFTWARE_VERSIONS: Tag = Tag::new(0x0018, 0x1020);
+
+/// Protocol Name
+pub const PROTOCOL_NAME: Tag = Tag::new(0x0018, 0x1030);
+
+/// Contrast/Bolus Route
+pub const CONTRAST_BOLUS_ROUTE: Tag = Tag::new(0x0018, 0x1040);
+
+/// Contrast/Bolus Volume
+pub const CONTRAST_BOLUS_VOLUME: Tag = Tag::new(0x0018, 0x1041);
+
+/// Contrast/Bolus Ingredient Concentration
+pub const CONTRAST_BOLUS_INGREDIENT_CONCENTRATION: Tag = Tag::new(0x0018, 0x1049);
+
+/// Trigger Time
+pub const TRIGGER_TIME: Tag = Tag::new(0x0018, 0x1060);
+
+/// Frame Time Vector
+pub const FRAME_TIME_VECTOR: Tag = Tag::new(0x0018, 0x1065);
+
+/// Heart Rate
+pub const HEART_RATE: Tag = Tag::new(0x0018, 0x1088);
+
+/// Reconstruction Diameter
+pub const RECONSTRUCTION_DIAMETER: Tag = Tag::new(0x0018, 0x1100);
+
+/// Distance Source to Detector
+pub const DISTANCE_SOURCE_TO_DETECTOR: Tag = Tag::new(0x0018, 0x1110);
+
+/// Distance Source to Patient
+pub const DISTANCE_SOURCE_TO_PATIENT: Tag = Tag::new(0x0018, 0x1111);
+
+/// Gantry/Detector Tilt
+pub const GANTRY_DETECTOR_TILT: Tag = Tag::new(0x0018, 0x1120);
+
+/// Table Height
+pub const TABLE_HEIGHT: Tag = Tag::new(0x0018, 0x1130);
+
+/// Rotation Direction
+pub const ROTATION_DIRECTION: Tag = Tag::new(0x0018, 0x1140);
+
+/// Exposure Time
+pub const EXPOSURE_TIME: Tag = Tag::new(0x0018, 0x1150);
+
+/// X-Ray Tube Current
+pub const X_RAY_TUBE_CURRENT: Tag = Tag::new(0x0018, 0x1151);
+
+/// Exposure
+pub const EXPOSURE: Tag = Tag::new(0x0018, 0x1152);
+
+/// Filter Type
+pub const FILTER_TYPE: Tag = Tag::new(0x0018, 0x1160);
+
+/// Imager Pixel Spacing
+pub const IMAGER_PIXEL_SPACING: Tag = Tag::new(0x0018, 0x1164);
+
+/// Generator Power
+pub const GENERATOR_POWER: Tag = Tag::new(0x0018, 0x1170);
+
+/// Focal Spot(s)
+pub const FOCAL_SPOTS: Tag = Tag::new(0x0018, 0x1190);
+
+/// Convolution Kernel
+pub const CONVOLUTION_KERNEL: Tag = Tag::new(0x0018, 0x1210);
+
+/// Receive Coil Name
+pub const RECEIVE_COIL_NAME: Tag = Tag::new(0x0018, 0x1250);
+
+/// Transmit Coil Name
+pub const TRANSMIT_COIL_NAME: Tag = Tag::new(0x0018, 0x1251);
+
+/// Acquisition Matrix
+pub const ACQUISITION_MATRIX: Tag = Tag::new(0x0018, 0x1310);
+
+/// In-plane Phase Encoding Direction
+pub const IN_PLANE_PHASE_ENCODING_DIRECTION: Tag = Tag::new(0x0018, 0x1312);
+
+/// Flip Angle
+pub const FLIP_ANGLE: Tag = Tag::new(0x0018, 0x1314);
+
+/// Patient Position
+pub const PATIENT_POSITION: Tag = Tag::new(0x0018, 0x5100);
+
+/// Sequence of Ultrasound Regions
+pub const SEQUENCE_OF_ULTRASOUND_REGIONS: Tag = Tag::new(0x0018, 0x6011);
+
+/// Region Spatial Format
+pub const REGION_SPATIAL_FORMAT: Tag = Tag::new(0x0018, 0x6012);
+
+/// Region Data Type
+pub const REGION_DATA_TYPE: Tag = Tag::new(0x0018, 0x6014);
+
+/// Region Flags
+pub const REGION_FLAGS: Tag = Tag::new(0x0018, 0x6016);
+
+/// Region Location Min X0
+pub const REGION_LOCATION_MIN_X0: Tag = Tag::new(0x0018, 0x6018);
+
+/// Region Location Min Y0
+pub const REGION_LOCATION_MIN_Y0: Tag = Tag::new(0x0018, 0x601A);
+
+/// Region Location Max X1
+pub const REGION_LOCATION_MAX_X1: Tag = Tag::new(0x0018, 0x601C);
+
+/// Region Location Max Y1
+pub const REGION_LOCATION_MAX_Y1: Tag = Tag::new(0x0018, 0x601E);
+
+/// Reference Pixel X0
+pub const REFERENCE_PIXEL_X0: Tag = Tag::new(0x0018, 0x6020);
+
+/// Reference Pixel Y0
+pub const REFERENCE_PIXEL_Y0: Tag = Tag::new(0x0018, 0x6022);
+
+/// Physical Units X Direction
+pub const PHYSICAL_UNITS_X_DIRECTION: Tag = Tag::new(0x0018, 0x6024);
+
+/// Physical Units Y Direction
+pub const PHYSICAL_UNITS_Y_DIRECTION: Tag = Tag::new(0x0018, 0x6026);
+
+/// Reference Pixel Physical Value X
+pub const REFERENCE_PIXEL_PHYSICAL_VALUE_X: Tag = Tag::new(0x0018, 0x6028);
+
+/// Reference Pixel Physical Value Y
+pub const REFERENCE_PIXEL_PHYSICAL_VALUE_Y: Tag = Tag::new(0x0018, 0x602A);
+
+/// Physical Delta X
+pub const PHYSICAL_DELTA_X: Tag = Tag::new(0x0018, 0x602C);
+
+/// Physical Delta Y
+pub const PHYSICAL_DELTA_Y: Tag = Tag::new(0x0018, 0x602E);
+
+/// Transducer Frequency
+pub const TRANSDUCER_FREQUENCY: Tag = Tag::new(0x0018, 0x6030);
+
+/// Transducer Type
+pub const TRANSDUCER_TYPE: Tag = Tag::new(0x0018, 0x6031);
+
+/// Pulse Repetition Frequency
+pub const PULSE_REPETITION_FREQUENCY: Tag = Tag::new(0x0018, 0x6032);
+
+/// Content Qualification
+pub const CONTENT_QUALIFICATION: Tag = Tag::new(0x0018, 0x9004);
+
+/// CT Table Dynamics Sequence
+pub const CT_TABLE_DYNAMICS_SEQUENCE: Tag = Tag::new(0x0018, 0x9329);
+
+/// Contrast/Bolus Agent Number
+pub const CONTRAST_BOLUS_AGENT_NUMBER: Tag = Tag::new(0x0018, 0x9337);
+
+/// Contrast/Bolus Ingredient Code Sequence
+pub const CONTRAST_BOLUS_INGREDIENT_CODE_SEQUENCE: Tag = Tag::new(0x0018, 0x9338);
+
+/// Contrast/Bolus Usage Sequence
+pub const CONTRAST_BOLUS_USAGE_SEQUENCE: Tag = Tag::new(0x0018, 0x9341);
+
+/// Contrast/Bolus Agent Administered
+pub const CONTRAST_BOLUS_AGENT_ADMINISTERED: Tag = Tag::new(0x0018, 0x9342);
+
+/// Contrast/Bolus Agent Detected
+pub const CONTRAST_BOLUS_AGENT_DETECTED: Tag = Tag::new(0x0018, 0x9343);
+
+/// Contrast/Bolus Agent Phase
+pub const CONTRAST_BOLUS_AGENT_PHASE: Tag = Tag::new(0x0018, 0x9344);
+
+/// Irradiation Event Identification Sequence
+pub const IRRADIATION_EVENT_IDENTIFICATION_SEQUENCE: Tag = Tag::new(0x0018, 0x9477);
+
+/// Study Instance UID
+pub const STUDY_INSTANCE_UID: Tag = Tag::new(0x0020, 0x000D);
+
+/// Series Instance UID
+pub const SERIES_INSTANCE_UID: Tag = Tag::new(0x0020, 0x000E);
+
+/// Study ID
+pub const STUDY_ID: Tag = Tag::new(0x0020, 0x0010);
+
+/// Series Number
+pub const SERIES_NUMBER: Tag = Tag::new(0x0020, 0x0011);
+
+/// Acquisition Number
+pub const ACQUISITION_NUMBER: Tag = Tag::new(0x0020, 0x0012);
+
+/// Instance Number
+pub const INSTANCE_NUMBER: Tag = Tag::new(0x0020, 0x0013);
+
+/// Isotope Number (retired)
+pub const ISOTOPE_NUMBER: Tag = Tag::new(0x0020, 0x0014);
+
+/// Item Number
+pub const ITEM_NUMBER: Tag = Tag::new(0x0020, 0x0019);
+
+/// Patient Orientation
+pub const PATIENT_ORIENTATION: Tag = Tag::new(0x0020, 0x0020);
+
+/// Image Position (retired)
+pub const IMAGE_POSITION: Tag = Tag::new(0x0020, 0x0030);
+
+/// Image Position (Patient)
+pub const IMAGE_POSITION_PATIENT: Tag = Tag::new(0x0020, 0x0032);
+
+/// Image Orientation (retired)
+pub const IMAGE_ORIENTATION: Tag = Tag::new(0x0020, 0x0035);
+
+/// Image Orientation (Patient)
+pub const IMAGE_ORIENTATION_PATIENT: Tag = Tag::new(0x0020, 0x0037);
+
+/// Frame of Reference UID
+pub const FRAME_OF_REFERENCE_UID: Tag = Tag::new(0x0020, 0x0052);
+
+/// Laterality
+pub const LATERALITY: Tag = Tag::new(0x0020, 0x0060);
+
+/// Image Laterality
+pub const IMAGE_LATERALITY: Tag = Tag::new(0x0020, 0x0062);
+
+/// Temporal Position Identifier
+pub const TEMPORAL_POSITION_IDENTIFIER: Tag = Tag::new(0x0020, 0x0100);
+
+/// Number of Temporal Positions
+pub const NUMBER_OF_TEMPORAL_POSITIONS: Tag = Tag::new(0x0020, 0x0105);
+
+/// Images in Acquisition
+pub const IMAGES_IN_ACQUISITION: Tag = Tag::new(0x0020, 0x1002);
+
+/// Position Reference Indicator
+pub const POSITION_REFERENCE_INDICATOR: Tag = Tag::new(0x0020, 0x1040);
+
+/// Slice Location
+pub const SLICE_LOCATION: Tag = Tag::new(0x0020, 0x1041);
+
+/// Image Comments
+pub const IMAGE_COMMENTS: Tag = Tag::new(0x0020, 0x4000);
+
+/// Stack ID
+pub const STACK_ID: Tag = Tag::new(0x0020, 0x9056);
+
+/// In-Stack Position Number
+pub const IN_STACK_POSITION_NUMBER: Tag = Tag::new(0x0020, 0x9057);
+
+/// Frame Anatomy Sequence
+pub const FRAME_ANATOMY_SEQUENCE: Tag = Tag::new(0x0020, 0x9071);
+
+/// Frame Laterality
+pub const FRAME_LATERALITY: Tag = Tag::new(0x0020, 0x9072);
+
+/// Frame Content Sequence
+pub const FRAME_CONTENT_SEQUENCE: Tag = Tag::new(0x0020, 0x9111);
+
+/// Plane Position Sequence
+pub const PLANE_POSITION_SEQUENCE: Tag = Tag::new(0x0020, 0x9113);
+
+/// Plane Orientation Sequence
+pub const PLANE_ORIENTATION_SEQUENCE: Tag = Tag::new(0x0020, 0x9116);
+
+/// Frame Acquisition Number
+pub const FRAME_ACQUISITION_NUMBER: Tag = Tag::new(0x0020, 0x9156);
+
+/// Dimension Index Values
+pub const DIMENSION_INDEX_VALUES: Tag = Tag::new(0x0020, 0x9157);
+
+/// Dimension Organization UID
+pub const DIMENSION_ORGANIZATION_UID: Tag = Tag::new(0x0020, 0x9164);
+
+/// Dimension Index Pointer
+pub const DIMENSION_INDEX_POINTER: Tag = Tag::new(0x0020, 0x9165);
+
+/// Functional Group Pointer
+pub const FUNCTIONAL_GROUP_POINTER: Tag = Tag::new(0x0020, 0x9167);
+
+/// Dimension Organization Sequence
+pub const DIMENSION_ORGANIZATION_SEQUENCE: Tag = Tag::new(0x0020, 0x9221);
+
+/// Dimension Index Sequence
+pub const DIMENSION_INDEX_SEQUENCE: Tag = Tag::new(0x0020, 0x9222);
+
+/// Samples per Pixel
+pub const SAMPLES_PER_PIXEL: Tag = Tag::new(0x0028, 0x0002);
+
+/// Samples per Pixel Used
+pub const SAMPLES_PER_PIXEL_USED: Tag = Tag::new(0x0028, 0x0003);
+
+/// Photometric Interpretation
+pub const PHOTOMETRIC_INTERPRETATION: Tag = Tag::new(0x0028, 0x0004);
+
+/// Planar Configuration
+pub const PLANAR_CONFIGURATION: Tag = Tag::new(0x0028, 0x0006);
+
+/// Number of Frames
+pub const NUMBER_OF_FRAMES: Tag = Tag::new(0x0028, 0x0008);
+
+/// Frame Increment Pointer
+pub const FRAME_INCREMENT_POINTER: Tag = Tag::new(0x0028, 0x0009);
+
+/// Rows
+pub const ROWS: Tag = Tag::new(0x0028, 0x0010);
+
+/// Columns
+pub const COLUMNS: Tag = Tag::new(0x0028, 0x0011);
+
+/// Pixel Spacing
+pub const PIXEL_SPACING: Tag = Tag::new(0x0028, 0x0030);
+
+/// Pixel Aspect Ratio
+pub const PIXEL_ASPECT_RATIO: Tag = Tag::new(0x0028, 0x0034);
+
+/// Bits Allocated
+pub const BITS_ALLOCATED: Tag = Tag::new(0x0028, 0x0100);
+
+/// Bits Stored
+pub const BITS_STORED: Tag = Tag::new(0x0028, 0x0101);
+
+/// High Bit
+pub const HIGH_BIT: Tag = Tag::new(0x0028, 0x0102);
+
+/// Pixel Representation
+pub const PIXEL_REPRESENTATION: Tag = Tag::new(0x0028, 0x0103);
+
+/// Smallest Image Pixel Value
+pub const SMALLEST_IMAGE_PIXEL_VALUE: Tag = Tag::new(0x0028, 0x0106);
+
+/// Largest Image Pixel Value
+pub const LARGEST_IMAGE_PIXEL_VALUE: Tag = Tag::new(0x0028, 0x0107);
+
+/// Pixel Padding Value
+pub const PIXEL_PADDING_VALUE: Tag = Tag::new(0x0028, 0x0120);
+
+/// Pixel Padding Range Limit
+pub const PIXEL_PADDING_RANGE_LIMIT: Tag = Tag::new(0x0028, 0x0121);
+
+/// Burned In Annotation
+pub const BURNED_IN_ANNOTATION: Tag = Tag::new(0x0028, 0x0301);
+
+/// Pixel Intensity Relationship
+pub const PIXEL_INTENSITY_RELATIONSHIP: Tag = Tag::new(0x0028, 0x1040);
+
+/// Window Center
+pub const WINDOW_CENTER: Tag = Tag::new(0x0028, 0x1050);
+
+/// Window Width
+pub const WINDOW_WIDTH: Tag = Tag::new(0x0028, 0x1051);
+
+/// Rescale Intercept
+pub const RESCALE_INTERCEPT: Tag = Tag::new(0x0028, 0x1052);
+
+/// Rescale Slope
+pub const RESCALE_SLOPE: Tag = Tag::new(0x0028, 0x1053);
+
+/// Rescale Type
+pub const RESCALE_TYPE: Tag = Tag::new(0x0028, 0x1054);
+
+/// Window Center &amp; Width Explanation
+pub const WINDOW_CENTER_WIDTH_EXPLANATION: Tag = Tag::new(0x0028, 0x1055);
+
+/// Red Palette Color Lookup Table Descriptor
+pub const RED_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag::new(0x0028, 0x1101);
+
+/// Green Palette Color Lookup Table Descriptor
+pub const GREEN_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag::new(0x0028, 0x1102);
+
+/// Blue Palette Color Lookup Table Descriptor
+pub const BLUE_PALETTE_COLOR_LOOKUP_TABLE_DESCRIPTOR: Tag = Tag::new(0x0028, 0x1103);
+
+/// Red Palette Color Lookup Table Data
+pub const RED_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag::new(0x0028, 0x1201);
+
+/// Green Palette Color Lookup Table Data
+pub const GREEN_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag::new(0x0028, 0x1202);
+
+/// Blue Palette Color Lookup Table Data
+pub const BLUE_PALETTE_COLOR_LOOKUP_TABLE_DATA: Tag = Tag::new(0x0028, 0x1203);
+
+/// Lossy Image Compression
+pub const LOSSY_IMAGE_COMPRESSION: Tag = Tag::new(0x0028, 0x2110);
+
+/// Lossy Image Compression Ratio
+pub const LOSSY_IMAGE_COMPRESSION_RATIO: Tag = Tag::new(0x0028, 0x2112);
+
+/// Lossy Image Compression Method
+pub const LOSSY_IMAGE_COMPRESSION_METHOD: Tag = Tag::new(0x0028, 0x2114);
+
+/// Modality LUT Sequence
+pub const MODALITY_LUT_SEQUENCE: Tag = Tag::new(0x0028, 0x3000);
+
+/// LUT Descriptor
+pub const LUT_DESCRIPTOR: Tag = Tag::new(0x0028, 0x3002);
+
+/// LUT Explanation
+pub const LUT_EXPLANATION: Tag = Tag::new(0x0028, 0x3003);
+
+/// LUT Data
+pub const LUT_DATA: Tag = Tag::new(0x0028, 0x3006);
+
+/// VOI LUT Sequence
+pub const VOILUT_SEQUENCE: Tag = Tag::new(0x0028, 0x3010);
+
+/// Pixel Measures Sequence
+pub const PIXEL_MEASURES_SEQUENCE: Tag = Tag::new(0x0028, 0x9110);
+
+/// Frame VOI LUT Sequence
+pub const FRAME_VOILUT_SEQUENCE: Tag = Tag::new(0x0028, 0x9132);
+
+/// Pixel Value Transformation Sequence
+pub const PIXEL_VALUE_TRANSFORMATION_SEQUENCE: Tag = Tag::new(0x0028, 0x9145);
+
+/// Requesting Physician
+pub const REQUESTING_PHYSICIAN: Tag = Tag::new(0x0032, 0x1032);
+
+/// Requested Procedure Description
+pub const REQUESTED_PROCEDURE_DESCRIPTION: Tag = Tag::new(0x0032, 0x1060);
+
+/// Performed Procedure Step Start Date
+pub const PERFORMED_PROCEDURE_STEP_START_DATE: Tag = Tag::new(0x0040, 0x0244);
+
+/// Performed Procedure Step Start Time
+pub const PERFORMED_PROCEDURE_STEP_START_TIME: Tag = Tag::new(0x0040, 0x0245);
+
+/// Performed Procedure Step ID
+pub const PERFORMED_PROCEDURE_STEP_ID: Tag = Tag::new(0x0040, 0x0253);
+
+/// Performed Procedure Step Description
+pub const PERFORMED_PROCEDURE_STEP_DESCRIPTION: Tag = Tag::new(0x0040, 0x0254);
+
+/// Request Attributes Sequence
+pub const REQUEST_ATTRIBUTES_SEQUENCE: Tag = Tag::new(0x0040, 0x0275);
+
+/// Acquisition Context Sequence
+pub const ACQUISITION_CONTEXT_SEQUENCE: Tag = Tag::new(0x0040, 0x0555);
+
+/// Measurement Units Code Sequence
+pub const MEASUREMENT_UNITS_CODE_SEQUENCE: Tag = Tag::new(0x0040, 0x08EA);
+
+/// Requested Procedure ID
+pub const REQUESTED_PROCEDURE_ID: Tag = Tag::new(0x0040, 0x1001);
+
+/// Real World Value Mapping Sequence
+pub const REAL_WORLD_VALUE_MAPPING_SEQUENCE: Tag = Tag::new(0x0040, 0x9096);
+
+/// LUT Label
+pub const LUT_LABEL: Tag = Tag::new(0x0040, 0x9210);
+
+/// Real World Value Last Value Mapped
+pub const REAL_WORLD_VALUE_LAST_VALUE_MAPPED: Tag = Tag::new(0x0040, 0x9211);
+
+/// Real World Value First Value Mapped
+pub const REAL_WORLD_VALUE_FIRST_VALUE_MAPPED: Tag = Tag::new(0x0040, 0x9216);
+
+/// Real World Value Intercept
+pub const REAL_WORLD_VALUE_INTERCEPT: Tag = Tag::new(0x0040, 0x9224);
+
+/// Real World Value Slope
+pub const REAL_WORLD_VALUE_SLOPE: Tag = Tag::new(0x0040, 0x9225);
+
+/// Value Type
+pub const VALUE_TYPE: Tag = Tag::new(0x0040, 0xA040);
+
+/// Concept Name Code Sequence
+pub const CONCEPT_NAME_CODE_SEQUENCE: Tag = Tag::new(0x0040, 0xA043);
+
+/// Text Value
+pub const TEXT_VALUE: Tag = Tag::new(0x0040, 0xA160);
+
+/// Concept Code Sequence
+pub const CONCEPT_CODE_SEQUENCE: Tag = Tag::new(0x0040, 0xA168);
+
+/// Content Sequence
+pub const CONTENT_SEQUENCE: Tag = Tag::new(0x0040, 0xA730);
+
+/// Presentation LUT Shape
+pub const PRESENTATION_LUT_SHAPE: Tag = Tag::new(0x2050, 0x0020);
+
+/// Shared Functional Groups Sequence
+pub const SHARED_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag::new(0x5200, 0x9229);
+
+/// Per-frame Functional Groups Sequence
+pub const PER_FRAME_FUNCTIONAL_GROUPS_SEQUENCE: Tag = Tag::new(0x5200, 0x9230);
+
+/// Extended Offset Table
+pub const EXTENDED_OFFSET_TABLE: Tag = Tag::new(0x7FE0, 0x0001);
+
+/// Extended Offset Table Lengths
+pub const EXTENDED_OFFSET_TABLE_LENGTHS: Tag = Tag::new(0x7FE0, 0x0002);
+
+/// Float Pixel Data
+pub const FLOAT_PIXEL_DATA: Tag = Tag::new(0x7FE0, 0x0008);
+
+/// Double Float Pixel Data
+pub const DOUBLE_FLOAT_PIXEL_DATA: Tag = Tag::new(0x7FE0, 0x0009);
+
+/// Pixel Data
+pub const PIXEL_DATA: Tag = Tag::new(0x7FE0, 0x0010);
+
+/// Digital Signatures Sequence
+pub const DIGITAL_SIGNATURES_SEQUENCE: Tag = Tag::new(0xFFFA, 0xFFFA);
+
+/// Data Set Trailing Padding
+pub const DATA_SET_TRAILING_PADDING: Tag = Tag::new(0xFFFC, 0xFFFC);
+
+/// Item
+pub const ITEM: Tag = Tag::new(0xFFFE, 0xE000);
+
+/// Item Delimitation Item
+pub const ITEM_DELIMITATION_ITEM: Tag = Tag::new(0xFFFE, 0xE00D);
+
+/// Sequence Delimitation Item
+pub const SEQUENCE_DELIMITATION_ITEM: Tag = Tag::new(0xFFFE, 0xE0DD);
+
+/// Overlay Rows
+pub const OVERLAY_ROWS: Tag = Tag::new(0x6000, 0x0010);
+
+/// Overlay Columns
+pub const OVERLAY_COLUMNS: Tag = Tag::new(0x6000, 0x0011);
+
+/// Overlay Type
+pub const OVERLAY_TYPE: Tag = Tag::new(0x6000, 0x0040);
+
+/// Overlay Origin
+pub const OVERLAY_ORIGIN: Tag = Tag::new(0x6000, 0x0050);
+
+/// Overlay Bits Allocated
+pub const OVERLAY_BITS_ALLOCATED: Tag = Tag::new(0x6000, 0x0100);
+
+/// Overlay Bit Position
+pub const OVERLAY_BIT_POSITION: Tag = Tag::new(0x6000, 0x0102);
+
+/// Overlay Data
+pub const OVERLAY_DATA: Tag = Tag::new(0x6000, 0x3000);
@@ -1,11 +1,28 @@
+#[cfg(feature = "dictionary")]
+use crate::dictionary;
+#[cfg(feature = "dictionary")]
+use crate::tag::Tag;
 use crate::vr::VR;
-use std::convert::TryInto;
 use std::fmt;
 
 pub trait Encoding: fmt::Debug + Default {
     fn u16(bytes: &[u8]) -> u16;
     fn u32(bytes: &[u8]) -> u32;
+    /// Defaults to little endian, big endian encodings must implement it
+    fn u64(bytes: &[u8]) -> u64 {
+        let mut value = [0; 8];
+        value.copy_from_slice(&bytes[0..8]);
+        u64::from_le_bytes(value)
+    }
+    // callers map the unit error to their own ParseError with a position
+    #[allow(clippy::result_unit_err)]
     fn vr_and_length(bytes: &[u8]) -> Result<(Option<VR>, usize, usize), ()>;
+
+    /// Returns true if the VRs returned by vr_and_length() are looked up from
+    /// the tag rather than read from the bytes
+    fn vr_is_inferred() -> bool {
+        false
+    }
 }
 
 #[derive(Default)]
@@ -19,11 +36,17 @@ impl fmt::Debug for ExplicitLittleEndian {
 
 impl Encoding for ExplicitLittleEndian {
     fn u16(bytes: &[u8]) -> u16 {
-        u16::from_le_bytes([bytes[0], bytes[1]].try_into().unwrap())
+        u16::from_le_bytes([bytes[0], bytes[1]])
     }
 
     fn u32(bytes: &[u8]) -> u32 {
-        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]].try_into().unwrap())
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    fn u64(bytes: &[u8]) -> u64 {
+        let mut value = [0; 8];
+        value.copy_from_slice(&bytes[0..8]);
+        u64::from_le_bytes(value)
     }
 
     fn vr_and_length(bytes: &[u8]) -> Result<(Option<VR>, usize, usize), ()> {
@@ -62,11 +85,17 @@ impl fmt::Debug for ImplicitLittleEndian {
 
 impl Encoding for ImplicitLittleEndian {
     fn u16(bytes: &[u8]) -> u16 {
-        u16::from_le_bytes([bytes[0], bytes[1]].try_into().unwrap())
+        u16::from_le_bytes([bytes[0], bytes[1]])
     }
 
     fn u32(bytes: &[u8]) -> u32 {
-        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]].try_into().unwrap())
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    fn u64(bytes: &[u8]) -> u64 {
+        let mut value = [0; 8];
+        value.copy_from_slice(&bytes[0..8]);
+        u64::from_le_bytes(value)
     }
 
     fn vr_and_length(bytes: &[u8]) -> Result<(Option<VR>, usize, usize), ()> {
@@ -79,6 +108,45 @@ impl Encoding for ImplicitLittleEndian {
     }
 }
 
+/// ImplicitLittleEndian with the VR of each attribute looked up in the data
+/// dictionary (see dictionary::implicit_vr()).  The VR of attributes that
+/// are not in the dictionary (e.g. private attributes) is None
+#[cfg(feature = "dictionary")]
+#[derive(Default)]
+pub struct ImplicitLittleEndianDictionary {}
+
+#[cfg(feature = "dictionary")]
+impl fmt::Debug for ImplicitLittleEndianDictionary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImplicitLittleEndianDictionary").finish()
+    }
+}
+
+#[cfg(feature = "dictionary")]
+impl Encoding for ImplicitLittleEndianDictionary {
+    fn u16(bytes: &[u8]) -> u16 {
+        ImplicitLittleEndian::u16(bytes)
+    }
+
+    fn u32(bytes: &[u8]) -> u32 {
+        ImplicitLittleEndian::u32(bytes)
+    }
+
+    fn u64(bytes: &[u8]) -> u64 {
+        ImplicitLittleEndian::u64(bytes)
+    }
+
+    fn vr_and_length(bytes: &[u8]) -> Result<(Option<VR>, usize, usize), ()> {
+        let (_vr, length, bytes_consumed) = ImplicitLittleEndian::vr_and_length(bytes)?;
+        let tag = Tag::from_bytes::<ImplicitLittleEndian>(&bytes[0..4]);
+        Ok((dictionary::implicit_vr(tag), length, bytes_consumed))
+    }
+
+    fn vr_is_inferred() -> bool {
+        true
+    }
+}
+
 #[derive(Default)]
 pub struct ExplicitBigEndian {}
 
@@ -90,11 +158,17 @@ impl fmt::Debug for ExplicitBigEndian {
 
 impl Encoding for ExplicitBigEndian {
     fn u16(bytes: &[u8]) -> u16 {
-        u16::from_be_bytes([bytes[0], bytes[1]].try_into().unwrap())
+        u16::from_be_bytes([bytes[0], bytes[1]])
     }
 
     fn u32(bytes: &[u8]) -> u32 {
-        u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]].try_into().unwrap())
+        u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    fn u64(bytes: &[u8]) -> u64 {
+        let mut value = [0; 8];
+        value.copy_from_slice(&bytes[0..8]);
+        u64::from_be_bytes(value)
     }
 
     fn vr_and_length(bytes: &[u8]) -> Result<(Option<VR>, usize, usize), ()> {
@@ -114,6 +188,235 @@ impl Encoding for ExplicitBigEndian {
     }
 }
 
+/// Runtime representation of the Encodings (and the deflated variant of
+/// ExplicitLittleEndian) allowing the encoding to be selected from a transfer
+/// syntax UID instead of a generic parameter
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DynEncoding {
+    ImplicitLittleEndian,
+    ExplicitLittleEndian,
+    ExplicitBigEndian,
+    DeflatedExplicitLittleEndian,
+    /// ImplicitLittleEndian with the VRs looked up in the data dictionary,
+    /// only returned by from_transfer_syntax_uid_with_dictionary()
+    #[cfg(feature = "dictionary")]
+    ImplicitLittleEndianDictionary,
+}
+
+impl DynEncoding {
+    /// Returns the DynEncoding for a transfer syntax UID.  Transfer syntaxes
+    /// other than implicit little endian, explicit big endian and deflated
+    /// explicit little endian (e.g. encapsulated pixel data) use explicit
+    /// little endian
+    pub fn from_transfer_syntax_uid(transfer_syntax_uid: &str) -> DynEncoding {
+        DynEncoding::from_known_transfer_syntax_uid(transfer_syntax_uid)
+            .unwrap_or(DynEncoding::ExplicitLittleEndian)
+    }
+
+    /// Same as from_transfer_syntax_uid() but returns
+    /// ImplicitLittleEndianDictionary for implicit little endian
+    #[cfg(feature = "dictionary")]
+    pub fn from_transfer_syntax_uid_with_dictionary(transfer_syntax_uid: &str) -> DynEncoding {
+        match DynEncoding::from_transfer_syntax_uid(transfer_syntax_uid) {
+            DynEncoding::ImplicitLittleEndian => DynEncoding::ImplicitLittleEndianDictionary,
+            encoding => encoding,
+        }
+    }
+
+    /// Returns the DynEncoding for a transfer syntax UID defined in PS3.5 and
+    /// PS3.6 (including the retired ones) or None if the UID is not one of
+    /// them (e.g. a private transfer syntax)
+    pub fn from_known_transfer_syntax_uid(transfer_syntax_uid: &str) -> Option<DynEncoding> {
+        match transfer_syntax_uid {
+            "1.2.840.10008.1.2" => Some(DynEncoding::ImplicitLittleEndian),
+            "1.2.840.10008.1.2.2" => Some(DynEncoding::ExplicitBigEndian),
+            "1.2.840.10008.1.2.1.99" | "1.2.840.10008.1.2.4.95" | "1.2.840.10008.1.2.4.205" => {
+                Some(DynEncoding::DeflatedExplicitLittleEndian)
+            }
+            "1.2.840.10008.1.2.1"
+            | "1.2.840.10008.1.2.1.98"
+            | "1.2.840.10008.1.2.5"
+            | "1.2.840.10008.1.2.6.1"
+            | "1.2.840.10008.1.2.6.2"
+            | "1.2.840.10008.1.2.7.1"
+            | "1.2.840.10008.1.2.7.2"
+            | "1.2.840.10008.1.2.7.3"
+            | "1.2.840.10008.1.2.8.1" => Some(DynEncoding::ExplicitLittleEndian),
+            _ => match transfer_syntax_uid.strip_prefix("1.2.840.10008.1.2.4.") {
+                // JPEG, JPEG-LS, JPEG 2000, JPIP, MPEG, HEVC, JPEG XL and
+                // HTJ2K transfer syntaxes (encapsulated pixel data)
+                Some(
+                    "50" | "51" | "52" | "53" | "54" | "55" | "56" | "57" | "58" | "59" | "60"
+                    | "61" | "62" | "63" | "64" | "65" | "66" | "70" | "80" | "81" | "90" | "91"
+                    | "92" | "93" | "94" | "100" | "100.1" | "101" | "101.1" | "102" | "102.1"
+                    | "103" | "103.1" | "104" | "104.1" | "105" | "105.1" | "106" | "106.1" | "107"
+                    | "108" | "110" | "111" | "112" | "201" | "202" | "203" | "204",
+                ) => Some(DynEncoding::ExplicitLittleEndian),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynEncoding, Encoding, ExplicitBigEndian, ExplicitLittleEndian};
+    use crate::vr::VR;
+
+    const U32_LENGTH_VRS: [&[u8; 2]; 13] = [
+        b"OB", b"OD", b"OF", b"OL", b"OV", b"OW", b"SQ", b"SV", b"UC", b"UN", b"UR", b"UT", b"UV",
+    ];
+
+    #[test]
+    fn explicit_little_endian_u32_length_vrs() {
+        for vr in U32_LENGTH_VRS.iter() {
+            let bytes = [
+                0x08, 0x00, 0x10, 0x00, vr[0], vr[1], 0, 0, 0x04, 0x03, 0x02, 0x01,
+            ];
+            match ExplicitLittleEndian::vr_and_length(&bytes) {
+                Ok((parsed_vr, length, bytes_consumed)) => {
+                    assert_eq!(parsed_vr, Some(VR::from_bytes(&vr[..])));
+                    assert_eq!(length, 0x0102_0304, "{:?}", parsed_vr);
+                    assert_eq!(bytes_consumed, 12, "{:?}", parsed_vr);
+                }
+                Err(()) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+    }
+
+    #[test]
+    fn explicit_big_endian_u32_length_vrs() {
+        for vr in U32_LENGTH_VRS.iter() {
+            let bytes = [
+                0x00, 0x08, 0x00, 0x10, vr[0], vr[1], 0, 0, 0x01, 0x02, 0x03, 0x04,
+            ];
+            match ExplicitBigEndian::vr_and_length(&bytes) {
+                Ok((parsed_vr, length, bytes_consumed)) => {
+                    assert_eq!(parsed_vr, Some(VR::from_bytes(&vr[..])));
+                    assert_eq!(length, 0x0102_0304, "{:?}", parsed_vr);
+                    assert_eq!(bytes_consumed, 12, "{:?}", parsed_vr);
+                }
+                Err(()) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+    }
+
+    #[test]
+    fn explicit_little_endian_u32_length_vr_needs_12_bytes() {
+        let bytes = [0x08, 0x00, 0x10, 0x00, b'U', b'R', 0, 0, 0x04, 0x03];
+        assert!(ExplicitLittleEndian::vr_and_length(&bytes).is_err());
+    }
+
+    #[test]
+    fn explicit_little_endian_u16_length_vr() {
+        let bytes = [0x08, 0x00, 0x10, 0x00, b'L', b'O', 0x02, 0x01];
+        match ExplicitLittleEndian::vr_and_length(&bytes) {
+            Ok((vr, length, bytes_consumed)) => {
+                assert_eq!(vr, Some(VR::LO));
+                assert_eq!(length, 0x0102);
+                assert_eq!(bytes_consumed, 8);
+            }
+            Err(()) => panic!("Let's play Global Thermonuclear War"),
+        }
+    }
+
+    #[test]
+    fn explicit_big_endian_u16_length_vr() {
+        let bytes = [0x00, 0x08, 0x00, 0x10, b'L', b'O', 0x01, 0x02];
+        match ExplicitBigEndian::vr_and_length(&bytes) {
+            Ok((vr, length, bytes_consumed)) => {
+                assert_eq!(vr, Some(VR::LO));
+                assert_eq!(length, 0x0102);
+                assert_eq!(bytes_consumed, 8);
+            }
+            Err(()) => panic!("Let's play Global Thermonuclear War"),
+        }
+    }
+
+    #[test]
+    fn from_transfer_syntax_uid_returns_implicit_little_endian() {
+        assert_eq!(
+            DynEncoding::from_transfer_syntax_uid("1.2.840.10008.1.2"),
+            DynEncoding::ImplicitLittleEndian
+        );
+    }
+
+    #[test]
+    fn from_transfer_syntax_uid_returns_explicit_big_endian() {
+        assert_eq!(
+            DynEncoding::from_transfer_syntax_uid("1.2.840.10008.1.2.2"),
+            DynEncoding::ExplicitBigEndian
+        );
+    }
+
+    #[test]
+    fn from_transfer_syntax_uid_returns_deflated() {
+        assert_eq!(
+            DynEncoding::from_transfer_syntax_uid("1.2.840.10008.1.2.1.99"),
+            DynEncoding::DeflatedExplicitLittleEndian
+        );
+    }
+
+    #[test]
+    fn from_transfer_syntax_uid_defaults_to_explicit_little_endian() {
+        assert_eq!(
+            DynEncoding::from_transfer_syntax_uid("1.2.840.10008.1.2.4.80"),
+            DynEncoding::ExplicitLittleEndian
+        );
+    }
+
+    // Encoding without a u64() implementation to test the default
+    #[derive(Debug, Default)]
+    struct NoU64LittleEndian {}
+
+    impl Encoding for NoU64LittleEndian {
+        fn u16(bytes: &[u8]) -> u16 {
+            ExplicitLittleEndian::u16(bytes)
+        }
+
+        fn u32(bytes: &[u8]) -> u32 {
+            ExplicitLittleEndian::u32(bytes)
+        }
+
+        fn vr_and_length(bytes: &[u8]) -> Result<(Option<VR>, usize, usize), ()> {
+            ExplicitLittleEndian::vr_and_length(bytes)
+        }
+    }
+
+    #[test]
+    fn u64_byte_order() {
+        let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(NoU64LittleEndian::u64(&bytes), u64::from_le_bytes(bytes));
+        assert_eq!(ExplicitLittleEndian::u64(&bytes), u64::from_le_bytes(bytes));
+        assert_eq!(ExplicitBigEndian::u64(&bytes), u64::from_be_bytes(bytes));
+    }
+
+    #[cfg(feature = "dictionary")]
+    #[test]
+    fn from_transfer_syntax_uid_with_dictionary_returns_dictionary_encoding() {
+        assert_eq!(
+            DynEncoding::from_transfer_syntax_uid_with_dictionary("1.2.840.10008.1.2"),
+            DynEncoding::ImplicitLittleEndianDictionary
+        );
+        assert_eq!(
+            DynEncoding::from_transfer_syntax_uid_with_dictionary("1.2.840.10008.1.2.2"),
+            DynEncoding::ExplicitBigEndian
+        );
+    }
+
+    #[test]
+    fn from_known_transfer_syntax_uid_returns_none_for_unknown_uid() {
+        assert_eq!(
+            DynEncoding::from_known_transfer_syntax_uid("1.2.840.10008.1.2.4.80"),
+            Some(DynEncoding::ExplicitLittleEndian)
+        );
+        assert_eq!(
+            DynEncoding::from_known_transfer_syntax_uid("1.2.3.4.5"),
+            None
+        );
+    }
+}
+
 /*
 #[cfg(test)]
 mod tests {
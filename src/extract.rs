@@ -0,0 +1,436 @@
+//! Mapping of attributes to the fields of a struct.  Implement DicomExtract
+//! with `#[derive(DicomExtract)]` (derive feature) and parse with an
+//! ExtractHandler wrapped in an AccumulatingHandler:
+//!
+//! ```ignore
+//! #[derive(Default, DicomExtract)]
+//! struct Series {
+//!     #[dicom(tag = "(0020,000E)")]
+//!     series_instance_uid: String,
+//!     #[dicom(keyword = "Rows")]
+//!     rows: Option<u16>,
+//!     #[dicom(tag = "(0008,1140)", sequence)]
+//!     referenced_images: Vec<ReferencedImage>,
+//! }
+//! ```
+//!
+//! Keywords require the dictionary feature.  Binary values are decoded with
+//! the encoding of the transfer syntax in the P10 Header (see
+//! ExtractHandler::encoding).
+
+use crate::attribute::Attribute;
+use crate::encoding::{DynEncoding, Encoding, ExplicitBigEndian, ExplicitLittleEndian};
+use crate::handler::accumulate::ValueHandler;
+use crate::handler::{Handler, HandlerResult, SequenceItem};
+use crate::tag::Tag;
+use crate::value;
+use crate::value::date_time::{DicomDate, DicomDateTime, DicomTime};
+use crate::value::number::Mode;
+use crate::value::ValueError;
+use crate::vr::VR;
+
+#[cfg(feature = "derive")]
+pub use dicomparser_derive::DicomExtract;
+
+// the encoding of binary values is given by the transfer syntax
+const TRANSFER_SYNTAX_UID: Tag = Tag::new(0x0002, 0x0010);
+
+/// Conversion of the value field of an attribute to the type of a field.
+/// Single values are the first value of the value field.
+pub trait FromValue: Sized {
+    /// Decodes the complete value field of the attribute, binary values are
+    /// decoded with the Encoding E
+    fn from_value<E: Encoding>(attribute: &Attribute, value: &[u8]) -> Result<Self, ValueError>;
+}
+
+/// A struct whose fields are filled from the attributes of a DataSet by an
+/// ExtractHandler.  Usually implemented with `#[derive(DicomExtract)]`.  The
+/// `sequences` arguments are the tags of the sequences the attribute is
+/// nested in, starting with the one in the root DataSet.
+pub trait DicomExtract: Default {
+    /// Returns the largest tag mapped in the root DataSet or None if no
+    /// attribute is mapped
+    fn last_tag() -> Option<Tag>;
+
+    /// Returns true if the attribute with the tag is mapped to a field
+    fn is_mapped(sequences: &[Tag], tag: Tag) -> bool;
+
+    /// Sets the field the attribute is mapped to from its value, binary
+    /// values are decoded with the Encoding E.  The field is unchanged if
+    /// the value cannot be decoded
+    fn set_value<E: Encoding>(
+        &mut self,
+        sequences: &[Tag],
+        attribute: &Attribute,
+        value: &[u8],
+    ) -> Result<(), ValueError>;
+
+    /// Adds an item to the sequence field for the last sequence
+    fn start_item(&mut self, sequences: &[Tag]);
+}
+
+/// Implements the ValueHandler trait that fills a DicomExtract from the
+/// attributes parsed.  Attributes that are not mapped are skipped and the
+/// parse is cancelled at the first attribute in the root DataSet after the
+/// last mapped tag, or after the P10 Header if none is mapped.  The P10 Header
+/// is never cancelled so it is complete.  Use it with an AccumulatingHandler
+/// so the values are complete.  Values that cannot be decoded leave their
+/// field unchanged.
+pub struct ExtractHandler<'t, T: DicomExtract> {
+    /// true if the parse has been canceled, false otherwise
+    pub canceled: bool,
+    /// the struct to fill
+    pub target: &'t mut T,
+    /// the encoding binary values are decoded with.  Set from the transfer
+    /// syntax UID when parsing a P10 Header, set it before parsing a DataSet
+    /// without one
+    pub encoding: DynEncoding,
+    // the tags of the sequences of the items currently being parsed
+    sequences: Vec<Tag>,
+}
+
+impl<'t, T: DicomExtract> ExtractHandler<'t, T> {
+    /// Creates a new ExtractHandler given the struct to fill
+    pub fn new(target: &'t mut T) -> ExtractHandler<'t, T> {
+        ExtractHandler {
+            canceled: false,
+            target,
+            encoding: DynEncoding::ExplicitLittleEndian,
+            sequences: vec![],
+        }
+    }
+}
+
+impl<T: DicomExtract> Handler for ExtractHandler<'_, T> {
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        _position: usize,
+        _data_offset: usize,
+    ) -> HandlerResult {
+        if attribute.tag == TRANSFER_SYNTAX_UID {
+            HandlerResult::Continue
+        } else if self.sequences.is_empty()
+            && attribute.tag.group != TRANSFER_SYNTAX_UID.group
+            && T::last_tag().map_or(true, |last_tag| attribute.tag > last_tag)
+        {
+            self.canceled = true;
+            HandlerResult::Cancel
+        } else if T::is_mapped(&self.sequences, attribute.tag) {
+            HandlerResult::Continue
+        } else {
+            HandlerResult::Skip
+        }
+    }
+    fn start_sequence_item(&mut self, attribute: &Attribute, _item: &SequenceItem) {
+        self.sequences.push(attribute.tag);
+        self.target.start_item(&self.sequences);
+    }
+    fn end_sequence_item(&mut self, _attribute: &Attribute) {
+        self.sequences.pop();
+    }
+}
+
+impl<T: DicomExtract> ValueHandler for ExtractHandler<'_, T> {
+    fn value(&mut self, attribute: &Attribute, value: &[u8]) -> HandlerResult {
+        if attribute.tag == TRANSFER_SYNTAX_UID {
+            if let Ok(uid) = String::from_value::<ExplicitLittleEndian>(attribute, value) {
+                self.encoding = DynEncoding::from_transfer_syntax_uid(&uid);
+            }
+            if !T::is_mapped(&self.sequences, attribute.tag) {
+                return HandlerResult::Continue;
+            }
+        }
+        // values that cannot be decoded leave their field unchanged
+        let _ = match self.encoding {
+            DynEncoding::ExplicitBigEndian => {
+                self.target
+                    .set_value::<ExplicitBigEndian>(&self.sequences, attribute, value)
+            }
+            _ => self
+                .target
+                .set_value::<ExplicitLittleEndian>(&self.sequences, attribute, value),
+        };
+        HandlerResult::Continue
+    }
+}
+
+fn first<V>(values: Vec<V>) -> Result<V, ValueError> {
+    values
+        .into_iter()
+        .next()
+        .ok_or(ValueError::InvalidLength(0))
+}
+
+/// The values joined with backslashes, e.g. all values of a multi-valued CS
+impl FromValue for String {
+    fn from_value<E: Encoding>(attribute: &Attribute, value: &[u8]) -> Result<Self, ValueError> {
+        Ok(value::strings(attribute, value)?.join("\\"))
+    }
+}
+
+impl FromValue for Vec<String> {
+    fn from_value<E: Encoding>(attribute: &Attribute, value: &[u8]) -> Result<Self, ValueError> {
+        Ok(value::strings(attribute, value)?
+            .into_iter()
+            .map(String::from)
+            .collect())
+    }
+}
+
+/// The bytes of the value field, e.g. an OB value
+impl FromValue for Vec<u8> {
+    fn from_value<E: Encoding>(_attribute: &Attribute, value: &[u8]) -> Result<Self, ValueError> {
+        Ok(value.to_vec())
+    }
+}
+
+impl<V: FromValue> FromValue for Option<V> {
+    fn from_value<E: Encoding>(attribute: &Attribute, value: &[u8]) -> Result<Self, ValueError> {
+        V::from_value::<E>(attribute, value).map(Some)
+    }
+}
+
+// implements FromValue for a binary number type and a Vec of it
+macro_rules! binary_from_value {
+    ($type:ty, $decode:ident) => {
+        impl FromValue for Vec<$type> {
+            fn from_value<E: Encoding>(
+                attribute: &Attribute,
+                value: &[u8],
+            ) -> Result<Self, ValueError> {
+                Ok(value::$decode::<E>(attribute, value)?.collect())
+            }
+        }
+
+        impl FromValue for $type {
+            fn from_value<E: Encoding>(
+                attribute: &Attribute,
+                value: &[u8],
+            ) -> Result<Self, ValueError> {
+                first(Vec::<$type>::from_value::<E>(attribute, value)?)
+            }
+        }
+    };
+}
+
+binary_from_value!(u16, u16s);
+binary_from_value!(i16, i16s);
+binary_from_value!(u32, u32s);
+binary_from_value!(i32, i32s);
+binary_from_value!(u64, u64s);
+binary_from_value!(f32, f32s);
+
+/// FD and OD values or DS values (including attributes without a VR)
+impl FromValue for Vec<f64> {
+    fn from_value<E: Encoding>(attribute: &Attribute, value: &[u8]) -> Result<Self, ValueError> {
+        match attribute.vr {
+            Some(VR::FD) | Some(VR::OD) => Ok(value::f64s::<E>(attribute, value)?.collect()),
+            _ => value::number::decimal_strings(attribute, value, Mode::Lenient),
+        }
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value<E: Encoding>(attribute: &Attribute, value: &[u8]) -> Result<Self, ValueError> {
+        first(Vec::<f64>::from_value::<E>(attribute, value)?)
+    }
+}
+
+/// SV values or IS values (including attributes without a VR)
+impl FromValue for Vec<i64> {
+    fn from_value<E: Encoding>(attribute: &Attribute, value: &[u8]) -> Result<Self, ValueError> {
+        match attribute.vr {
+            Some(VR::SV) => Ok(value::i64s::<E>(attribute, value)?.collect()),
+            _ => value::number::integer_strings(attribute, value, Mode::Lenient),
+        }
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value<E: Encoding>(attribute: &Attribute, value: &[u8]) -> Result<Self, ValueError> {
+        first(Vec::<i64>::from_value::<E>(attribute, value)?)
+    }
+}
+
+// implements FromValue for a type parsed from the first string value
+macro_rules! string_from_value {
+    ($type:ty, $parse:expr) => {
+        impl FromValue for $type {
+            fn from_value<E: Encoding>(
+                attribute: &Attribute,
+                value: &[u8],
+            ) -> Result<Self, ValueError> {
+                $parse(first(value::strings(attribute, value)?)?)
+            }
+        }
+    };
+}
+
+string_from_value!(DicomDate, DicomDate::parse);
+string_from_value!(DicomTime, DicomTime::parse);
+string_from_value!(DicomDateTime, DicomDateTime::parse);
+
+#[cfg(test)]
+mod tests {
+    use super::{DicomExtract, ExtractHandler, FromValue};
+    use crate::attribute::Attribute;
+    use crate::encoding::{DynEncoding, Encoding, ExplicitBigEndian, ExplicitLittleEndian};
+    use crate::handler::accumulate::AccumulatingHandler;
+    use crate::handler::{Handler, HandlerResult};
+    use crate::p10::parse;
+    use crate::tag::Tag;
+    use crate::test::tests::read_file;
+    use crate::value::date_time::DicomDate;
+    use crate::value::ValueError;
+    use crate::vr::VR;
+
+    fn make_attribute(vr: Option<VR>, length: usize) -> Attribute {
+        Attribute {
+            tag: Tag::new(0x0028, 0x0030),
+            vr,
+            length,
+            vr_inferred: false,
+        }
+    }
+
+    // hand written DicomExtract, the derive macro is tested in its crate
+    #[derive(Default, Debug)]
+    struct Image {
+        modality: String,
+        rows: Option<u16>,
+        pixel_spacing: Vec<f64>,
+        study_date: Option<DicomDate>,
+    }
+
+    impl DicomExtract for Image {
+        fn last_tag() -> Option<Tag> {
+            Some(Tag::new(0x0028, 0x0030))
+        }
+        fn is_mapped(sequences: &[Tag], tag: Tag) -> bool {
+            sequences.is_empty()
+                && [
+                    Tag::new(0x0008, 0x0020),
+                    Tag::new(0x0008, 0x0060),
+                    Tag::new(0x0028, 0x0010),
+                    Tag::new(0x0028, 0x0030),
+                ]
+                .contains(&tag)
+        }
+        fn set_value<E: Encoding>(
+            &mut self,
+            _sequences: &[Tag],
+            attribute: &Attribute,
+            value: &[u8],
+        ) -> Result<(), ValueError> {
+            if attribute.tag == Tag::new(0x0008, 0x0020) {
+                self.study_date = FromValue::from_value::<E>(attribute, value)?;
+            } else if attribute.tag == Tag::new(0x0008, 0x0060) {
+                self.modality = FromValue::from_value::<E>(attribute, value)?;
+            } else if attribute.tag == Tag::new(0x0028, 0x0010) {
+                self.rows = FromValue::from_value::<E>(attribute, value)?;
+            } else if attribute.tag == Tag::new(0x0028, 0x0030) {
+                self.pixel_spacing = FromValue::from_value::<E>(attribute, value)?;
+            }
+            Ok(())
+        }
+        fn start_item(&mut self, _sequences: &[Tag]) {}
+    }
+
+    #[test]
+    fn decodes_values() {
+        let attribute = make_attribute(Some(VR::DS), 8);
+        assert_eq!(
+            Vec::<f64>::from_value::<ExplicitLittleEndian>(&attribute, b"0.5\\1.5 "),
+            Ok(vec![0.5, 1.5])
+        );
+        assert_eq!(
+            f64::from_value::<ExplicitLittleEndian>(&attribute, b"0.5\\1.5 "),
+            Ok(0.5)
+        );
+        let attribute = make_attribute(None, 2);
+        assert_eq!(
+            u16::from_value::<ExplicitLittleEndian>(&attribute, &[0x00, 0x02]),
+            Ok(512)
+        );
+        assert_eq!(
+            Option::<u16>::from_value::<ExplicitLittleEndian>(&attribute, &[0x00, 0x02]),
+            Ok(Some(512))
+        );
+        assert!(u16::from_value::<ExplicitLittleEndian>(&attribute, &[]).is_err());
+        assert_eq!(
+            u16::from_value::<ExplicitBigEndian>(&attribute, &[0x00, 0x02]),
+            Ok(2)
+        );
+        let attribute = make_attribute(Some(VR::CS), 16);
+        assert_eq!(
+            String::from_value::<ExplicitLittleEndian>(&attribute, b"ORIGINAL\\PRIMARY"),
+            Ok(String::from("ORIGINAL\\PRIMARY"))
+        );
+        assert_eq!(
+            Vec::<String>::from_value::<ExplicitLittleEndian>(&attribute, b"ORIGINAL\\PRIMARY"),
+            Ok(vec![String::from("ORIGINAL"), String::from("PRIMARY")])
+        );
+    }
+
+    #[test]
+    fn extracts_and_cancels() {
+        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut image = Image::default();
+        let mut handler = ExtractHandler::new(&mut image);
+        let mut accumulating_handler = AccumulatingHandler::new(&mut handler, 1024);
+        assert!(parse(&mut accumulating_handler, &bytes).is_ok());
+        assert!(handler.canceled);
+        assert_eq!(image.modality, "CT");
+        assert_eq!(image.rows, Some(512));
+        assert_eq!(image.pixel_spacing.len(), 2);
+        assert_eq!(image.study_date.unwrap().year, 2004);
+    }
+
+    #[test]
+    fn extracts_big_endian_values() {
+        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_big_endian.dcm");
+        let mut image = Image::default();
+        let mut handler = ExtractHandler::new(&mut image);
+        let mut accumulating_handler = AccumulatingHandler::new(&mut handler, 1024);
+        assert!(parse(&mut accumulating_handler, &bytes).is_ok());
+        assert_eq!(handler.encoding, DynEncoding::ExplicitBigEndian);
+        assert_eq!(image.modality, "CT");
+        assert_eq!(image.rows, Some(512));
+    }
+
+    // DicomExtract without mapped attributes
+    #[derive(Default)]
+    struct Nothing {}
+
+    impl DicomExtract for Nothing {
+        fn last_tag() -> Option<Tag> {
+            None
+        }
+        fn is_mapped(_sequences: &[Tag], _tag: Tag) -> bool {
+            false
+        }
+        fn set_value<E: Encoding>(
+            &mut self,
+            _sequences: &[Tag],
+            _attribute: &Attribute,
+            _value: &[u8],
+        ) -> Result<(), ValueError> {
+            Ok(())
+        }
+        fn start_item(&mut self, _sequences: &[Tag]) {}
+    }
+
+    #[test]
+    fn cancels_after_header_if_nothing_mapped() {
+        let mut nothing = Nothing::default();
+        let mut handler = ExtractHandler::new(&mut nothing);
+        let mut attribute = make_attribute(Some(VR::UI), 8);
+        attribute.tag = Tag::new(0x0002, 0x0002);
+        assert_eq!(handler.attribute(&attribute, 0, 0), HandlerResult::Skip);
+        assert!(!handler.canceled);
+        let result = handler.attribute(&make_attribute(Some(VR::DS), 8), 0, 0);
+        assert_eq!(result, HandlerResult::Cancel);
+        assert!(handler.canceled);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::attribute::Attribute;
+use crate::handler::{Handler, HandlerResult};
+
+/// The ValueHandler trait extends the Handler trait with a function that is
+/// invoked once with the complete value field of an attribute.  It is used
+/// with the AccumulatingHandler which takes care of buffering the data
+/// for each attribute.
+pub trait ValueHandler: Handler {
+    /// Invoked with the complete value field of an attribute.  Values larger
+    /// than the AccumulatingHandler's max_size are not buffered and are
+    /// delivered in chunks via Handler::data() instead
+    ///
+    /// # Arguments
+    ///
+    /// * `_attribute` - The attribute the value is for
+    /// * `_value`     - The complete value field of the attribute
+    fn value(&mut self, _attribute: &Attribute, _value: &[u8]) -> HandlerResult {
+        HandlerResult::Continue
+    }
+}
+
+/// Implements the Handler trait that buffers the data for each attribute and
+/// forwards the complete value to a ValueHandler.  Values larger than
+/// max_size are passed through in chunks to ValueHandler::data() without
+/// being buffered so large values such as pixel data are not copied.  Only
+/// attribute() and data() are intercepted, sequence and pixel data fragment
+/// events reach the ValueHandler unchanged.
+pub struct AccumulatingHandler<'t, H: ValueHandler + ?Sized> {
+    /// the ValueHandler to forward/proxy function calls to
+    pub handler: &'t mut H,
+    /// the maximum size of a value to buffer
+    pub max_size: usize,
+    // buffer to accumulate data for the current attribute
+    data_buffer: Vec<u8>,
+    // true if the data for the current attribute is passed through
+    pass_through: bool,
+}
+
+impl<'t, H: ValueHandler + ?Sized> AccumulatingHandler<'t, H> {
+    /// Creates a new AccumulatingHandler given a ValueHandler to forward
+    /// functions to and the maximum size of a value to buffer
+    pub fn new(handler: &'t mut H, max_size: usize) -> AccumulatingHandler<'t, H> {
+        AccumulatingHandler {
+            handler,
+            max_size,
+            data_buffer: vec![],
+            pass_through: false,
+        }
+    }
+}
+
+impl<H: ValueHandler + ?Sized> Handler for AccumulatingHandler<'_, H> {
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        position: usize,
+        data_offset: usize,
+    ) -> HandlerResult {
+        self.data_buffer.clear();
+        // undefined length values are buffered until they exceed max_size
+        self.pass_through = attribute.length != 0xFFFF_FFFF && attribute.length > self.max_size;
+        self.handler.attribute(attribute, position, data_offset)
+    }
+    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
+        if !self.pass_through && self.data_buffer.len() + data.len() > self.max_size {
+            // too big to buffer, pass through what has been buffered so far
+            self.pass_through = true;
+            if !self.data_buffer.is_empty() {
+                let data_buffer = std::mem::take(&mut self.data_buffer);
+                match self.handler.data(attribute, &data_buffer, false) {
+                    HandlerResult::Continue => {}
+                    handler_result => return handler_result,
+                }
+            }
+        }
+        if self.pass_through {
+            return self.handler.data(attribute, data, complete);
+        }
+
+        self.data_buffer.extend_from_slice(data);
+        if complete {
+            self.handler.value(attribute, &self.data_buffer)
+        } else {
+            HandlerResult::Continue
+        }
+    }
+    forward_handler!(
+        start_sequence,
+        start_sequence_item,
+        end_sequence_item,
+        end_sequence,
+        basic_offset_table,
+        pixel_data_fragment,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccumulatingHandler, ValueHandler};
+    use crate::attribute::Attribute;
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::{Handler, HandlerResult};
+    use crate::tag::Tag;
+    use crate::test::tests::read_data_set_bytes_from_file;
+
+    // ValueHandler that collects the values and the chunks passed through
+    #[derive(Default)]
+    struct TestValueHandler {
+        values: Vec<(Tag, Vec<u8>)>,
+        chunks: Vec<(Tag, usize, bool)>,
+    }
+
+    impl Handler for TestValueHandler {
+        fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
+            self.chunks.push((attribute.tag, data.len(), complete));
+            HandlerResult::Continue
+        }
+    }
+
+    impl ValueHandler for TestValueHandler {
+        fn value(&mut self, attribute: &Attribute, value: &[u8]) -> HandlerResult {
+            self.values.push((attribute.tag, value.to_vec()));
+            HandlerResult::Continue
+        }
+    }
+
+    fn make_attribute(length: usize) -> Attribute {
+        Attribute {
+            tag: Tag::new(0x0010, 0x0010),
+            length,
+            ..Attribute::default()
+        }
+    }
+
+    #[test]
+    fn chunks_are_delivered_as_one_value() {
+        let mut value_handler = TestValueHandler::default();
+        let mut handler = AccumulatingHandler::new(&mut value_handler, 16);
+        let attribute = make_attribute(6);
+        handler.attribute(&attribute, 0, 8);
+        handler.data(&attribute, b"ab", false);
+        handler.data(&attribute, b"cd", false);
+        handler.data(&attribute, b"ef", true);
+        assert_eq!(value_handler.values.len(), 1);
+        assert_eq!(value_handler.values[0].1, b"abcdef");
+        assert!(value_handler.chunks.is_empty());
+    }
+
+    #[test]
+    fn values_over_max_size_pass_through() {
+        let mut value_handler = TestValueHandler::default();
+        let mut handler = AccumulatingHandler::new(&mut value_handler, 4);
+        let attribute = make_attribute(6);
+        handler.attribute(&attribute, 0, 8);
+        handler.data(&attribute, b"abc", false);
+        handler.data(&attribute, b"def", true);
+        assert!(value_handler.values.is_empty());
+        assert_eq!(value_handler.chunks.len(), 2);
+        assert!(value_handler.chunks[1].2);
+    }
+
+    #[test]
+    fn undefined_length_over_max_size_passes_through_buffered_data() {
+        let mut value_handler = TestValueHandler::default();
+        let mut handler = AccumulatingHandler::new(&mut value_handler, 4);
+        let attribute = make_attribute(0xFFFF_FFFF);
+        handler.attribute(&attribute, 0, 8);
+        handler.data(&attribute, b"abc", false);
+        handler.data(&attribute, b"def", true);
+        assert!(value_handler.values.is_empty());
+        assert_eq!(
+            value_handler.chunks,
+            vec![(attribute.tag, 3, false), (attribute.tag, 3, true)]
+        );
+    }
+
+    #[test]
+    fn data_set_values_are_complete() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut value_handler = TestValueHandler::default();
+        let mut handler = AccumulatingHandler::new(&mut value_handler, 1024);
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        // parse in small chunks so values are delivered over multiple calls
+        let mut offset = 0;
+        for end in (7..bytes.len()).step_by(7).chain(Some(bytes.len())) {
+            match parser.parse(
+                &mut handler,
+                &bytes[offset..end],
+                meta.end_position + offset,
+            ) {
+                Ok(result) => offset += result.bytes_consumed,
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+        assert_eq!(offset, bytes.len());
+        let patient_name = value_handler
+            .values
+            .iter()
+            .find(|(tag, _value)| *tag == Tag::new(0x0010, 0x0010))
+            .unwrap();
+        assert_eq!(patient_name.1, b"CompressedSamples^CT1 ");
+        // pixel data is passed through in chunks
+        let pixel_data = Tag::new(0x7fe0, 0x0010);
+        assert!(value_handler
+            .values
+            .iter()
+            .all(|(tag, _value)| *tag != pixel_data));
+        assert!(value_handler
+            .chunks
+            .iter()
+            .any(|(tag, _length, complete)| *tag == pixel_data && !complete));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::attribute::Attribute;
-use crate::handler::{Handler, HandlerResult};
+use crate::handler::{Handler, HandlerResult, SequenceItem};
 
 pub type CancelFN = fn(&Attribute) -> bool;
 
@@ -7,20 +7,25 @@ pub type CancelFN = fn(&Attribute) -> bool;
 /// Cancel function returns true or forward/proxy all functions to another
 /// Handler implementation.  Some use cases do not require parsing the full
 /// DICOM DataSet and encapsulating the stop parsing in a function (perhaps
-/// a closure) can aid in readability of processing logic.
-pub struct CancelHandler<'t> {
+/// a closure) can aid in readability of processing logic.  The Cancel
+/// function can be any closure so it can capture state such as a stop tag
+/// configured at runtime or a counter.
+pub struct CancelHandler<'t, F = CancelFN>
+where
+    F: FnMut(&Attribute) -> bool,
+{
     /// true if the parse has been canceled, false otherwise
     pub canceled: bool,
     /// the Handler to forward/proxy function calls to
     pub handler: &'t mut dyn Handler,
     /// The function to call to see if the parse should be cancelled
-    pub cancel_fn: CancelFN,
+    pub cancel_fn: F,
 }
 
-impl<'t> CancelHandler<'t> {
+impl<'t, F: FnMut(&Attribute) -> bool> CancelHandler<'t, F> {
     /// Creates a new CancelHanlder given a handler to proxy/forward functions
     /// to and a function that returns true when the parse should be canceled
-    pub fn new(handler: &'t mut dyn Handler, cancel_fn: CancelFN) -> CancelHandler<'t> {
+    pub fn new(handler: &'t mut dyn Handler, cancel_fn: F) -> CancelHandler<'t, F> {
         CancelHandler {
             canceled: false,
             handler,
@@ -29,33 +34,33 @@ impl<'t> CancelHandler<'t> {
     }
 }
 
-impl Handler for CancelHandler<'_> {
+impl<F: FnMut(&Attribute) -> bool> Handler for CancelHandler<'_, F> {
     fn attribute(
         &mut self,
         attribute: &Attribute,
         position: usize,
         data_offset: usize,
     ) -> HandlerResult {
-        if (self.cancel_fn)(&attribute) {
+        if (self.cancel_fn)(attribute) {
             self.canceled = true;
             return HandlerResult::Cancel;
         }
-        self.handler.attribute(&attribute, position, data_offset)
+        self.handler.attribute(attribute, position, data_offset)
     }
-    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) {
-        self.handler.data(&attribute, data, complete)
+    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
+        self.handler.data(attribute, data, complete)
     }
     fn start_sequence(&mut self, attribute: &Attribute) {
-        self.handler.start_sequence(&attribute)
+        self.handler.start_sequence(attribute)
     }
-    fn start_sequence_item(&mut self, attribute: &Attribute) {
-        self.handler.start_sequence_item(&attribute)
+    fn start_sequence_item(&mut self, attribute: &Attribute, item: &SequenceItem) {
+        self.handler.start_sequence_item(attribute, item)
     }
     fn end_sequence_item(&mut self, attribute: &Attribute) {
-        self.handler.end_sequence_item(&attribute)
+        self.handler.end_sequence_item(attribute)
     }
     fn end_sequence(&mut self, attribute: &Attribute) {
-        self.handler.end_sequence(&attribute)
+        self.handler.end_sequence(attribute)
     }
     fn basic_offset_table(
         &mut self,
@@ -63,7 +68,7 @@ impl Handler for CancelHandler<'_> {
         data: &[u8],
         complete: bool,
     ) -> HandlerResult {
-        self.handler.basic_offset_table(&attribute, data, complete)
+        self.handler.basic_offset_table(attribute, data, complete)
     }
     fn pixel_data_fragment(
         &mut self,
@@ -73,6 +78,61 @@ impl Handler for CancelHandler<'_> {
         complete: bool,
     ) -> HandlerResult {
         self.handler
-            .pixel_data_fragment(&attribute, fragment_number, data, complete)
+            .pixel_data_fragment(attribute, fragment_number, data, complete)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CancelHandler;
+    use crate::attribute::Attribute;
+    use crate::handler::{Handler, HandlerResult};
+    use crate::tag::Tag;
+    use crate::test::tests::TestHandler;
+
+    #[test]
+    fn closure_can_capture_state() {
+        let mut handler = TestHandler::default();
+        let mut count = 0;
+        let mut cancel_handler = CancelHandler::new(&mut handler, |_x: &Attribute| {
+            count += 1;
+            count > 2
+        });
+        let attribute = Attribute::default();
+        assert_eq!(
+            cancel_handler.attribute(&attribute, 0, 0),
+            HandlerResult::Continue
+        );
+        assert_eq!(
+            cancel_handler.attribute(&attribute, 0, 0),
+            HandlerResult::Continue
+        );
+        assert_eq!(
+            cancel_handler.attribute(&attribute, 0, 0),
+            HandlerResult::Cancel
+        );
+        assert!(cancel_handler.canceled);
+        assert_eq!(handler.attributes.len(), 2);
+    }
+
+    #[test]
+    fn closure_can_capture_stop_tag() {
+        let mut handler = TestHandler::default();
+        let stop_tag = Tag::new(0x0020, 0x000E);
+        let mut cancel_handler =
+            CancelHandler::new(&mut handler, move |x: &Attribute| x.tag == stop_tag);
+        let mut attribute = Attribute {
+            tag: Tag::new(0x0020, 0x000D),
+            ..Attribute::default()
+        };
+        assert_eq!(
+            cancel_handler.attribute(&attribute, 0, 0),
+            HandlerResult::Continue
+        );
+        attribute.tag = stop_tag;
+        assert_eq!(
+            cancel_handler.attribute(&attribute, 0, 0),
+            HandlerResult::Cancel
+        );
     }
 }
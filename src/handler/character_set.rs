@@ -0,0 +1,207 @@
+use crate::attribute::Attribute;
+use crate::handler::accumulate::ValueHandler;
+use crate::handler::{Handler, HandlerResult, SequenceItem};
+use crate::tag::Tag;
+use crate::value::character_set::{texts, uses_character_set, SpecificCharacterSet};
+
+/// The TextHandler trait extends the ValueHandler trait with a function that
+/// is invoked with the decoded values of text attributes.  It is used with
+/// the CharacterSetHandler which takes care of tracking the Specific
+/// Character Set.
+pub trait TextHandler: ValueHandler {
+    /// Invoked after ValueHandler::value() for SH, LO, ST, LT, UT, PN and UC
+    /// attributes with the values decoded using the active Specific
+    /// Character Set.  Attributes without a VR (implicit VR encodings) are
+    /// not decoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `_attribute` - The attribute the values are for
+    /// * `_values`    - The decoded values of the attribute
+    fn text(&mut self, _attribute: &Attribute, _values: &[String]) -> HandlerResult {
+        HandlerResult::Continue
+    }
+}
+
+/// Implements the ValueHandler trait that tracks (0008,0005) Specific
+/// Character Set and decodes the values of text attributes for a
+/// TextHandler.  Sequence items inherit the Specific Character Set of their
+/// parent and may override it for their own attributes.  Values of Specific
+/// Character Set that are not recognized are ignored.  The TextHandler still
+/// receives every Handler and ValueHandler function, text() comes on top of
+/// value().  Use it with an AccumulatingHandler so the values are complete.
+pub struct CharacterSetHandler<'t, H: TextHandler + ?Sized> {
+    /// the TextHandler to forward/proxy function calls to
+    pub handler: &'t mut H,
+    // the Specific Character Set of the root DataSet followed by the ones
+    // for the sequence items currently being parsed
+    specific_character_sets: Vec<SpecificCharacterSet>,
+}
+
+impl<'t, H: TextHandler + ?Sized> CharacterSetHandler<'t, H> {
+    /// Creates a new CharacterSetHandler given a TextHandler to forward
+    /// functions to
+    pub fn new(handler: &'t mut H) -> CharacterSetHandler<'t, H> {
+        CharacterSetHandler {
+            handler,
+            specific_character_sets: vec![SpecificCharacterSet::default()],
+        }
+    }
+
+    /// Returns the Specific Character Set for the DataSet or sequence item
+    /// currently being parsed
+    pub fn specific_character_set(&self) -> &SpecificCharacterSet {
+        // there is always at least the one for the root DataSet
+        self.specific_character_sets.last().unwrap()
+    }
+}
+
+impl<H: TextHandler + ?Sized> Handler for CharacterSetHandler<'_, H> {
+    fn start_sequence_item(&mut self, attribute: &Attribute, item: &SequenceItem) {
+        let specific_character_set = self.specific_character_set().clone();
+        self.specific_character_sets.push(specific_character_set);
+        self.handler.start_sequence_item(attribute, item)
+    }
+    fn end_sequence_item(&mut self, attribute: &Attribute) {
+        if self.specific_character_sets.len() > 1 {
+            self.specific_character_sets.pop();
+        }
+        self.handler.end_sequence_item(attribute)
+    }
+    forward_handler!(
+        attribute,
+        data,
+        start_sequence,
+        end_sequence,
+        basic_offset_table,
+        pixel_data_fragment,
+    );
+}
+
+impl<H: TextHandler + ?Sized> ValueHandler for CharacterSetHandler<'_, H> {
+    fn value(&mut self, attribute: &Attribute, value: &[u8]) -> HandlerResult {
+        if attribute.tag == Tag::new(0x0008, 0x0005) {
+            let string = String::from_utf8_lossy(value);
+            if let Ok(specific_character_set) = SpecificCharacterSet::parse(&string) {
+                if let Some(last) = self.specific_character_sets.last_mut() {
+                    *last = specific_character_set;
+                }
+            }
+        }
+
+        match self.handler.value(attribute, value) {
+            HandlerResult::Continue => {}
+            handler_result => return handler_result,
+        }
+
+        match attribute.vr {
+            Some(vr) if uses_character_set(vr) => {
+                match texts(attribute, value, self.specific_character_set()) {
+                    Ok(values) => self.handler.text(attribute, &values),
+                    Err(_) => HandlerResult::Continue,
+                }
+            }
+            _ => HandlerResult::Continue,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CharacterSetHandler, TextHandler};
+    use crate::attribute::Attribute;
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::accumulate::{AccumulatingHandler, ValueHandler};
+    use crate::handler::Handler;
+    use crate::handler::HandlerResult;
+    use crate::handler::SequenceItem;
+    use crate::tag::Tag;
+    use crate::test::tests::read_data_set_bytes_from_file;
+    use crate::value::character_set::CharacterSet;
+    use crate::vr::VR;
+
+    // TextHandler that collects the decoded values
+    #[derive(Default)]
+    struct TestTextHandler {
+        texts: Vec<(Tag, Vec<String>)>,
+    }
+
+    impl Handler for TestTextHandler {}
+
+    impl ValueHandler for TestTextHandler {}
+
+    impl TextHandler for TestTextHandler {
+        fn text(&mut self, attribute: &Attribute, values: &[String]) -> HandlerResult {
+            self.texts.push((attribute.tag, values.to_vec()));
+            HandlerResult::Continue
+        }
+    }
+
+    fn make_attribute(tag: Tag, vr: VR, length: usize) -> Attribute {
+        Attribute {
+            tag,
+            vr: Some(vr),
+            length,
+            vr_inferred: false,
+        }
+    }
+
+    #[test]
+    fn decodes_text_values_in_data_set() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut text_handler = TestTextHandler::default();
+        let mut character_set_handler = CharacterSetHandler::new(&mut text_handler);
+        let mut handler = AccumulatingHandler::new(&mut character_set_handler, 1024);
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        match parser.parse(&mut handler, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert_eq!(
+            character_set_handler
+                .specific_character_set()
+                .character_sets(),
+            &[CharacterSet::IsoIr100]
+        );
+        let patient_name = text_handler
+            .texts
+            .iter()
+            .find(|(tag, _values)| *tag == Tag::new(0x0010, 0x0010))
+            .unwrap();
+        assert_eq!(patient_name.1, vec!["CompressedSamples^CT1"]);
+        // CS values are not decoded
+        assert!(text_handler
+            .texts
+            .iter()
+            .all(|(tag, _values)| *tag != Tag::new(0x0008, 0x0005)));
+    }
+
+    #[test]
+    fn sequence_items_override_specific_character_set() {
+        let mut text_handler = TestTextHandler::default();
+        let mut handler = CharacterSetHandler::new(&mut text_handler);
+        let specific_character_set = make_attribute(Tag::new(0x0008, 0x0005), VR::CS, 10);
+        let patient_name = make_attribute(Tag::new(0x0010, 0x0010), VR::PN, 2);
+        let sequence = make_attribute(Tag::new(0x0008, 0x1115), VR::SQ, 0xFFFF_FFFF);
+
+        handler.value(&specific_character_set, b"ISO_IR 100");
+        handler.start_sequence(&sequence);
+        handler.start_sequence_item(&sequence, &SequenceItem::default());
+        // inherited from the parent
+        handler.value(&patient_name, b"\xe9 ");
+        handler.value(&specific_character_set, b"ISO_IR 144");
+        handler.value(&patient_name, b"\xe9 ");
+        handler.end_sequence_item(&sequence);
+        handler.end_sequence(&sequence);
+        handler.value(&patient_name, b"\xe9 ");
+
+        let values: Vec<&str> = text_handler
+            .texts
+            .iter()
+            .map(|(_tag, values)| values[0].as_str())
+            .collect();
+        assert_eq!(values, vec!["é", "щ", "é"]);
+    }
+}
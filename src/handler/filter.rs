@@ -0,0 +1,197 @@
+use crate::attribute::Attribute;
+use crate::handler::{Handler, HandlerResult, SequenceItem};
+use crate::tag::Tag;
+
+/// Describes the attributes a FilterHandler forwards
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TagFilter {
+    /// A single tag
+    Tag(Tag),
+    /// All tags in a group
+    Group(u16),
+    /// All tags between the first and second tag (inclusive)
+    Range(Tag, Tag),
+    /// All private tags (odd group)
+    Private,
+    /// All public tags (even group)
+    Public,
+}
+
+impl TagFilter {
+    /// Returns true if the tag is selected by this filter
+    pub fn matches(&self, tag: Tag) -> bool {
+        match *self {
+            TagFilter::Tag(filter_tag) => tag == filter_tag,
+            TagFilter::Group(group) => tag.group == group,
+            TagFilter::Range(first, last) => tag >= first && tag <= last,
+            TagFilter::Private => tag.is_private(),
+            TagFilter::Public => !tag.is_private(),
+        }
+    }
+}
+
+/// Implements the Handler trait that forwards function calls to another
+/// Handler only for attributes in the root DataSet that match one of its
+/// filters.  Attributes that do not match are skipped.  If a sequence
+/// matches, everything in it (nested items, sequences and their attributes)
+/// is forwarded so the sequence events the Handler sees are always
+/// consistent.  Attributes nested in sequences are not matched on their own.
+pub struct FilterHandler<'t> {
+    /// the Handler to forward/proxy function calls to
+    pub handler: &'t mut dyn Handler,
+    /// the filters that select the attributes to forward
+    pub filters: Vec<TagFilter>,
+    // true if the current root DataSet attribute matched
+    included: bool,
+    // current sequence depth, zero for the root DataSet
+    depth: usize,
+}
+
+impl<'t> FilterHandler<'t> {
+    /// Creates a new FilterHandler given a handler to proxy/forward functions
+    /// to and the filters that select the attributes to forward
+    pub fn new(handler: &'t mut dyn Handler, filters: Vec<TagFilter>) -> FilterHandler<'t> {
+        FilterHandler {
+            handler,
+            filters,
+            included: false,
+            depth: 0,
+        }
+    }
+}
+
+impl Handler for FilterHandler<'_> {
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        position: usize,
+        data_offset: usize,
+    ) -> HandlerResult {
+        if self.depth == 0 {
+            self.included = self.filters.iter().any(|x| x.matches(attribute.tag));
+        }
+        if !self.included {
+            return HandlerResult::Skip;
+        }
+        self.handler.attribute(attribute, position, data_offset)
+    }
+    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
+        if !self.included {
+            return HandlerResult::Skip;
+        }
+        self.handler.data(attribute, data, complete)
+    }
+    fn start_sequence(&mut self, attribute: &Attribute) {
+        self.depth += 1;
+        if self.included {
+            self.handler.start_sequence(attribute)
+        }
+    }
+    fn start_sequence_item(&mut self, attribute: &Attribute, item: &SequenceItem) {
+        if self.included {
+            self.handler.start_sequence_item(attribute, item)
+        }
+    }
+    fn end_sequence_item(&mut self, attribute: &Attribute) {
+        if self.included {
+            self.handler.end_sequence_item(attribute)
+        }
+    }
+    fn end_sequence(&mut self, attribute: &Attribute) {
+        self.depth -= 1;
+        if self.included {
+            self.handler.end_sequence(attribute)
+        }
+    }
+    fn basic_offset_table(
+        &mut self,
+        attribute: &Attribute,
+        data: &[u8],
+        complete: bool,
+    ) -> HandlerResult {
+        if !self.included {
+            return HandlerResult::Skip;
+        }
+        self.handler.basic_offset_table(attribute, data, complete)
+    }
+    fn pixel_data_fragment(
+        &mut self,
+        attribute: &Attribute,
+        fragment_number: usize,
+        data: &[u8],
+        complete: bool,
+    ) -> HandlerResult {
+        if !self.included {
+            return HandlerResult::Skip;
+        }
+        self.handler
+            .pixel_data_fragment(attribute, fragment_number, data, complete)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FilterHandler, TagFilter};
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::{Encoding, ExplicitLittleEndian, ImplicitLittleEndian};
+    use crate::handler::Handler;
+    use crate::tag::Tag;
+    use crate::test::tests::read_data_set_bytes_from_file;
+    use crate::test::tests::TestHandler;
+
+    fn parse<T: 'static + Encoding>(filepath: &str, handler: &mut dyn Handler) {
+        let (meta, bytes) = read_data_set_bytes_from_file(filepath);
+        let mut parser = DataSetParser::<T>::default();
+        if parser.parse(handler, &bytes, meta.end_position).is_err() {
+            panic!("Let's play Global Thermonuclear War");
+        }
+    }
+
+    #[test]
+    fn tag_filter_matches() {
+        let tag = Tag::new(0x0010, 0x0020);
+        assert!(TagFilter::Tag(tag).matches(tag));
+        assert!(!TagFilter::Tag(tag).matches(Tag::new(0x0010, 0x0021)));
+        assert!(TagFilter::Group(0x0010).matches(tag));
+        assert!(!TagFilter::Group(0x0008).matches(tag));
+        assert!(TagFilter::Range(Tag::new(0x0010, 0x0000), tag).matches(tag));
+        assert!(!TagFilter::Range(Tag::new(0x0010, 0x0000), tag).matches(Tag::new(0x0010, 0x0021)));
+        assert!(TagFilter::Private.matches(Tag::new(0x0009, 0x0010)));
+        assert!(!TagFilter::Private.matches(tag));
+        assert!(TagFilter::Public.matches(tag));
+    }
+
+    #[test]
+    fn forwards_only_matching_attributes() {
+        let mut handler = TestHandler::default();
+        let patient_name = Tag::new(0x0010, 0x0010);
+        let mut filter_handler = FilterHandler::new(
+            &mut handler,
+            vec![TagFilter::Tag(patient_name), TagFilter::Group(0x0028)],
+        );
+        parse::<ExplicitLittleEndian>(
+            "tests/fixtures/CT1_UNC.explicit_little_endian.dcm",
+            &mut filter_handler,
+        );
+        assert!(handler.attributes.len() > 1);
+        assert_eq!(handler.attributes[0].tag, patient_name);
+        assert!(handler.attributes[1..]
+            .iter()
+            .all(|x| x.tag.group == 0x0028));
+        assert_eq!(handler.attributes.len(), handler.data.len());
+    }
+
+    #[test]
+    fn forwards_nested_attributes_of_matching_sequence() {
+        let mut handler = TestHandler::default();
+        let sequence = Tag::new(0x0018, 0x6011);
+        let mut filter_handler = FilterHandler::new(&mut handler, vec![TagFilter::Tag(sequence)]);
+        parse::<ImplicitLittleEndian>(
+            "tests/fixtures/IM00001.implicit_little_endian.dcm",
+            &mut filter_handler,
+        );
+        assert_eq!(handler.attributes[0].tag, sequence);
+        assert!(handler.attributes.len() > 1);
+        assert_eq!(handler.depth, 0);
+    }
+}
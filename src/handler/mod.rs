@@ -1,13 +1,26 @@
 use crate::attribute::Attribute;
 
 /// Enum returned from Handler implementations to instruct the parser to
-/// continue parsing or cancel parsing.  
-#[derive(PartialEq)]
+/// continue parsing, skip the value field of the current attribute or cancel
+/// parsing.
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum HandlerResult {
     Continue, // continue (decode the element's data)
+    Skip,     // skip the rest of the element's data without delivering it
     Cancel,   // stop parsing
 }
 
+/// Describes a sequence item passed to Handler::start_sequence_item()
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceItem {
+    /// The number of the item in the sequence, starting at zero
+    pub index: usize,
+    /// The offset from the beginning of the stream of the item tag
+    pub position: usize,
+    /// The length of the item, 0xFFFFFFFF if undefined
+    pub length: usize,
+}
+
 /// The Handler trait defines a callback interface that is called when
 /// parsing a DICOM DataSet allowing the parsed data to be processed.
 /// Note that DICOM DataSet are tree like due to sequences, implementations
@@ -15,15 +28,18 @@ pub enum HandlerResult {
 pub trait Handler {
     /// Invoked every time an Attribute is parsed.  Note that the data for the
     /// attribute is provided via the data function below and may have not been
-    /// provided to the parser yet (due to streaming).  
+    /// provided to the parser yet (due to streaming).  Returning Skip advances
+    /// over the value field without invoking any other functions for it
+    /// (including those for sequence items and pixel data fragments)
     ///
     /// # Arguments
     ///
     /// * `_attribute`   - The attribute parsed (Tag, VR, Length)
     /// * `_position`    - The offset from the beginning of the stream of the
-    ///                    first byte of the Attribute
+    ///   first byte of the Attribute.  For deflated DataSets
+    ///   this is the offset in the inflated stream
     /// * `_data_offset` - The offset from _position of the attribute's value
-    ///                    field
+    ///   field
     fn attribute(
         &mut self,
         _attribute: &Attribute,
@@ -41,7 +57,13 @@ pub trait Handler {
     /// * `_attribute` - the Attribute corresponding to this data
     /// * `_data`      - the raw bytes for the value field
     /// * `_complete`  - true if this is the data is complete, false if not
-    fn data(&mut self, _attribute: &Attribute, _data: &[u8], _complete: bool) {}
+    ///
+    /// Returning Skip advances over the rest of the value field without
+    /// further calls to data() and returning Cancel stops the parse after the
+    /// provided data
+    fn data(&mut self, _attribute: &Attribute, _data: &[u8], _complete: bool) -> HandlerResult {
+        HandlerResult::Continue
+    }
     /// Invoked after attribute() for Sequences Attributes instead of data().
     /// A corresponding call to end_sequence() will be made once the value
     /// field for the sequence is fully parsed.
@@ -51,7 +73,12 @@ pub trait Handler {
     /// sequence item is fully parsed.  Parsing a sequence item includes
     /// zero or more calls to attribute() for each attribute in the sequence
     /// item
-    fn start_sequence_item(&mut self, _attribute: &Attribute) {}
+    ///
+    /// # Arguments
+    ///
+    /// * `_attribute` - The sequence attribute
+    /// * `_item`      - The index, position and length of the sequence item
+    fn start_sequence_item(&mut self, _attribute: &Attribute, _item: &SequenceItem) {}
     /// Invoked after all attributes in a sequence item are parsed.  
     /// Corresponds to exactly one prior call to start_sequence_item()
     fn end_sequence_item(&mut self, _attribute: &Attribute) {}
@@ -63,7 +90,9 @@ pub trait Handler {
     /// empty (or zero length)
     /// This function may be invoked multiple times for the same attribute
     /// due to streaming.  Handler implementations are responsible for
-    /// concatenating the received data in this case
+    /// concatenating the received data in this case.  Returning Skip skips
+    /// the rest of the pixel data and returning Cancel stops the parse after
+    /// the provided data
     fn basic_offset_table(
         &mut self,
         _attribute: &Attribute,
@@ -78,7 +107,9 @@ pub trait Handler {
     /// confirm this)
     /// This function may be invoked multiple times for the same attribute
     /// due to streaming.  Handler implementations are responsible for
-    /// concatenating the received data in this case
+    /// concatenating the received data in this case.  Returning Skip skips
+    /// the rest of the pixel data and returning Cancel stops the parse after
+    /// the provided data
     fn pixel_data_fragment(
         &mut self,
         _attribute: &Attribute,
@@ -90,5 +121,88 @@ pub trait Handler {
     }
 }
 
+// Implements the listed Handler functions of a wrapper Handler by passing
+// the call on to its handler field unchanged.  The wrapper implements the
+// functions it needs to intercept itself, e.g.
+// forward_handler!(data, start_sequence, end_sequence);
+macro_rules! forward_handler {
+    ($($function:ident),* $(,)?) => {
+        $(forward_handler!(@ $function);)*
+    };
+    (@ attribute) => {
+        fn attribute(
+            &mut self,
+            attribute: &$crate::attribute::Attribute,
+            position: usize,
+            data_offset: usize,
+        ) -> $crate::handler::HandlerResult {
+            self.handler.attribute(attribute, position, data_offset)
+        }
+    };
+    (@ data) => {
+        fn data(
+            &mut self,
+            attribute: &$crate::attribute::Attribute,
+            data: &[u8],
+            complete: bool,
+        ) -> $crate::handler::HandlerResult {
+            self.handler.data(attribute, data, complete)
+        }
+    };
+    (@ start_sequence) => {
+        fn start_sequence(&mut self, attribute: &$crate::attribute::Attribute) {
+            self.handler.start_sequence(attribute)
+        }
+    };
+    (@ start_sequence_item) => {
+        fn start_sequence_item(
+            &mut self,
+            attribute: &$crate::attribute::Attribute,
+            item: &$crate::handler::SequenceItem,
+        ) {
+            self.handler.start_sequence_item(attribute, item)
+        }
+    };
+    (@ end_sequence_item) => {
+        fn end_sequence_item(&mut self, attribute: &$crate::attribute::Attribute) {
+            self.handler.end_sequence_item(attribute)
+        }
+    };
+    (@ end_sequence) => {
+        fn end_sequence(&mut self, attribute: &$crate::attribute::Attribute) {
+            self.handler.end_sequence(attribute)
+        }
+    };
+    (@ basic_offset_table) => {
+        fn basic_offset_table(
+            &mut self,
+            attribute: &$crate::attribute::Attribute,
+            data: &[u8],
+            complete: bool,
+        ) -> $crate::handler::HandlerResult {
+            self.handler.basic_offset_table(attribute, data, complete)
+        }
+    };
+    (@ pixel_data_fragment) => {
+        fn pixel_data_fragment(
+            &mut self,
+            attribute: &$crate::attribute::Attribute,
+            fragment_number: usize,
+            data: &[u8],
+            complete: bool,
+        ) -> $crate::handler::HandlerResult {
+            self.handler
+                .pixel_data_fragment(attribute, fragment_number, data, complete)
+        }
+    };
+}
+
+pub mod accumulate;
 pub mod cancel;
+pub mod character_set;
+pub mod filter;
+pub mod path;
+pub mod private;
+pub mod tag_cancel;
+pub mod tag_path_filter;
 pub mod tee;
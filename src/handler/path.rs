@@ -0,0 +1,448 @@
+use crate::attribute::Attribute;
+use crate::handler::accumulate::{AccumulatingHandler, ValueHandler};
+use crate::handler::character_set::{CharacterSetHandler, TextHandler};
+use crate::handler::private::{
+    PrivateCreatorHandler, PrivateDictionaryEntry, PrivateHandler, PrivateTag,
+};
+use crate::handler::{Handler, HandlerResult, SequenceItem};
+use crate::tag::Tag;
+use std::fmt;
+
+/// A sequence attribute and the item in it an AttributePath passes through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathItem {
+    /// The tag of the sequence attribute
+    pub tag: Tag,
+    /// The sequence item
+    pub item: SequenceItem,
+}
+
+/// The location of an attribute in a DataSet, the sequence items it is
+/// nested in followed by its tag.  Formats as (0040,A730)[2].(0008,0100)
+/// with the item indexes starting at zero.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AttributePath {
+    /// The sequence items from the root DataSet to the attribute, empty for
+    /// attributes in the root DataSet
+    pub items: Vec<PathItem>,
+    /// The tag of the attribute
+    pub tag: Tag,
+}
+
+impl AttributePath {
+    /// Returns the sequence depth of the attribute, zero for the root DataSet
+    pub fn depth(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl fmt::Display for AttributePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for path_item in &self.items {
+            write!(f, "{:?}[{}].", path_item.tag, path_item.item.index)?;
+        }
+        write!(f, "{:?}", self.tag)
+    }
+}
+
+/// The PathHandler trait extends the Handler trait with a function that
+/// receives the AttributePath of the attribute each Handler function is for.
+/// It is used with the AttributePathHandler which takes care of tracking the
+/// path.
+pub trait PathHandler: Handler {
+    /// Invoked before every Handler function (and the ValueHandler,
+    /// TextHandler and PrivateHandler functions when they are stacked) with
+    /// the path of the attribute the function is for.  For the sequence and
+    /// sequence item functions this is the path of the sequence attribute
+    ///
+    /// # Arguments
+    ///
+    /// * `_path` - The path of the attribute
+    fn path(&mut self, _path: &AttributePath) {}
+}
+
+/// Implements the Handler trait that tracks the AttributePath of the
+/// attribute being parsed for a PathHandler.  Each function is passed on
+/// unchanged after PathHandler::path() has been invoked with the path it is
+/// for, so the PathHandler always knows where in the DataSet it is.
+pub struct AttributePathHandler<'t, H: PathHandler + ?Sized> {
+    /// the PathHandler to forward/proxy function calls to
+    pub handler: &'t mut H,
+    path: AttributePath,
+}
+
+impl<'t, H: PathHandler + ?Sized> AttributePathHandler<'t, H> {
+    /// Creates a new AttributePathHandler given a PathHandler to forward
+    /// functions to
+    pub fn new(handler: &'t mut H) -> AttributePathHandler<'t, H> {
+        AttributePathHandler {
+            handler,
+            path: AttributePath::default(),
+        }
+    }
+
+    /// Returns the path of the last attribute parsed
+    pub fn path(&self) -> &AttributePath {
+        &self.path
+    }
+}
+
+impl<H: PathHandler + ?Sized> Handler for AttributePathHandler<'_, H> {
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        position: usize,
+        data_offset: usize,
+    ) -> HandlerResult {
+        self.path.tag = attribute.tag;
+        self.handler.path(&self.path);
+        self.handler.attribute(attribute, position, data_offset)
+    }
+    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
+        self.handler.path(&self.path);
+        self.handler.data(attribute, data, complete)
+    }
+    fn start_sequence(&mut self, attribute: &Attribute) {
+        self.handler.path(&self.path);
+        self.handler.start_sequence(attribute)
+    }
+    fn start_sequence_item(&mut self, attribute: &Attribute, item: &SequenceItem) {
+        self.handler.path(&self.path);
+        self.handler.start_sequence_item(attribute, item);
+        self.path.items.push(PathItem {
+            tag: attribute.tag,
+            item: *item,
+        });
+    }
+    fn end_sequence_item(&mut self, attribute: &Attribute) {
+        self.path.items.pop();
+        self.path.tag = attribute.tag;
+        self.handler.path(&self.path);
+        self.handler.end_sequence_item(attribute)
+    }
+    fn end_sequence(&mut self, attribute: &Attribute) {
+        self.handler.path(&self.path);
+        self.handler.end_sequence(attribute)
+    }
+    fn basic_offset_table(
+        &mut self,
+        attribute: &Attribute,
+        data: &[u8],
+        complete: bool,
+    ) -> HandlerResult {
+        self.handler.path(&self.path);
+        self.handler.basic_offset_table(attribute, data, complete)
+    }
+    fn pixel_data_fragment(
+        &mut self,
+        attribute: &Attribute,
+        fragment_number: usize,
+        data: &[u8],
+        complete: bool,
+    ) -> HandlerResult {
+        self.handler.path(&self.path);
+        self.handler
+            .pixel_data_fragment(attribute, fragment_number, data, complete)
+    }
+}
+
+// the AttributePathHandler can be used on either side of the
+// AccumulatingHandler, CharacterSetHandler and PrivateCreatorHandler with a
+// handler that implements their traits too
+
+impl<H: PathHandler + ValueHandler + ?Sized> ValueHandler for AttributePathHandler<'_, H> {
+    fn value(&mut self, attribute: &Attribute, value: &[u8]) -> HandlerResult {
+        self.handler.path(&self.path);
+        self.handler.value(attribute, value)
+    }
+}
+
+impl<H: PathHandler + TextHandler + ?Sized> TextHandler for AttributePathHandler<'_, H> {
+    fn text(&mut self, attribute: &Attribute, values: &[String]) -> HandlerResult {
+        self.handler.path(&self.path);
+        self.handler.text(attribute, values)
+    }
+}
+
+impl<H: PathHandler + PrivateHandler + ?Sized> PrivateHandler for AttributePathHandler<'_, H> {
+    fn private_attribute(
+        &mut self,
+        attribute: &Attribute,
+        private_tag: &PrivateTag,
+        entry: Option<&PrivateDictionaryEntry>,
+    ) -> HandlerResult {
+        self.path.tag = attribute.tag;
+        self.handler.path(&self.path);
+        self.handler
+            .private_attribute(attribute, private_tag, entry)
+    }
+}
+
+impl<H: ValueHandler + PathHandler + ?Sized> PathHandler for AccumulatingHandler<'_, H> {
+    fn path(&mut self, path: &AttributePath) {
+        self.handler.path(path)
+    }
+}
+
+impl<H: TextHandler + PathHandler + ?Sized> PathHandler for CharacterSetHandler<'_, H> {
+    fn path(&mut self, path: &AttributePath) {
+        self.handler.path(path)
+    }
+}
+
+impl<H: PrivateHandler + PathHandler + ?Sized> PathHandler for PrivateCreatorHandler<'_, H> {
+    fn path(&mut self, path: &AttributePath) {
+        self.handler.path(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttributePath, AttributePathHandler, PathHandler, PathItem};
+    use crate::attribute::Attribute;
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::accumulate::{AccumulatingHandler, ValueHandler};
+    use crate::handler::character_set::{CharacterSetHandler, TextHandler};
+    use crate::handler::private::{
+        PrivateCreatorHandler, PrivateDictionaryEntry, PrivateHandler, PrivateTag,
+    };
+    use crate::handler::{Handler, HandlerResult, SequenceItem};
+    use crate::tag::Tag;
+    use crate::test::tests::read_data_set_bytes_from_file;
+
+    // PathHandler that collects the paths of the attributes, the other
+    // functions with the path they were invoked for and the sequence items
+    #[derive(Default)]
+    struct TestPathHandler {
+        path: AttributePath,
+        paths: Vec<AttributePath>,
+        functions: Vec<String>,
+        items: Vec<SequenceItem>,
+    }
+
+    impl Handler for TestPathHandler {
+        fn attribute(
+            &mut self,
+            _attribute: &Attribute,
+            _position: usize,
+            _data_offset: usize,
+        ) -> HandlerResult {
+            self.paths.push(self.path.clone());
+            HandlerResult::Continue
+        }
+        fn data(&mut self, _attribute: &Attribute, _data: &[u8], _complete: bool) -> HandlerResult {
+            self.functions.push(format!("data {}", self.path));
+            HandlerResult::Continue
+        }
+        fn start_sequence(&mut self, _attribute: &Attribute) {
+            self.functions.push(format!("start_sequence {}", self.path));
+        }
+        fn start_sequence_item(&mut self, _attribute: &Attribute, item: &SequenceItem) {
+            self.functions
+                .push(format!("start_sequence_item {}", self.path));
+            self.items.push(*item);
+        }
+        fn end_sequence_item(&mut self, _attribute: &Attribute) {
+            self.functions
+                .push(format!("end_sequence_item {}", self.path));
+        }
+        fn end_sequence(&mut self, _attribute: &Attribute) {
+            self.functions.push(format!("end_sequence {}", self.path));
+        }
+    }
+
+    impl PathHandler for TestPathHandler {
+        fn path(&mut self, path: &AttributePath) {
+            self.path = path.clone();
+        }
+    }
+
+    #[test]
+    fn formats_path() {
+        let item = SequenceItem {
+            index: 2,
+            position: 0,
+            length: 0xFFFF_FFFF,
+        };
+        let path = AttributePath {
+            items: vec![PathItem {
+                tag: Tag::new(0x0040, 0xA730),
+                item,
+            }],
+            tag: Tag::new(0x0008, 0x0100),
+        };
+        assert_eq!(path.to_string(), "(0040,A730)[2].(0008,0100)");
+        assert_eq!(path.depth(), 1);
+        assert_eq!(AttributePath::default().to_string(), "(0000,0000)");
+    }
+
+    fn make_sequence() -> Vec<u8> {
+        let mut bytes = vec![];
+        // (0008,1115) with undefined length
+        bytes.extend_from_slice(&[
+            0x08, 0x00, 0x15, 0x11, b'S', b'Q', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF,
+        ]);
+        // item with a known length
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 10, 0, 0, 0]);
+        bytes.extend_from_slice(&[0x08, 0x00, 0x50, 0x11, b'U', b'I', 2, 0, b'1', 0]);
+        // item with undefined length
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF]);
+        bytes.extend_from_slice(&[0x08, 0x00, 0x55, 0x11, b'U', b'I', 2, 0, b'2', 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0]);
+        // sequence delimitation item
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0x10, 0x00, 0x10, 0x00, b'P', b'N', 2, 0, b'A', b' ']);
+
+        bytes
+    }
+
+    #[test]
+    fn tracks_paths_in_data_set() {
+        let bytes = make_sequence();
+        let mut path_handler = TestPathHandler::default();
+        let mut handler = AttributePathHandler::new(&mut path_handler);
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        match parser.parse(&mut handler, &bytes, 100) {
+            Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert_eq!(handler.path().depth(), 0);
+
+        let paths: Vec<String> = path_handler
+            .paths
+            .iter()
+            .map(|path| path.to_string())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "(0008,1115)",
+                "(0008,1115)[0].(0008,1150)",
+                "(0008,1115)[1].(0008,1155)",
+                "(0010,0010)"
+            ]
+        );
+        assert_eq!(
+            path_handler.items,
+            vec![
+                SequenceItem {
+                    index: 0,
+                    position: 112,
+                    length: 10
+                },
+                SequenceItem {
+                    index: 1,
+                    position: 130,
+                    length: 0xFFFF_FFFF
+                }
+            ]
+        );
+        assert_eq!(
+            path_handler.functions,
+            vec![
+                "start_sequence (0008,1115)",
+                "start_sequence_item (0008,1115)",
+                "data (0008,1115)[0].(0008,1150)",
+                "end_sequence_item (0008,1115)",
+                "start_sequence_item (0008,1115)",
+                "data (0008,1115)[1].(0008,1155)",
+                "end_sequence_item (0008,1115)",
+                "end_sequence (0008,1115)",
+                "data (0010,0010)"
+            ]
+        );
+    }
+
+    // Handler with all the extension traits that records the path each of
+    // their functions was invoked for
+    #[derive(Default)]
+    struct StackedHandler {
+        path: AttributePath,
+        values: usize,
+        texts: Vec<(String, Vec<String>)>,
+        private_attributes: Vec<(String, String)>,
+    }
+
+    impl Handler for StackedHandler {}
+
+    impl ValueHandler for StackedHandler {
+        fn value(&mut self, attribute: &Attribute, _value: &[u8]) -> HandlerResult {
+            assert_eq!(self.path.tag, attribute.tag);
+            self.values += 1;
+            HandlerResult::Continue
+        }
+    }
+
+    impl TextHandler for StackedHandler {
+        fn text(&mut self, _attribute: &Attribute, values: &[String]) -> HandlerResult {
+            self.texts.push((self.path.to_string(), values.to_vec()));
+            HandlerResult::Continue
+        }
+    }
+
+    impl PrivateHandler for StackedHandler {
+        fn private_attribute(
+            &mut self,
+            _attribute: &Attribute,
+            private_tag: &PrivateTag,
+            _entry: Option<&PrivateDictionaryEntry>,
+        ) -> HandlerResult {
+            self.private_attributes
+                .push((self.path.to_string(), private_tag.creator.clone()));
+            HandlerResult::Continue
+        }
+    }
+
+    impl PathHandler for StackedHandler {
+        fn path(&mut self, path: &AttributePath) {
+            self.path = path.clone();
+        }
+    }
+
+    fn parse_stacked(handler: &mut dyn Handler) {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        match parser.parse(handler, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+    }
+
+    #[test]
+    fn extension_traits_stack() {
+        let mut first = StackedHandler::default();
+        {
+            let mut private_creator_handler = PrivateCreatorHandler::new(&mut first);
+            let mut character_set_handler = CharacterSetHandler::new(&mut private_creator_handler);
+            let mut path_handler = AttributePathHandler::new(&mut character_set_handler);
+            let mut handler = AccumulatingHandler::new(&mut path_handler, 1024);
+            parse_stacked(&mut handler);
+        }
+        let mut second = StackedHandler::default();
+        {
+            let mut character_set_handler = CharacterSetHandler::new(&mut second);
+            let mut private_creator_handler =
+                PrivateCreatorHandler::new(&mut character_set_handler);
+            let mut accumulating_handler =
+                AccumulatingHandler::new(&mut private_creator_handler, 1024);
+            let mut handler = AttributePathHandler::new(&mut accumulating_handler);
+            parse_stacked(&mut handler);
+        }
+        for handler in &[&first, &second] {
+            assert!(handler.values > 0);
+            assert!(handler.texts.contains(&(
+                String::from("(0010,0010)"),
+                vec![String::from("CompressedSamples^CT1")]
+            )));
+            assert_eq!(
+                handler.private_attributes[0],
+                (String::from("(0009,1001)"), String::from("GEMS_IDEN_01"))
+            );
+        }
+        assert_eq!(first.values, second.values);
+        assert_eq!(first.texts, second.texts);
+        assert_eq!(first.private_attributes, second.private_attributes);
+    }
+}
@@ -0,0 +1,401 @@
+use crate::attribute::Attribute;
+use crate::handler::accumulate::ValueHandler;
+use crate::handler::character_set::{CharacterSetHandler, TextHandler};
+use crate::handler::{Handler, HandlerResult, SequenceItem};
+use crate::tag::Tag;
+use crate::vr::VR;
+use std::collections::HashMap;
+
+/// A private data element (gggg,xxyy) resolved to the Private Creator of its
+/// block (gggg,00xx)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrivateTag {
+    /// The odd group of the data element
+    pub group: u16,
+    /// The value of the Private Creator data element reserving the block
+    pub creator: String,
+    /// The element within the block (the low byte of the element number)
+    pub element: u8,
+}
+
+/// An entry of a private data dictionary
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrivateDictionaryEntry {
+    /// The Private Creator (e.g. SIEMENS CSA HEADER)
+    pub creator: &'static str,
+    /// The odd group of the data element
+    pub group: u16,
+    /// The element within the block (the low byte of the element number)
+    pub element: u8,
+    /// The keyword (e.g. CSAImageHeaderInfo)
+    pub keyword: &'static str,
+    /// The name (e.g. CSA Image Header Info)
+    pub name: &'static str,
+    /// The VR of the data element or None if it is not known
+    pub vr: Option<VR>,
+}
+
+/// A private data dictionary the PrivateCreatorHandler looks up private data
+/// elements in.  Implemented for arrays and Vecs of PrivateDictionaryEntry so
+/// a table can be used as is.
+pub trait PrivateDictionary {
+    /// Returns the entry for a private data element or None if it is not in
+    /// the dictionary
+    fn lookup(&self, private_tag: &PrivateTag) -> Option<&PrivateDictionaryEntry>;
+}
+
+fn find<'a>(
+    entries: &'a [PrivateDictionaryEntry],
+    private_tag: &PrivateTag,
+) -> Option<&'a PrivateDictionaryEntry> {
+    entries.iter().find(|entry| {
+        entry.group == private_tag.group
+            && entry.element == private_tag.element
+            && entry.creator == private_tag.creator
+    })
+}
+
+impl<const N: usize> PrivateDictionary for [PrivateDictionaryEntry; N] {
+    fn lookup(&self, private_tag: &PrivateTag) -> Option<&PrivateDictionaryEntry> {
+        find(self, private_tag)
+    }
+}
+
+impl PrivateDictionary for Vec<PrivateDictionaryEntry> {
+    fn lookup(&self, private_tag: &PrivateTag) -> Option<&PrivateDictionaryEntry> {
+        find(self, private_tag)
+    }
+}
+
+/// The PrivateHandler trait extends the ValueHandler trait with a function
+/// that is invoked for private data elements.  It is used with the
+/// PrivateCreatorHandler which takes care of tracking the Private Creators.
+pub trait PrivateHandler: ValueHandler {
+    /// Invoked before Handler::attribute() for private data elements
+    /// (gggg,1000-FFFF) in a block reserved by a Private Creator.  Private
+    /// data elements without a Private Creator are not reported.  Returning
+    /// Skip or Cancel is returned to the parser without invoking
+    /// Handler::attribute()
+    ///
+    /// # Arguments
+    ///
+    /// * `_attribute`   - The private attribute
+    /// * `_private_tag` - The attribute's group, Private Creator and element
+    /// * `_entry`       - The entry from the private dictionary or None if
+    ///   there is no dictionary or it doesn't have one
+    fn private_attribute(
+        &mut self,
+        _attribute: &Attribute,
+        _private_tag: &PrivateTag,
+        _entry: Option<&PrivateDictionaryEntry>,
+    ) -> HandlerResult {
+        HandlerResult::Continue
+    }
+}
+
+/// Returns true if the tag is a Private Creator data element (gggg,0010-00FF)
+pub fn is_private_creator(tag: Tag) -> bool {
+    is_private_group(tag.group) && tag.element >= 0x0010 && tag.element <= 0x00FF
+}
+
+// groups 0001, 0003, 0005, 0007 and FFFF are not private groups (PS3.5 7.8.1)
+fn is_private_group(group: u16) -> bool {
+    group & 1 == 1 && group > 0x0007 && group != 0xFFFF
+}
+
+/// Implements the ValueHandler trait that tracks the Private Creator data
+/// elements and resolves private data elements to their Private Creator for
+/// a PrivateHandler.  Each sequence item has its own Private Creators.
+/// private_attribute() is invoked ahead of attribute() and can skip or
+/// cancel it, the other functions are passed on unchanged.  Use it with an
+/// AccumulatingHandler so the Private Creator values are complete.
+pub struct PrivateCreatorHandler<'t, H: PrivateHandler + ?Sized> {
+    /// the PrivateHandler to forward/proxy function calls to
+    pub handler: &'t mut H,
+    /// the private dictionary to look up private data elements in
+    pub private_dictionary: Option<&'t dyn PrivateDictionary>,
+    // the Private Creators of the root DataSet followed by the ones for the
+    // sequence items currently being parsed keyed by the tag of the Private
+    // Creator data element
+    private_creators: Vec<HashMap<Tag, String>>,
+}
+
+impl<'t, H: PrivateHandler + ?Sized> PrivateCreatorHandler<'t, H> {
+    /// Creates a new PrivateCreatorHandler given a PrivateHandler to forward
+    /// functions to
+    pub fn new(handler: &'t mut H) -> PrivateCreatorHandler<'t, H> {
+        PrivateCreatorHandler {
+            handler,
+            private_dictionary: None,
+            private_creators: vec![HashMap::new()],
+        }
+    }
+
+    /// Returns the PrivateTag for a private data element in the DataSet or
+    /// sequence item currently being parsed or None if the tag is not a
+    /// private data element or its block is not reserved
+    pub fn private_tag(&self, tag: Tag) -> Option<PrivateTag> {
+        if !is_private_group(tag.group) || tag.element < 0x1000 {
+            return None;
+        }
+        let creator_tag = Tag::new(tag.group, tag.element >> 8);
+        // there is always at least the one for the root DataSet
+        let creator = self.private_creators.last().unwrap().get(&creator_tag)?;
+        Some(PrivateTag {
+            group: tag.group,
+            creator: creator.clone(),
+            element: (tag.element & 0xFF) as u8,
+        })
+    }
+}
+
+impl<H: PrivateHandler + ?Sized> Handler for PrivateCreatorHandler<'_, H> {
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        position: usize,
+        data_offset: usize,
+    ) -> HandlerResult {
+        if let Some(private_tag) = self.private_tag(attribute.tag) {
+            let entry = self
+                .private_dictionary
+                .and_then(|private_dictionary| private_dictionary.lookup(&private_tag));
+            match self
+                .handler
+                .private_attribute(attribute, &private_tag, entry)
+            {
+                HandlerResult::Continue => {}
+                handler_result => return handler_result,
+            }
+        }
+        self.handler.attribute(attribute, position, data_offset)
+    }
+    fn start_sequence_item(&mut self, attribute: &Attribute, item: &SequenceItem) {
+        self.private_creators.push(HashMap::new());
+        self.handler.start_sequence_item(attribute, item)
+    }
+    fn end_sequence_item(&mut self, attribute: &Attribute) {
+        if self.private_creators.len() > 1 {
+            self.private_creators.pop();
+        }
+        self.handler.end_sequence_item(attribute)
+    }
+    forward_handler!(
+        data,
+        start_sequence,
+        end_sequence,
+        basic_offset_table,
+        pixel_data_fragment,
+    );
+}
+
+impl<H: PrivateHandler + ?Sized> ValueHandler for PrivateCreatorHandler<'_, H> {
+    fn value(&mut self, attribute: &Attribute, value: &[u8]) -> HandlerResult {
+        if is_private_creator(attribute.tag) {
+            let creator = String::from_utf8_lossy(value);
+            let creator = creator.trim_matches(&[' ', '\0'][..]);
+            if let Some(private_creators) = self.private_creators.last_mut() {
+                private_creators.insert(attribute.tag, String::from(creator));
+            }
+        }
+        self.handler.value(attribute, value)
+    }
+}
+
+// a TextHandler that is also a PrivateHandler can be used below either of
+// the CharacterSetHandler and the PrivateCreatorHandler
+
+impl<H: PrivateHandler + TextHandler + ?Sized> TextHandler for PrivateCreatorHandler<'_, H> {
+    fn text(&mut self, attribute: &Attribute, values: &[String]) -> HandlerResult {
+        self.handler.text(attribute, values)
+    }
+}
+
+impl<H: TextHandler + PrivateHandler + ?Sized> PrivateHandler for CharacterSetHandler<'_, H> {
+    fn private_attribute(
+        &mut self,
+        attribute: &Attribute,
+        private_tag: &PrivateTag,
+        entry: Option<&PrivateDictionaryEntry>,
+    ) -> HandlerResult {
+        self.handler
+            .private_attribute(attribute, private_tag, entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_private_creator, PrivateCreatorHandler, PrivateDictionaryEntry, PrivateHandler,
+        PrivateTag,
+    };
+    use crate::attribute::Attribute;
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::accumulate::{AccumulatingHandler, ValueHandler};
+    use crate::handler::character_set::{CharacterSetHandler, TextHandler};
+    use crate::handler::{Handler, HandlerResult, SequenceItem};
+    use crate::tag::Tag;
+    use crate::test::tests::read_data_set_bytes_from_file;
+    use crate::vr::VR;
+
+    static GEMS_DICTIONARY: [PrivateDictionaryEntry; 1] = [PrivateDictionaryEntry {
+        creator: "GEMS_IDEN_01",
+        group: 0x0009,
+        element: 0x01,
+        keyword: "FullFidelity",
+        name: "Full Fidelity",
+        vr: Some(VR::LO),
+    }];
+
+    // PrivateHandler that collects the private attributes and the decoded
+    // text values
+    #[derive(Default)]
+    struct TestPrivateHandler {
+        private_attributes: Vec<(Tag, PrivateTag, Option<&'static str>)>,
+        attribute_count: usize,
+        texts: Vec<(Tag, Vec<String>)>,
+    }
+
+    impl Handler for TestPrivateHandler {
+        fn attribute(
+            &mut self,
+            _attribute: &Attribute,
+            _position: usize,
+            _data_offset: usize,
+        ) -> HandlerResult {
+            self.attribute_count += 1;
+            HandlerResult::Continue
+        }
+    }
+
+    impl ValueHandler for TestPrivateHandler {}
+
+    impl TextHandler for TestPrivateHandler {
+        fn text(&mut self, attribute: &Attribute, values: &[String]) -> HandlerResult {
+            self.texts.push((attribute.tag, values.to_vec()));
+            HandlerResult::Continue
+        }
+    }
+
+    impl PrivateHandler for TestPrivateHandler {
+        fn private_attribute(
+            &mut self,
+            attribute: &Attribute,
+            private_tag: &PrivateTag,
+            entry: Option<&PrivateDictionaryEntry>,
+        ) -> HandlerResult {
+            self.private_attributes.push((
+                attribute.tag,
+                private_tag.clone(),
+                entry.map(|entry| entry.keyword),
+            ));
+            HandlerResult::Continue
+        }
+    }
+
+    fn make_attribute(tag: Tag, vr: VR, length: usize) -> Attribute {
+        Attribute {
+            tag,
+            vr: Some(vr),
+            length,
+            vr_inferred: false,
+        }
+    }
+
+    #[test]
+    fn is_private_creator_returns_true() {
+        assert!(is_private_creator(Tag::new(0x0009, 0x0010)));
+        assert!(is_private_creator(Tag::new(0x0029, 0x00FF)));
+        assert!(!is_private_creator(Tag::new(0x0009, 0x1010)));
+        assert!(!is_private_creator(Tag::new(0x0008, 0x0010)));
+        assert!(!is_private_creator(Tag::new(0x0003, 0x0010)));
+    }
+
+    #[test]
+    fn resolves_private_attributes_in_data_set() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut private_handler = TestPrivateHandler::default();
+        let mut private_creator_handler = PrivateCreatorHandler::new(&mut private_handler);
+        private_creator_handler.private_dictionary = Some(&GEMS_DICTIONARY);
+        let mut handler = AccumulatingHandler::new(&mut private_creator_handler, 1024);
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        match parser.parse(&mut handler, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert_eq!(private_handler.attribute_count, 257);
+        let (tag, private_tag, keyword) = &private_handler.private_attributes[0];
+        assert_eq!(*tag, Tag::new(0x0009, 0x1001));
+        assert_eq!(
+            *private_tag,
+            PrivateTag {
+                group: 0x0009,
+                creator: String::from("GEMS_IDEN_01"),
+                element: 0x01,
+            }
+        );
+        assert_eq!(*keyword, Some("FullFidelity"));
+        assert!(private_handler
+            .private_attributes
+            .iter()
+            .any(
+                |(_tag, private_tag, keyword)| private_tag.creator == "GEMS_ACQU_01"
+                    && private_tag.group == 0x0019
+                    && keyword.is_none()
+            ));
+    }
+
+    #[test]
+    fn sequence_items_have_own_private_creators() {
+        let mut private_handler = TestPrivateHandler::default();
+        let mut handler = PrivateCreatorHandler::new(&mut private_handler);
+        let creator = make_attribute(Tag::new(0x0029, 0x0010), VR::LO, 18);
+        let element = make_attribute(Tag::new(0x0029, 0x1010), VR::OB, 0);
+        let sequence = make_attribute(Tag::new(0x0008, 0x1115), VR::SQ, 0xFFFF_FFFF);
+
+        handler.value(&creator, b"SIEMENS CSA HEADER");
+        handler.start_sequence(&sequence);
+        handler.start_sequence_item(&sequence, &SequenceItem::default());
+        // not reserved in the item
+        handler.attribute(&element, 0, 8);
+        handler.value(&creator, b"OTHER ");
+        handler.attribute(&element, 0, 8);
+        handler.end_sequence_item(&sequence);
+        handler.end_sequence(&sequence);
+        handler.attribute(&element, 0, 8);
+
+        let creators: Vec<&str> = private_handler
+            .private_attributes
+            .iter()
+            .map(|(_tag, private_tag, _keyword)| private_tag.creator.as_str())
+            .collect();
+        assert_eq!(creators, vec!["OTHER", "SIEMENS CSA HEADER"]);
+        assert_eq!(private_handler.attribute_count, 3);
+    }
+
+    #[test]
+    fn stacks_with_character_set_handler() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut private_handler = TestPrivateHandler::default();
+        let mut character_set_handler = CharacterSetHandler::new(&mut private_handler);
+        let mut private_creator_handler = PrivateCreatorHandler::new(&mut character_set_handler);
+        let mut handler = AccumulatingHandler::new(&mut private_creator_handler, 1024);
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        match parser.parse(&mut handler, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert_eq!(private_handler.attribute_count, 257);
+        assert_eq!(
+            private_handler.private_attributes[0].1.creator,
+            "GEMS_IDEN_01"
+        );
+        assert!(private_handler.texts.contains(&(
+            Tag::new(0x0010, 0x0010),
+            vec![String::from("CompressedSamples^CT1")]
+        )));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::attribute::Attribute;
+use crate::handler::{Handler, HandlerResult, SequenceItem};
+use crate::tag::Tag;
+
+/// Implements the Handler trait that will cancel the parse at the first
+/// attribute in the root DataSet with a tag greater than or equal to the
+/// configured tag and forward/proxy all other functions to another Handler
+/// implementation.  Attributes inside sequence items never cancel the parse
+/// even if their tag is greater.  This is useful to parse just the attributes
+/// before a given tag (e.g. everything before Pixel Data).
+///
+/// The attribute that triggered the cancel is not forwarded.  When resuming
+/// the parse, use a Handler that does not cancel on it or set canceled to
+/// false and increase tag.
+pub struct TagCancelHandler<'t> {
+    /// true if the parse has been canceled, false otherwise
+    pub canceled: bool,
+    /// the Handler to forward/proxy function calls to
+    pub handler: &'t mut dyn Handler,
+    /// the tag to cancel the parse at
+    pub tag: Tag,
+    // current sequence depth, zero for the root DataSet
+    depth: usize,
+}
+
+impl<'t> TagCancelHandler<'t> {
+    /// Creates a new TagCancelHandler given a handler to proxy/forward
+    /// functions to and the tag to cancel the parse at
+    pub fn new(handler: &'t mut dyn Handler, tag: Tag) -> TagCancelHandler<'t> {
+        TagCancelHandler {
+            canceled: false,
+            handler,
+            tag,
+            depth: 0,
+        }
+    }
+}
+
+impl Handler for TagCancelHandler<'_> {
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        position: usize,
+        data_offset: usize,
+    ) -> HandlerResult {
+        if self.depth == 0 && attribute.tag >= self.tag {
+            self.canceled = true;
+            return HandlerResult::Cancel;
+        }
+        self.handler.attribute(attribute, position, data_offset)
+    }
+    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
+        self.handler.data(attribute, data, complete)
+    }
+    fn start_sequence(&mut self, attribute: &Attribute) {
+        self.depth += 1;
+        self.handler.start_sequence(attribute)
+    }
+    fn start_sequence_item(&mut self, attribute: &Attribute, item: &SequenceItem) {
+        self.handler.start_sequence_item(attribute, item)
+    }
+    fn end_sequence_item(&mut self, attribute: &Attribute) {
+        self.handler.end_sequence_item(attribute)
+    }
+    fn end_sequence(&mut self, attribute: &Attribute) {
+        self.depth -= 1;
+        self.handler.end_sequence(attribute)
+    }
+    fn basic_offset_table(
+        &mut self,
+        attribute: &Attribute,
+        data: &[u8],
+        complete: bool,
+    ) -> HandlerResult {
+        self.handler.basic_offset_table(attribute, data, complete)
+    }
+    fn pixel_data_fragment(
+        &mut self,
+        attribute: &Attribute,
+        fragment_number: usize,
+        data: &[u8],
+        complete: bool,
+    ) -> HandlerResult {
+        self.handler
+            .pixel_data_fragment(attribute, fragment_number, data, complete)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TagCancelHandler;
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::{ExplicitLittleEndian, ImplicitLittleEndian};
+    use crate::tag::Tag;
+    use crate::test::tests::read_data_set_bytes_from_file;
+    use crate::test::tests::TestHandler;
+    use crate::value_parser::ParseState;
+
+    #[test]
+    fn cancels_at_root_tag() {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut handler = TestHandler::default();
+        let mut cancel_handler = TagCancelHandler::new(&mut handler, Tag::new(0x0020, 0x0000));
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        match parser.parse(&mut cancel_handler, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.state, ParseState::Cancelled),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert!(cancel_handler.canceled);
+        assert!(handler.attributes.iter().all(|x| x.tag.group < 0x0020));
+    }
+
+    #[test]
+    fn nested_tags_do_not_cancel() {
+        // (0018,6011) Sequence of Ultrasound Regions has items with tags that
+        // are greater than the cancel tag
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/IM00001.implicit_little_endian.dcm");
+        let mut handler = TestHandler::default();
+        let tag = Tag::new(0x0018, 0x6012);
+        let mut cancel_handler = TagCancelHandler::new(&mut handler, tag);
+        let mut parser = DataSetParser::<ImplicitLittleEndian>::default();
+        match parser.parse(&mut cancel_handler, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.state, ParseState::Cancelled),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert!(handler.attributes.iter().any(|x| x.tag > tag));
+        assert_eq!(handler.depth, 0);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::attribute::Attribute;
+use crate::handler::path::{AttributePath, PathItem};
+use crate::handler::{Handler, HandlerResult, SequenceItem};
+use crate::tag_path::TagPath;
+
+/// Implements the Handler trait that forwards function calls to another
+/// Handler only for the attributes selected by one of its TagPaths.  The
+/// sequences and sequence items the selected attributes are nested in are
+/// forwarded too (without their other attributes) so the sequence events the
+/// Handler sees are always consistent.  If a sequence is selected, everything
+/// in it is forwarded.  Attributes that are not forwarded are skipped.
+pub struct TagPathFilterHandler<'t> {
+    /// the Handler to forward/proxy function calls to
+    pub handler: &'t mut dyn Handler,
+    /// the TagPaths that select the attributes to forward
+    pub tag_paths: Vec<TagPath>,
+    // the path of the current attribute
+    path: AttributePath,
+    // the depth of the selected sequence everything is forwarded for
+    selected_depth: Option<usize>,
+    // true for each sequence item in the path that was forwarded
+    forwarded_items: Vec<bool>,
+}
+
+impl<'t> TagPathFilterHandler<'t> {
+    /// Creates a new TagPathFilterHandler given a handler to proxy/forward
+    /// functions to and the TagPaths that select the attributes to forward
+    pub fn new(handler: &'t mut dyn Handler, tag_paths: Vec<TagPath>) -> TagPathFilterHandler<'t> {
+        TagPathFilterHandler {
+            handler,
+            tag_paths,
+            path: AttributePath::default(),
+            selected_depth: None,
+            forwarded_items: vec![],
+        }
+    }
+
+    // returns true if the current path is nested in a selected sequence
+    fn in_selected_sequence(&self) -> bool {
+        self.selected_depth
+            .is_some_and(|depth| self.path.depth() > depth)
+    }
+}
+
+impl Handler for TagPathFilterHandler<'_> {
+    fn attribute(
+        &mut self,
+        attribute: &Attribute,
+        position: usize,
+        data_offset: usize,
+    ) -> HandlerResult {
+        self.path.tag = attribute.tag;
+        if !self.in_selected_sequence() {
+            self.selected_depth = None;
+            if self.tag_paths.iter().any(|x| x.matches(&self.path)) {
+                self.selected_depth = Some(self.path.depth());
+            } else if !self.tag_paths.iter().any(|x| x.is_ancestor(&self.path)) {
+                return HandlerResult::Skip;
+            }
+        }
+        self.handler.attribute(attribute, position, data_offset)
+    }
+    fn start_sequence_item(&mut self, attribute: &Attribute, item: &SequenceItem) {
+        self.path.items.push(PathItem {
+            tag: attribute.tag,
+            item: *item,
+        });
+        let forwarded = self.in_selected_sequence()
+            || self
+                .tag_paths
+                .iter()
+                .any(|x| x.matches_items(&self.path.items));
+        self.forwarded_items.push(forwarded);
+        if forwarded {
+            self.handler.start_sequence_item(attribute, item)
+        }
+    }
+    fn end_sequence_item(&mut self, attribute: &Attribute) {
+        self.path.items.pop();
+        self.path.tag = attribute.tag;
+        if self.forwarded_items.pop().unwrap_or(false) {
+            self.handler.end_sequence_item(attribute)
+        }
+    }
+    forward_handler!(
+        data,
+        start_sequence,
+        end_sequence,
+        basic_offset_table,
+        pixel_data_fragment,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TagPathFilterHandler;
+    use crate::data_set::DataSetBuilder;
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::ImplicitLittleEndian;
+    use crate::tag::Tag;
+    use crate::tag_path::TagPath;
+    use crate::test::tests::read_data_set_bytes_from_file;
+    use crate::test::tests::TestHandler;
+
+    fn parse(tag_paths: &[&str]) -> (TestHandler, DataSetBuilder) {
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/IM00001.implicit_little_endian.dcm");
+        let tag_paths: Vec<TagPath> = tag_paths
+            .iter()
+            .map(|tag_path| TagPath::parse(tag_path).unwrap())
+            .collect();
+        let mut test_handler = TestHandler::default();
+        let mut handler = TagPathFilterHandler::new(&mut test_handler, tag_paths.clone());
+        let mut parser = DataSetParser::<ImplicitLittleEndian>::default();
+        match parser.parse(&mut handler, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        let mut builder = DataSetBuilder::default();
+        let mut handler = TagPathFilterHandler::new(&mut builder, tag_paths);
+        let mut parser = DataSetParser::<ImplicitLittleEndian>::default();
+        match parser.parse(&mut handler, &bytes, meta.end_position) {
+            Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        (test_handler, builder)
+    }
+
+    #[test]
+    fn forwards_nested_attributes() {
+        let (handler, builder) = parse(&["(0018,6011)[0].(0018,6012)", "(0008,0060)"]);
+        assert_eq!(handler.depth, 0);
+        let tags: Vec<Tag> = handler
+            .attributes
+            .iter()
+            .map(|attribute| attribute.tag)
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                Tag::new(0x0008, 0x0060),
+                Tag::new(0x0018, 0x6011),
+                Tag::new(0x0018, 0x6012)
+            ]
+        );
+        let data_set = builder.data_set();
+        let items = data_set
+            .get(Tag::new(0x0018, 0x6011))
+            .unwrap()
+            .items()
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].len(), 1);
+    }
+
+    #[test]
+    fn forwards_selected_sequences() {
+        let (handler, builder) = parse(&["(0018,6011)"]);
+        assert_eq!(handler.depth, 0);
+        let (meta, bytes) =
+            read_data_set_bytes_from_file("tests/fixtures/IM00001.implicit_little_endian.dcm");
+        let mut full = DataSetBuilder::default();
+        let mut parser = DataSetParser::<ImplicitLittleEndian>::default();
+        assert!(parser.parse(&mut full, &bytes, meta.end_position).is_ok());
+        assert_eq!(builder.data_set().len(), 1);
+        assert_eq!(
+            builder
+                .data_set()
+                .get(Tag::new(0x0018, 0x6011))
+                .unwrap()
+                .value,
+            full.data_set().get(Tag::new(0x0018, 0x6011)).unwrap().value
+        );
+    }
+}
@@ -1,12 +1,65 @@
 use crate::attribute::Attribute;
-use crate::handler::{Handler, HandlerResult};
+use crate::handler::{Handler, HandlerResult, SequenceItem};
 
 /// Implements the Handler trait that forward each function call to each
-/// handler in its list of handlers
+/// handler in its list of handlers.  Cancel is returned if any handler
+/// cancels and Skip is returned only if all handlers skip.  Handlers that
+/// skip an attribute are not forwarded anything else for it, if it is a
+/// sequence this includes its items and their attributes.
 #[derive(Default)]
 pub struct TeeHandler<'t> {
     /// the Handlers to forward function calls to
     pub handlers: Vec<&'t mut dyn Handler>,
+    // for each handler, the sequence depth of the attribute it skipped if
+    // it skipped the current attribute or a sequence being parsed
+    skipped: Vec<Option<usize>>,
+    // current sequence depth, zero for the root DataSet
+    depth: usize,
+}
+
+impl TeeHandler<'_> {
+    // forwards a function call to each handler that has not skipped and
+    // merges the results
+    fn forward<F>(&mut self, mut f: F) -> HandlerResult
+    where
+        F: FnMut(&mut dyn Handler) -> HandlerResult,
+    {
+        self.skipped.resize(self.handlers.len(), None);
+        let depth = self.depth;
+        let mut cancelled = false;
+        for (handler, skipped) in self.handlers.iter_mut().zip(self.skipped.iter_mut()) {
+            if skipped.is_some() {
+                continue;
+            }
+            match f(*handler) {
+                HandlerResult::Continue => {}
+                HandlerResult::Skip => *skipped = Some(depth),
+                HandlerResult::Cancel => cancelled = true,
+            }
+        }
+        if cancelled {
+            HandlerResult::Cancel
+        } else if !self.skipped.is_empty() && self.skipped.iter().all(|skipped| skipped.is_some()) {
+            HandlerResult::Skip
+        } else {
+            HandlerResult::Continue
+        }
+    }
+
+    // forwards a sequence function call to each handler that has not skipped
+    // a sequence at a depth less than max_depth
+    fn forward_sequence<F>(&mut self, max_depth: usize, mut f: F)
+    where
+        F: FnMut(&mut dyn Handler),
+    {
+        self.skipped.resize(self.handlers.len(), None);
+        for (handler, skipped) in self.handlers.iter_mut().zip(self.skipped.iter()) {
+            match skipped {
+                Some(depth) if *depth < max_depth => {}
+                _ => f(*handler),
+            }
+        }
+    }
 }
 
 impl Handler for TeeHandler<'_> {
@@ -16,43 +69,35 @@ impl Handler for TeeHandler<'_> {
         position: usize,
         data_offset: usize,
     ) -> HandlerResult {
-        if self
-            .handlers
-            .iter_mut()
-            .map(|handler| handler.attribute(attribute, position, data_offset))
-            .filter(|handler_result| handler_result == &HandlerResult::Cancel)
-            .count()
-            > 0
-        {
-            HandlerResult::Cancel
-        } else {
-            HandlerResult::Continue
+        // handlers that skipped a previous attribute get this one unless it
+        // is nested in a sequence they skipped
+        let depth = self.depth;
+        for skipped in self.skipped.iter_mut() {
+            if matches!(skipped, Some(skipped_depth) if *skipped_depth >= depth) {
+                *skipped = None;
+            }
         }
+        self.forward(|handler| handler.attribute(attribute, position, data_offset))
     }
-    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) {
-        self.handlers
-            .iter_mut()
-            .for_each(|handler| handler.data(attribute, data, complete))
+    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
+        self.forward(|handler| handler.data(attribute, data, complete))
     }
     fn start_sequence(&mut self, attribute: &Attribute) {
-        self.handlers
-            .iter_mut()
-            .for_each(|handler| handler.start_sequence(attribute))
+        // not forwarded to the handlers that skipped the sequence
+        self.forward_sequence(self.depth + 1, |handler| handler.start_sequence(attribute));
+        self.depth += 1;
     }
-    fn start_sequence_item(&mut self, attribute: &Attribute) {
-        self.handlers
-            .iter_mut()
-            .for_each(|handler| handler.start_sequence_item(attribute))
+    fn start_sequence_item(&mut self, attribute: &Attribute, item: &SequenceItem) {
+        self.forward_sequence(self.depth, |handler| {
+            handler.start_sequence_item(attribute, item)
+        });
     }
     fn end_sequence_item(&mut self, attribute: &Attribute) {
-        self.handlers
-            .iter_mut()
-            .for_each(|handler| handler.end_sequence_item(attribute))
+        self.forward_sequence(self.depth, |handler| handler.end_sequence_item(attribute));
     }
     fn end_sequence(&mut self, attribute: &Attribute) {
-        self.handlers
-            .iter_mut()
-            .for_each(|handler| handler.end_sequence(attribute))
+        self.forward_sequence(self.depth, |handler| handler.end_sequence(attribute));
+        self.depth = self.depth.saturating_sub(1);
     }
     fn basic_offset_table(
         &mut self,
@@ -60,18 +105,7 @@ impl Handler for TeeHandler<'_> {
         data: &[u8],
         complete: bool,
     ) -> HandlerResult {
-        if self
-            .handlers
-            .iter_mut()
-            .map(|handler| handler.basic_offset_table(attribute, data, complete))
-            .filter(|handler_result| handler_result == &HandlerResult::Cancel)
-            .count()
-            > 0
-        {
-            HandlerResult::Cancel
-        } else {
-            HandlerResult::Continue
-        }
+        self.forward(|handler| handler.basic_offset_table(attribute, data, complete))
     }
     fn pixel_data_fragment(
         &mut self,
@@ -80,17 +114,156 @@ impl Handler for TeeHandler<'_> {
         data: &[u8],
         complete: bool,
     ) -> HandlerResult {
-        if self
-            .handlers
-            .iter_mut()
-            .map(|handler| handler.pixel_data_fragment(attribute, fragment_number, data, complete))
-            .filter(|handler_result| handler_result == &HandlerResult::Cancel)
-            .count()
-            > 0
-        {
-            HandlerResult::Cancel
-        } else {
+        self.forward(|handler| {
+            handler.pixel_data_fragment(attribute, fragment_number, data, complete)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TeeHandler;
+    use crate::attribute::Attribute;
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::{Handler, HandlerResult, SequenceItem};
+    use crate::tag::Tag;
+    use crate::test::tests::TestHandler;
+
+    // Handler that returns a fixed result from attribute() and counts data()
+    struct ResultHandler {
+        result: HandlerResult,
+        data_count: usize,
+    }
+
+    impl ResultHandler {
+        fn new(result: HandlerResult) -> ResultHandler {
+            ResultHandler {
+                result,
+                data_count: 0,
+            }
+        }
+    }
+
+    impl Handler for ResultHandler {
+        fn attribute(
+            &mut self,
+            _attribute: &Attribute,
+            _position: usize,
+            _data_offset: usize,
+        ) -> HandlerResult {
+            self.result
+        }
+        fn data(&mut self, _attribute: &Attribute, _data: &[u8], _complete: bool) -> HandlerResult {
+            self.data_count += 1;
             HandlerResult::Continue
         }
     }
+
+    #[test]
+    fn cancel_if_any_handler_cancels() {
+        let mut continue_handler = ResultHandler::new(HandlerResult::Continue);
+        let mut cancel_handler = ResultHandler::new(HandlerResult::Cancel);
+        let mut tee_handler = TeeHandler::default();
+        tee_handler.handlers.push(&mut continue_handler);
+        tee_handler.handlers.push(&mut cancel_handler);
+        let result = tee_handler.attribute(&Attribute::default(), 0, 0);
+        assert_eq!(result, HandlerResult::Cancel);
+    }
+
+    #[test]
+    fn skip_if_all_handlers_skip() {
+        let mut skip_handler1 = ResultHandler::new(HandlerResult::Skip);
+        let mut skip_handler2 = ResultHandler::new(HandlerResult::Skip);
+        let mut tee_handler = TeeHandler::default();
+        tee_handler.handlers.push(&mut skip_handler1);
+        tee_handler.handlers.push(&mut skip_handler2);
+        let result = tee_handler.attribute(&Attribute::default(), 0, 0);
+        assert_eq!(result, HandlerResult::Skip);
+    }
+
+    #[test]
+    fn data_not_forwarded_to_skipping_handler() {
+        let mut continue_handler = ResultHandler::new(HandlerResult::Continue);
+        let mut skip_handler = ResultHandler::new(HandlerResult::Skip);
+        {
+            let mut tee_handler = TeeHandler::default();
+            tee_handler.handlers.push(&mut continue_handler);
+            tee_handler.handlers.push(&mut skip_handler);
+            let attribute = Attribute::default();
+            let result = tee_handler.attribute(&attribute, 0, 0);
+            assert_eq!(result, HandlerResult::Continue);
+            let result = tee_handler.data(&attribute, &[], true);
+            assert_eq!(result, HandlerResult::Continue);
+        }
+        assert_eq!(continue_handler.data_count, 1);
+        assert_eq!(skip_handler.data_count, 0);
+    }
+
+    // Handler that skips one sequence and records the functions invoked
+    #[derive(Default)]
+    struct SkipSequenceHandler {
+        tags: Vec<Tag>,
+        sequence_functions: usize,
+    }
+
+    impl Handler for SkipSequenceHandler {
+        fn attribute(
+            &mut self,
+            attribute: &Attribute,
+            _position: usize,
+            _data_offset: usize,
+        ) -> HandlerResult {
+            self.tags.push(attribute.tag);
+            if attribute.tag == Tag::new(0x0018, 0x6011) {
+                HandlerResult::Skip
+            } else {
+                HandlerResult::Continue
+            }
+        }
+        fn start_sequence(&mut self, _attribute: &Attribute) {
+            self.sequence_functions += 1;
+        }
+        fn start_sequence_item(&mut self, _attribute: &Attribute, _item: &SequenceItem) {
+            self.sequence_functions += 1;
+        }
+        fn end_sequence_item(&mut self, _attribute: &Attribute) {
+            self.sequence_functions += 1;
+        }
+        fn end_sequence(&mut self, _attribute: &Attribute) {
+            self.sequence_functions += 1;
+        }
+    }
+
+    #[test]
+    fn skipped_sequence_not_forwarded_to_skipping_handler() {
+        let mut bytes = vec![];
+        // (0018,6011) with one item containing (0018,6012)
+        bytes.extend_from_slice(&[0x18, 0x00, 0x11, 0x60, b'S', b'Q', 0, 0, 18, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 10, 0, 0, 0]);
+        bytes.extend_from_slice(&[0x18, 0x00, 0x12, 0x60, b'U', b'S', 2, 0, 1, 0]);
+        bytes.extend_from_slice(&[0x20, 0x00, 0x0D, 0x00, b'U', b'I', 2, 0, b'1', 0]);
+
+        let mut all_handler = TestHandler::default();
+        let mut skip_handler = SkipSequenceHandler::default();
+        {
+            let mut tee_handler = TeeHandler::default();
+            tee_handler.handlers.push(&mut all_handler);
+            tee_handler.handlers.push(&mut skip_handler);
+            let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+            match parser.parse(&mut tee_handler, &bytes, 0) {
+                Ok(result) => assert_eq!(result.bytes_consumed, bytes.len()),
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+        assert_eq!(all_handler.attributes.len(), 3);
+        assert_eq!(all_handler.depth, 0);
+        // the skipped sequence and its attributes are not forwarded but the
+        // attribute after it is
+        assert_eq!(skip_handler.sequence_functions, 0);
+        assert_eq!(
+            skip_handler.tags,
+            vec![Tag::new(0x0018, 0x6011), Tag::new(0x0020, 0x000D)]
+        );
+    }
 }
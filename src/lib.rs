@@ -1,15 +1,29 @@
 //#![no_std]
 
+// lets #[derive(DicomExtract)] refer to this crate as ::dicomparser in it
+extern crate self as dicomparser;
+
 pub mod attribute;
 pub mod attribute_parser;
+pub mod data_set;
 pub mod data_set_parser;
+pub mod data_set_ref;
+pub mod deflate;
+#[cfg(feature = "dictionary")]
+pub mod dictionary;
 pub mod encoding;
+pub mod extract;
 pub mod handler;
 pub mod meta_information;
 pub mod p10;
 pub mod p10_parser;
 pub mod prefix;
 pub mod tag;
+pub mod tag_path;
 pub mod test;
+pub mod value;
 pub mod value_parser;
 pub mod vr;
+
+#[cfg(feature = "dictionary")]
+pub use dictionary::tags;
@@ -1,177 +1,653 @@
 use crate::attribute::Attribute;
-use crate::data_set_parser::parse_full;
-use crate::encoding::ExplicitLittleEndian;
+use crate::data_set::{Element, Value};
+use crate::data_set_parser::DataSetParser;
+use crate::encoding::{DynEncoding, ExplicitLittleEndian};
+use crate::extract::{DicomExtract, FromValue};
 use crate::handler::cancel::CancelHandler;
 use crate::handler::tee::TeeHandler;
 use crate::handler::Handler;
 use crate::handler::HandlerResult;
 use crate::prefix;
 use crate::tag::Tag;
+use crate::value::ValueError;
 use crate::value_parser::ParseError;
-use std::str;
+use crate::value_parser::ParseErrorKind;
+use crate::value_parser::ParseResult;
+use crate::value_parser::ParseState;
 
-/*
-macro_rules! map_string {
-    ($group:expr, $element:expr, $property:expr) => {
-        if attribute.tag.group == group && attribute.tag.element == element {
-            property = str::from_utf8(self.data_buffer).unwrap()
-        }
-    };
-}*/
+// the tag of the File Meta Information Group Length attribute
+const GROUP_LENGTH: Tag = Tag::new(0x0002, 0x0000);
+// the size of the group length attribute (tag, VR, length and UL value)
+const GROUP_LENGTH_SIZE: usize = 12;
 
-/// MetaInformation includes the required attributes from the DICOM P10 Header
-#[derive(Debug, Default)]
+/// MetaInformation includes the attributes from the DICOM P10 Header
+/// (group 0002).  The required attributes are always present in a
+/// MetaInformation returned by the parsers.
+#[derive(Debug, Default, dicomparser_derive::DicomExtract)]
 pub struct MetaInformation {
+    /// The File Meta Information Group Length, the number of bytes in the
+    /// P10 Header following the group length attribute (0 if missing)
+    pub group_length: u32,
+    /// The File Meta Information Version (usually 00H 01H)
+    #[dicom(tag = "(0002,0001)")]
+    pub file_meta_information_version: Vec<u8>,
     /// The SOP Class UID
+    #[dicom(tag = "(0002,0002)")]
     pub media_storage_sop_class_uid: String,
     /// The SOP Instance UID
+    #[dicom(tag = "(0002,0003)")]
     pub media_storage_sop_instance_uid: String,
     /// The Transfer Syntax UID
+    #[dicom(tag = "(0002,0010)")]
     pub transfer_syntax_uid: String,
     /// The Implementation Class UID
+    #[dicom(tag = "(0002,0012)")]
     pub implementation_class_uid: String,
+    /// The Implementation Version Name
+    #[dicom(tag = "(0002,0013)")]
+    pub implementation_version_name: Option<String>,
+    /// The Source Application Entity Title
+    #[dicom(tag = "(0002,0016)")]
+    pub source_application_entity_title: Option<String>,
+    /// The Sending Application Entity Title
+    #[dicom(tag = "(0002,0017)")]
+    pub sending_application_entity_title: Option<String>,
+    /// The Receiving Application Entity Title
+    #[dicom(tag = "(0002,0018)")]
+    pub receiving_application_entity_title: Option<String>,
+    /// The Private Information Creator UID
+    #[dicom(tag = "(0002,0100)")]
+    pub private_information_creator_uid: Option<String>,
+    /// The Private Information
+    #[dicom(tag = "(0002,0102)")]
+    pub private_information: Option<Vec<u8>>,
+    /// The other attributes in the P10 Header (e.g. Source Presentation
+    /// Address or the RTV attributes)
+    pub other_elements: Vec<Element>,
     /// The offset from the beginning of the file that the DICOM P10 header
     /// ends at
     pub end_position: usize,
 }
 
-struct MetaInformationBuilder<'a> {
-    pub meta_information: &'a mut MetaInformation,
+impl MetaInformation {
+    /// Returns true if the DataSet following the P10 Header is encoded with
+    /// the Deflated Explicit VR Little Endian transfer syntax.  In this case
+    /// positions reported for the DataSet are offsets in the inflated stream
+    /// rather than in the file
+    pub fn is_deflated(&self) -> bool {
+        DynEncoding::from_transfer_syntax_uid(&self.transfer_syntax_uid)
+            == DynEncoding::DeflatedExplicitLittleEndian
+    }
+
+    // Returns an error if a required attribute is missing or the transfer
+    // syntax UID cannot be used to select the encoding of the DataSet
+    pub(crate) fn validate(&self) -> Result<(), ParseError> {
+        let required = [
+            (
+                Tag::new(0x0002, 0x0001),
+                self.file_meta_information_version.is_empty(),
+            ),
+            (
+                Tag::new(0x0002, 0x0002),
+                self.media_storage_sop_class_uid.is_empty(),
+            ),
+            (
+                Tag::new(0x0002, 0x0003),
+                self.media_storage_sop_instance_uid.is_empty(),
+            ),
+            (
+                Tag::new(0x0002, 0x0010),
+                self.transfer_syntax_uid.is_empty(),
+            ),
+            (
+                Tag::new(0x0002, 0x0012),
+                self.implementation_class_uid.is_empty(),
+            ),
+        ];
+        if let Some((tag, _)) = required.iter().find(|(_, missing)| *missing) {
+            return Err(ParseError::with_tag(
+                ParseErrorKind::MissingMetaInformation,
+                *tag,
+                self.end_position,
+            ));
+        }
+        let uid = &self.transfer_syntax_uid;
+        if DynEncoding::from_known_transfer_syntax_uid(uid).is_none() {
+            return Err(ParseError::with_tag(
+                ParseErrorKind::UnsupportedTransferSyntax(uid.clone()),
+                Tag::new(0x0002, 0x0010),
+                self.end_position,
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Handler that builds a MetaInformation from the attributes in the
+/// DICOM P10 Header.  Cancels the parse with an error if a value cannot be
+/// decoded.
+#[derive(Default)]
+pub(crate) struct MetaInformationBuilder {
+    pub meta_information: MetaInformation,
+    /// The value of the group length attribute once it has been parsed
+    pub group_length: Option<u32>,
+    /// true if the header does not start with a group length attribute
+    /// with a UL value
+    pub missing_group_length: bool,
+    /// The first error encountered while building the MetaInformation
+    pub error: Option<ParseError>,
 
     // buffer to accumulate data for an attribute
     data_buffer: Vec<u8>,
+    // position of the current attribute
+    position: usize,
+    // true until the value of the current attribute is complete
+    pending: bool,
+}
+
+impl MetaInformationBuilder {
+    // records the error and returns Cancel
+    fn cancel(&mut self, kind: ParseErrorKind, tag: Tag) -> HandlerResult {
+        self.error
+            .get_or_insert(ParseError::with_tag(kind, tag, self.position));
+        HandlerResult::Cancel
+    }
+
+    // sets the MetaInformation field for the attribute from its value
+    fn set_value(&mut self, attribute: &Attribute) -> Result<(), ValueError> {
+        let meta = &mut self.meta_information;
+        let bytes = &self.data_buffer;
+        let tag = attribute.tag;
+        if tag == GROUP_LENGTH {
+            if !self.missing_group_length {
+                self.group_length =
+                    Some(u32::from_value::<ExplicitLittleEndian>(attribute, bytes)?);
+            }
+        } else if MetaInformation::is_mapped(&[], tag) {
+            meta.set_value::<ExplicitLittleEndian>(&[], attribute, bytes)?;
+        } else {
+            meta.other_elements.push(Element {
+                attribute: *attribute,
+                position: self.position,
+                value: Value::Bytes(bytes.clone()),
+            });
+        }
+        Ok(())
+    }
 }
 
-impl Handler for MetaInformationBuilder<'_> {
+impl Handler for MetaInformationBuilder {
     fn attribute(
         &mut self,
-        _attribute: &Attribute,
-        _position: usize,
+        attribute: &Attribute,
+        position: usize,
         _data_offset: usize,
     ) -> HandlerResult {
         self.data_buffer.clear();
+        self.position = position;
+        self.pending = true;
+        if self.group_length.is_none() && (attribute.tag != GROUP_LENGTH || attribute.length != 4) {
+            self.missing_group_length = true;
+        }
         HandlerResult::Continue
     }
-    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) {
-        if attribute.length == 0 {
-            return;
-        }
+    fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
         self.data_buffer.extend_from_slice(data);
+        self.pending = !complete;
+        if complete && self.set_value(attribute).is_err() {
+            return self.cancel(ParseErrorKind::InvalidMetaInformation, attribute.tag);
+        }
+        HandlerResult::Continue
+    }
+}
+
+/// Streaming parser for the DICOM P10 Header following the prefix.  The
+/// group length attribute is parsed first and gives the end of the header
+/// so no bytes past the header are consumed.  If the group length is missing
+/// or does not end on the last group 2 attribute, the header ends at the
+/// first attribute not in group 2 instead.
+#[derive(Default)]
+pub(crate) struct MetaInformationParser {
+    parser: DataSetParser<ExplicitLittleEndian>,
+    builder: MetaInformationBuilder,
+    // number of bytes of the header consumed
+    bytes_consumed: usize,
+    // true if the end of the header is the first attribute not in group 2
+    ignore_group_length: bool,
+    // true if the bytes parsed so far end on an attribute boundary
+    at_attribute_boundary: bool,
+}
 
-        if complete {
-            let bytes = if self.data_buffer[attribute.length - 1] != 0 {
-                &self.data_buffer
-            } else {
-                &self.data_buffer[0..(attribute.length - 1)]
+impl MetaInformationParser {
+    /// Parses the next bytes of the header.  Returns Completed once the end
+    /// of the header is reached, Incomplete if more bytes are needed and
+    /// Cancelled if the Handler cancelled (the parse can be resumed)
+    ///
+    /// # Arguments
+    ///
+    /// * `handler`  - The Handler to invoke for the header attributes
+    /// * `bytes`    - The next bytes of the header
+    /// * `position` - The position of the bytes from the beginning of the
+    ///   stream
+    pub fn parse(
+        &mut self,
+        handler: &mut dyn Handler,
+        bytes: &[u8],
+        position: usize,
+    ) -> Result<ParseResult, ParseError> {
+        let mut bytes_consumed = 0;
+        loop {
+            // limit the bytes to the group length attribute until its value
+            // is known and to the end of the header afterwards
+            let end = self.end(position + bytes_consumed)?;
+            let remaining_bytes = &bytes[bytes_consumed..];
+            let length = match end {
+                Some(end) => std::cmp::min(remaining_bytes.len(), end - self.bytes_consumed),
+                None => remaining_bytes.len(),
             };
 
-            if attribute.tag == Tag::new(0x0002, 0x02) {
-                self.meta_information.media_storage_sop_class_uid =
-                    String::from(str::from_utf8(&bytes).unwrap());
-            } else if attribute.tag == Tag::new(0x0002, 0x0003) {
-                self.meta_information.media_storage_sop_instance_uid =
-                    String::from(str::from_utf8(&bytes).unwrap());
-            } else if attribute.tag == Tag::new(0x0002, 0x0010) {
-                self.meta_information.transfer_syntax_uid =
-                    String::from(str::from_utf8(&bytes).unwrap());
-            } else if attribute.tag == Tag::new(0x0002, 0x0012) {
-                self.meta_information.implementation_class_uid =
-                    String::from(str::from_utf8(&bytes).unwrap());
+            // the first attribute not in group 2 is the end of the header, it
+            // is not passed to the Handler
+            let mut tee_handler = TeeHandler::default();
+            tee_handler.handlers.push(handler);
+            tee_handler.handlers.push(&mut self.builder);
+            let mut cancel_handler =
+                CancelHandler::new(&mut tee_handler, |attribute: &Attribute| {
+                    attribute.tag.group != 2
+                });
+            let parse_result = self.parser.parse(
+                &mut cancel_handler,
+                &remaining_bytes[..length],
+                position + bytes_consumed,
+            )?;
+            let end_of_header = cancel_handler.canceled;
+            self.bytes_consumed += parse_result.bytes_consumed;
+            bytes_consumed += parse_result.bytes_consumed;
+            self.at_attribute_boundary = parse_result.state == ParseState::Completed;
+
+            if let Some(error) = self.builder.error.take() {
+                return Err(error);
+            }
+            if end_of_header {
+                return Ok(ParseResult::completed(bytes_consumed));
+            }
+            if parse_result.state == ParseState::Cancelled {
+                return Ok(ParseResult::cancelled(bytes_consumed));
+            }
+            let end = match end {
+                Some(end) => end,
+                None => return Ok(ParseResult::incomplete(bytes_consumed)),
+            };
+            if self.bytes_consumed < end && length == remaining_bytes.len() {
+                return Ok(ParseResult::incomplete(bytes_consumed));
+            }
+            if self.bytes_consumed < end || self.builder.pending {
+                // an attribute extends past the end given by the group length
+                self.ignore_group_length = true;
+                continue;
             }
+            match self.builder.group_length {
+                // continue with the rest of the header once the group length
+                // is known
+                Some(_) if self.end(position + bytes_consumed)? != Some(end) => continue,
+                // the group length is wrong if the next attribute is still in
+                // group 2.  If the next bytes are not available yet, the
+                // group length is assumed to be right
+                Some(_) => match bytes.get(bytes_consumed..bytes_consumed + 2) {
+                    Some([2, 0]) => self.ignore_group_length = true,
+                    _ => return Ok(ParseResult::completed(bytes_consumed)),
+                },
+                None => self.ignore_group_length = true,
+            }
+        }
+    }
+
+    // Returns the position of the end of the bytes to parse relative to the
+    // start of the header or None if the header ends at the first attribute
+    // not in group 2
+    fn end(&self, position: usize) -> Result<Option<usize>, ParseError> {
+        if self.ignore_group_length || self.builder.missing_group_length {
+            return Ok(None);
         }
+        match self.builder.group_length {
+            Some(group_length) => GROUP_LENGTH_SIZE
+                .checked_add(group_length as usize)
+                .map(Some)
+                .ok_or_else(|| {
+                    ParseError::with_tag(
+                        ParseErrorKind::InvalidMetaInformation,
+                        GROUP_LENGTH,
+                        position,
+                    )
+                }),
+            None => Ok(Some(GROUP_LENGTH_SIZE)),
+        }
+    }
+
+    /// Returns true if the header ends at the end of the bytes parsed so
+    /// far when no more bytes are available.  Only possible when the header
+    /// ends at the first attribute not in group 2
+    pub fn ends_with_bytes(&self) -> bool {
+        self.end(0).ok() == Some(None) && self.at_attribute_boundary && !self.builder.pending
+    }
+
+    /// Returns the validated MetaInformation once the parse is Completed
+    ///
+    /// # Arguments
+    ///
+    /// * `end_position` - The position the header ends at from the beginning
+    ///   of the stream
+    pub fn meta_information(&mut self, end_position: usize) -> Result<MetaInformation, ParseError> {
+        let mut meta_information = std::mem::take(&mut self.builder.meta_information);
+        meta_information.group_length = self.builder.group_length.unwrap_or(0);
+        meta_information.end_position = end_position;
+        meta_information.validate()?;
+        Ok(meta_information)
     }
 }
 
-/// Parses the DICOM P10 Header and returns it as a MetaInformation instance
+/// Parses the DICOM P10 Header and returns it as a MetaInformation instance.
+/// The end of the header is given by the File Meta Information Group Length
+/// attribute or, if it is missing or wrong, the first attribute not in group
+/// 2.  An error is returned if a required attribute is missing.
 ///
 /// # Arguments
 ///
 /// * `bytes` - bytes containg the entire DICOM P10 Header including the
-///             preamble
-pub fn parse<'a, T: Handler>(
-    handler: &'a mut T,
-    bytes: &[u8],
-) -> Result<MetaInformation, ParseError> {
+///   preamble
+pub fn parse<T: Handler>(handler: &mut T, bytes: &[u8]) -> Result<MetaInformation, ParseError> {
     // validate that we have a P10 Header Prefix
     prefix::validate(bytes)?;
 
-    // Create a MetaInformationBuilder
-    let mut meta_information = MetaInformation::default();
-    let mut builder = MetaInformationBuilder {
-        meta_information: &mut meta_information,
-        data_buffer: vec![],
-    };
-
-    // Create a TeeHandler that forwards Handler callbacks to
-    // the user supplied Handler and our MetaInformationBuilder
-    let mut tee_handler = TeeHandler::default();
-    tee_handler.handlers.push(handler);
-    tee_handler.handlers.push(&mut builder);
-
-    // create a CancelHandler that will cancel the parse when we see an attribute that has a
-    // tag not in group 2 (All meta information tags are group 2)
-    let mut handler = CancelHandler::new(&mut tee_handler, |x: &Attribute| x.tag.group != 2);
-
-    // attempt to fully parse the p10 header.  _cancelled will typically be true.
-    // If _cancelled is false, we may  not have a complete p10 header - we can't
-    // distinguish between these two until we validate the contents (which we do
-    // afterwards)
-    let (bytes_consumed, _cancelled) =
-        parse_full::<ExplicitLittleEndian>(&mut handler, &bytes[132..], 132)?;
-
-    // calculate the end position of the p10 header by adding the prefix length
-    // to the number of bytes consumed parsing the meta information
-    meta_information.end_position = 132 + bytes_consumed;
-
-    Ok(meta_information)
+    // parse the meta information up to the end given by the group length.
+    // If the Handler cancels, the attributes parsed so far are validated
+    let mut parser = MetaInformationParser::default();
+    let parse_result = parser.parse(handler, &bytes[132..], 132)?;
+    let end_position = 132 + parse_result.bytes_consumed;
+    if parse_result.state == ParseState::Incomplete && !parser.ends_with_bytes() {
+        return Err(ParseError::new(ParseErrorKind::UnexpectedEOF, end_position));
+    }
+    parser.meta_information(end_position)
 }
 
 #[cfg(test)]
 pub mod tests {
     use super::parse;
-    use crate::test::tests::TestHandler;
+    use crate::tag::Tag;
+    use crate::test::tests::{read_file, TestHandler};
+    use crate::value_parser::{ParseError, ParseErrorKind};
+    use crate::vr::VR;
 
     fn make_preamble_and_prefix() -> Vec<u8> {
-        let mut bytes = vec![];
-        bytes.resize(132, 0);
-        bytes[128] = 'D' as u8;
-        bytes[129] = 'I' as u8;
-        bytes[130] = 'C' as u8;
-        bytes[131] = 'M' as u8;
+        let mut bytes = vec![0; 132];
+        bytes[128] = b'D';
+        bytes[129] = b'I';
+        bytes[130] = b'C';
+        bytes[131] = b'M';
 
         bytes
     }
 
     pub fn make_p10_header() -> Vec<u8> {
+        make_p10_header_with_transfer_syntax("1.2.840.10008.1.2.1")
+    }
+
+    pub fn make_p10_header_with_transfer_syntax(transfer_syntax_uid: &str) -> Vec<u8> {
+        let mut transfer_syntax_uid = transfer_syntax_uid.as_bytes().to_vec();
+        transfer_syntax_uid.resize((transfer_syntax_uid.len() + 1) & !1, 0);
+
         let mut bytes = make_preamble_and_prefix();
-        bytes.extend_from_slice(&vec![0x02, 0x00, 0x00, 0x00, b'U', b'L', 4, 0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0x02, 0x00, 0x00, 0x00, b'U', b'L', 4, 0, 0, 0, 0, 0]);
         bytes.extend_from_slice(&vec![
             0x02, 0x00, 0x01, 0x00, b'O', b'B', 0, 0, 2, 0, 0, 0, 0, 1,
         ]);
-        bytes.extend_from_slice(&vec![0x02, 0x00, 0x02, 0x00, b'U', b'I', 2, 0, b'1', 0]);
-        bytes.extend_from_slice(&vec![0x02, 0x00, 0x03, 0x00, b'U', b'I', 2, 0, b'2', 0]);
-        bytes.extend_from_slice(&vec![0x02, 0x00, 0x10, 0x00, b'U', b'I', 2, 0, b'3', 0]);
-        bytes.extend_from_slice(&vec![0x02, 0x00, 0x12, 0x00, b'U', b'I', 2, 0, b'4', 0]);
-
-        let length = bytes.len() as u32;
-        bytes[140] = (length & 0xff) as u8;
-        bytes[141] = (length >> 8 & 0xff) as u8;
+        bytes.extend_from_slice(&[0x02, 0x00, 0x02, 0x00, b'U', b'I', 2, 0, b'1', 0]);
+        bytes.extend_from_slice(&[0x02, 0x00, 0x03, 0x00, b'U', b'I', 2, 0, b'2', 0]);
+        bytes.extend_from_slice(&[
+            0x02,
+            0x00,
+            0x10,
+            0x00,
+            b'U',
+            b'I',
+            transfer_syntax_uid.len() as u8,
+            0,
+        ]);
+        bytes.extend_from_slice(&transfer_syntax_uid);
+        bytes.extend_from_slice(&[0x02, 0x00, 0x12, 0x00, b'U', b'I', 2, 0, b'4', 0]);
+        set_group_length(&mut bytes);
 
         bytes
     }
 
+    // sets the group length to the number of bytes after the group length
+    // attribute
+    fn set_group_length(bytes: &mut [u8]) {
+        let length = (bytes.len() - 144) as u32;
+        bytes[140..144].copy_from_slice(&length.to_le_bytes());
+    }
+
+    fn parse_header_error(bytes: &[u8]) -> ParseError {
+        let mut handler = TestHandler::default();
+        match parse(&mut handler, bytes) {
+            Ok(_meta) => panic!("Let's play Global Thermonuclear War"),
+            Err(parse_error) => parse_error,
+        }
+    }
+
     #[test]
     fn valid_meta_information() {
-        let bytes = make_p10_header();
+        let mut bytes = make_p10_header();
+        let header_length = bytes.len();
+        // the DataSet is not parsed
+        bytes.extend_from_slice(&[0x08, 0x00, 0x05, 0x00, b'C', b'S']);
+        let mut handler = TestHandler::default();
+        match parse(&mut handler, &bytes) {
+            Ok(meta) => {
+                assert_eq!(meta.group_length as usize, header_length - 144);
+                assert_eq!(meta.file_meta_information_version, vec![0, 1]);
+                assert_eq!(meta.media_storage_sop_class_uid, "1");
+                assert_eq!(meta.media_storage_sop_instance_uid, "2");
+                assert_eq!(meta.transfer_syntax_uid, "1.2.840.10008.1.2.1");
+                assert_eq!(meta.implementation_class_uid, "4");
+                assert_eq!(meta.implementation_version_name, None);
+                assert!(meta.other_elements.is_empty());
+                assert_eq!(meta.end_position, header_length);
+                assert_eq!(handler.attributes.len(), 6);
+            }
+            Err(_parse_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+    }
+
+    #[test]
+    fn optional_meta_information() {
+        let mut bytes = make_p10_header();
+        bytes.extend_from_slice(&[
+            0x02, 0x00, 0x13, 0x00, b'S', b'H', 4, 0, b'V', b'1', b' ', b' ',
+        ]);
+        bytes.extend_from_slice(&[
+            0x02, 0x00, 0x16, 0x00, b'A', b'E', 4, 0, b'S', b'R', b'C', b' ',
+        ]);
+        bytes.extend_from_slice(&[0x02, 0x00, 0x17, 0x00, b'A', b'E', 2, 0, b'S', b'E']);
+        bytes.extend_from_slice(&[0x02, 0x00, 0x18, 0x00, b'A', b'E', 2, 0, b'R', b'E']);
+        bytes.extend_from_slice(&vec![
+            0x02, 0x00, 0x26, 0x00, b'U', b'R', 0, 0, 2, 0, 0, 0, b'a', b'b',
+        ]);
+        bytes.extend_from_slice(&[0x02, 0x00, 0x00, 0x01, b'U', b'I', 2, 0, b'5', 0]);
+        bytes.extend_from_slice(&vec![
+            0x02, 0x00, 0x02, 0x01, b'O', b'B', 0, 0, 2, 0, 0, 0, 0xAB, 0xCD,
+        ]);
+        set_group_length(&mut bytes);
+        let mut handler = TestHandler::default();
+        match parse(&mut handler, &bytes) {
+            Ok(meta) => {
+                assert_eq!(meta.implementation_version_name, Some(String::from("V1")));
+                assert_eq!(
+                    meta.source_application_entity_title,
+                    Some(String::from("SRC"))
+                );
+                assert_eq!(
+                    meta.sending_application_entity_title,
+                    Some(String::from("SE"))
+                );
+                assert_eq!(
+                    meta.receiving_application_entity_title,
+                    Some(String::from("RE"))
+                );
+                assert_eq!(
+                    meta.private_information_creator_uid,
+                    Some(String::from("5"))
+                );
+                assert_eq!(meta.private_information, Some(vec![0xAB, 0xCD]));
+                assert_eq!(meta.other_elements.len(), 1);
+                assert_eq!(meta.other_elements[0].tag(), Tag::new(0x0002, 0x0026));
+                assert_eq!(meta.other_elements[0].attribute.vr, Some(VR::UR));
+                assert_eq!(meta.other_elements[0].bytes(), Some(&b"ab"[..]));
+                assert_eq!(meta.end_position, bytes.len());
+            }
+            Err(_parse_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+    }
+
+    #[test]
+    fn file_meta_information() {
+        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut handler = TestHandler::default();
+        match parse(&mut handler, &bytes) {
+            Ok(meta) => {
+                assert_eq!(meta.end_position, 144 + meta.group_length as usize);
+                assert_eq!(meta.file_meta_information_version, vec![0, 1]);
+                assert!(meta.implementation_version_name.is_some());
+                assert!(meta.source_application_entity_title.is_some());
+            }
+            Err(_parse_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+    }
+
+    #[test]
+    fn missing_group_length_ends_at_first_attribute_not_in_group_2() {
+        let mut bytes = make_p10_header();
+        // remove the group length attribute
+        bytes.drain(132..144);
+        let header_length = bytes.len();
         let mut handler = TestHandler::default();
-        //handler.print = true;
         match parse(&mut handler, &bytes) {
-            Ok(_meta) => {
-                //println!("{:?}", _meta);
-                //assert_eq!(meta.data_set.attributes.len(), 6);
+            Ok(meta) => {
+                assert_eq!(meta.group_length, 0);
+                assert_eq!(meta.transfer_syntax_uid, "1.2.840.10008.1.2.1");
+                assert_eq!(meta.implementation_class_uid, "4");
+                assert_eq!(meta.end_position, header_length);
+                assert_eq!(handler.attributes.len(), 5);
             }
-            Err(_parse_error) => {
-                assert!(false);
+            Err(_parse_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+
+        // the DataSet is not parsed
+        bytes.extend_from_slice(&[0x08, 0x00, 0x05, 0x00, b'C', b'S', 2, 0, b'I', b'S']);
+        let mut handler = TestHandler::default();
+        match parse(&mut handler, &bytes) {
+            Ok(meta) => {
+                assert_eq!(meta.end_position, header_length);
+                assert_eq!(handler.attributes.len(), 5);
+            }
+            Err(_parse_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+    }
+
+    #[test]
+    fn missing_required_attribute_returns_missing_meta_information() {
+        let mut bytes = make_p10_header();
+        // remove the Implementation Class UID
+        bytes.truncate(bytes.len() - 10);
+        set_group_length(&mut bytes);
+        let parse_error = parse_header_error(&bytes);
+        assert_eq!(parse_error.kind, ParseErrorKind::MissingMetaInformation);
+        assert_eq!(parse_error.tag, Some(Tag::new(0x0002, 0x0012)));
+        assert_eq!(parse_error.position, bytes.len());
+    }
+
+    // parses the header followed by a DataSet attribute and checks the
+    // header ends at the DataSet attribute
+    fn assert_header_ends_at_data_set(mut bytes: Vec<u8>) {
+        let header_length = bytes.len();
+        bytes.extend_from_slice(&[0x08, 0x00, 0x05, 0x00, b'C', b'S', 2, 0, b'I', b'S']);
+        let mut handler = TestHandler::default();
+        match parse(&mut handler, &bytes) {
+            Ok(meta) => {
+                assert_eq!(meta.implementation_class_uid, "4");
+                assert_eq!(meta.end_position, header_length);
+                assert_eq!(handler.attributes.len(), 6);
+            }
+            Err(_parse_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+    }
+
+    #[test]
+    fn invalid_group_length_ends_at_first_attribute_not_in_group_2() {
+        // group length ends in the middle of the last attribute
+        let mut bytes = make_p10_header();
+        bytes[140] -= 2;
+        assert_header_ends_at_data_set(bytes);
+
+        // group length ends before the last attribute
+        let mut bytes = make_p10_header();
+        bytes[140] -= 10;
+        assert_header_ends_at_data_set(bytes);
+
+        // group length past the header includes DataSet attributes
+        let mut bytes = make_p10_header();
+        bytes[140] += 10;
+        assert_header_ends_at_data_set(bytes);
+
+        // group length past the end of the bytes
+        let mut bytes = make_p10_header();
+        bytes[140] += 10;
+        let parse_error = parse_header_error(&bytes);
+        assert_eq!(parse_error.kind, ParseErrorKind::UnexpectedEOF);
+    }
+
+    #[test]
+    fn invalid_utf8_returns_invalid_meta_information() {
+        let mut bytes = make_p10_header();
+        // replace SOP Class UID value "1" with an invalid UTF-8 byte
+        bytes[166] = 0xFF;
+        let mut handler = TestHandler::default();
+        match parse(&mut handler, &bytes) {
+            Ok(_meta) => panic!("Let's play Global Thermonuclear War"),
+            Err(parse_error) => {
+                assert_eq!(parse_error.kind, ParseErrorKind::InvalidMetaInformation);
+                assert_eq!(parse_error.tag, Some(Tag::new(0x0002, 0x0002)));
+                assert_eq!(parse_error.position, 158);
+            }
+        };
+    }
+
+    #[test]
+    fn invalid_transfer_syntax_returns_unsupported_transfer_syntax() {
+        let bytes = make_p10_header_with_transfer_syntax("ab");
+        let mut handler = TestHandler::default();
+        match parse(&mut handler, &bytes) {
+            Ok(_meta) => panic!("Let's play Global Thermonuclear War"),
+            Err(parse_error) => {
+                assert_eq!(
+                    parse_error.kind,
+                    ParseErrorKind::UnsupportedTransferSyntax(String::from("ab"))
+                );
+            }
+        };
+    }
+
+    #[test]
+    fn unknown_transfer_syntax_returns_unsupported_transfer_syntax() {
+        let bytes = make_p10_header_with_transfer_syntax("1.2.3.4.5");
+        let mut handler = TestHandler::default();
+        match parse(&mut handler, &bytes) {
+            Ok(_meta) => panic!("Let's play Global Thermonuclear War"),
+            Err(parse_error) => {
+                assert_eq!(
+                    parse_error.kind,
+                    ParseErrorKind::UnsupportedTransferSyntax(String::from("1.2.3.4.5"))
+                );
             }
         };
     }
@@ -1,11 +1,10 @@
-use crate::data_set_parser::parse_full;
-use crate::encoding::ExplicitBigEndian;
-use crate::encoding::ExplicitLittleEndian;
-use crate::encoding::ImplicitLittleEndian;
+use crate::data_set_parser::DynDataSetParser;
 use crate::handler::Handler;
 use crate::meta_information;
 use crate::meta_information::MetaInformation;
 use crate::value_parser::ParseError;
+use crate::value_parser::ParseErrorKind;
+use crate::value_parser::ParseState;
 
 /// Parses a DICOM P10 Instance.  Returns the corresponding MetaInformation or
 /// a ParseError if an error occurs during parse.
@@ -14,34 +13,51 @@ use crate::value_parser::ParseError;
 ///
 /// * `handler` - The Handler to invoke when parsing the DataSet
 /// * `bytes`   - bytes from a DICOM P10 instance.  Can be the entire file or
-///               the beginning part of the file.  If the entire file is not
-///               provided and the parse is not Cancelled, an error may be
-///               returned
+///   the beginning part of the file.  If the entire file is not
+///   provided and the parse is not Cancelled, an error may be
+///   returned
 ///
-pub fn parse<'a, T: Handler>(
-    handler: &'a mut T,
+/// For the Deflated Explicit VR Little Endian transfer syntax, the DataSet is
+/// inflated before being parsed and the positions passed to the Handler are
+/// offsets in the inflated stream (see MetaInformation::is_deflated())
+///
+/// A Cancelled parse cannot be resumed from here, use P10Parser to parse
+/// headers first and continue into the rest of the DataSet later
+///
+pub fn parse<T: Handler>(handler: &mut T, bytes: &[u8]) -> Result<MetaInformation, ParseError> {
+    parse_with(handler, bytes, DynDataSetParser::from_transfer_syntax_uid)
+}
+
+/// Same as parse() but implicit little endian DataSets are parsed with the
+/// ImplicitLittleEndianDictionary encoding so the VRs of attributes in the
+/// data dictionary are inferred
+#[cfg(feature = "dictionary")]
+pub fn parse_with_dictionary<T: Handler>(
+    handler: &mut T,
+    bytes: &[u8],
+) -> Result<MetaInformation, ParseError> {
+    parse_with(
+        handler,
+        bytes,
+        DynDataSetParser::from_transfer_syntax_uid_with_dictionary,
+    )
+}
+
+fn parse_with<T: Handler>(
+    handler: &mut T,
     bytes: &[u8],
+    data_set_parser: fn(&str) -> DynDataSetParser,
 ) -> Result<MetaInformation, ParseError> {
-    let meta = meta_information::parse(handler, &bytes)?;
+    let meta = meta_information::parse(handler, bytes)?;
     let remaining_bytes = &bytes[meta.end_position..];
-    let result = match &meta.transfer_syntax_uid[..] {
-        "1.2.840.10008.1.2" => {
-            // implicit little endian
-            parse_full::<ImplicitLittleEndian>(handler, remaining_bytes, meta.end_position)
-        }
-        "1.2.840.10008.1.2.2" => {
-            // explicit big endian
-            parse_full::<ExplicitBigEndian>(handler, remaining_bytes, meta.end_position)
-        }
-        "1.2.840.10008.1.2.1.99" => panic!("deflated not suported yet"),
-        _ => {
-            // explicit little endian
-            parse_full::<ExplicitLittleEndian>(handler, remaining_bytes, meta.end_position)
-        }
-    };
-    match result {
-        Ok(_) => Ok(meta),
-        Err(parse_error) => Err(parse_error),
+    let mut parser = data_set_parser(&meta.transfer_syntax_uid);
+    let parse_result = parser.parse(handler, remaining_bytes, meta.end_position)?;
+    match parse_result.state {
+        ParseState::Incomplete => Err(ParseError::new(
+            ParseErrorKind::UnexpectedEOF,
+            parse_result.bytes_consumed + meta.end_position,
+        )),
+        _ => Ok(meta),
     }
 }
 
@@ -49,62 +65,96 @@ pub fn parse<'a, T: Handler>(
 mod tests {
 
     use super::parse;
+    use crate::attribute::Attribute;
+    use crate::handler::{Handler, HandlerResult};
     use crate::meta_information::tests::make_p10_header;
+    use crate::meta_information::tests::make_p10_header_with_transfer_syntax;
     use crate::test::tests::read_file;
     use crate::test::tests::TestHandler;
 
     fn make_p10_file() -> Vec<u8> {
         let mut bytes = make_p10_header();
-        bytes.extend_from_slice(&vec![0x08, 0x00, 0x05, 0x00, b'C', b'S', 2, 0, b'I', b'S']);
+        bytes.extend_from_slice(&[0x08, 0x00, 0x05, 0x00, b'C', b'S', 2, 0, b'I', b'S']);
 
         bytes
     }
 
     #[test]
     fn explicit_little_endian_parses() {
-        let mut bytes = make_p10_file();
+        let bytes = make_p10_file();
         let mut handler = TestHandler::default();
-        let result = parse(&mut handler, &mut bytes);
+        let result = parse(&mut handler, &bytes);
         assert!(result.is_ok());
         assert_eq!(handler.attributes.len(), 7);
     }
     #[test]
     fn explicit_little_endian() {
-        let mut bytes = read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
         let mut handler = TestHandler::default();
         //handler.print = true;
-        let result = parse(&mut handler, &mut bytes);
+        let result = parse(&mut handler, &bytes);
         assert!(result.is_ok());
         assert_eq!(265, handler.attributes.len());
     }
 
     #[test]
     fn implicit_little_endian() {
-        let mut bytes = read_file("tests/fixtures/CT1_UNC.implicit_little_endian.dcm");
+        let bytes = read_file("tests/fixtures/CT1_UNC.implicit_little_endian.dcm");
         let mut handler = TestHandler::default();
         //handler.print = true;
-        let result = parse(&mut handler, &mut bytes);
+        let result = parse(&mut handler, &bytes);
         assert!(result.is_ok());
         assert_eq!(265, handler.attributes.len());
     }
 
+    #[cfg(feature = "dictionary")]
+    #[test]
+    fn implicit_little_endian_with_dictionary() {
+        let bytes = read_file("tests/fixtures/CT1_UNC.implicit_little_endian.dcm");
+        let mut handler = TestHandler::default();
+        let result = super::parse_with_dictionary(&mut handler, &bytes);
+        assert!(result.is_ok());
+        assert_eq!(265, handler.attributes.len());
+        assert!(handler
+            .attributes
+            .iter()
+            .any(|attribute| attribute.vr_inferred));
+    }
+
     #[test]
     fn explicit_big_endian() {
-        let mut bytes = read_file("tests/fixtures/CT1_UNC.explicit_big_endian.dcm");
+        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_big_endian.dcm");
         let mut handler = TestHandler::default();
         //handler.print = true;
-        let result = parse(&mut handler, &mut bytes);
+        let result = parse(&mut handler, &bytes);
         assert!(result.is_ok());
         assert_eq!(265, handler.attributes.len());
     }
 
+    #[test]
+    fn deflated_explicit_little_endian() {
+        let mut bytes = make_p10_header_with_transfer_syntax("1.2.840.10008.1.2.1.99");
+        let data_set = vec![0x08, 0x00, 0x05, 0x00, b'C', b'S', 2, 0, b'I', b'S'];
+        bytes.extend_from_slice(&miniz_oxide::deflate::compress_to_vec(&data_set, 6));
+        let mut handler = TestHandler::default();
+        let meta = match parse(&mut handler, &bytes) {
+            Ok(meta) => meta,
+            Err(_parse_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+        assert!(meta.is_deflated());
+        assert_eq!(handler.attributes.len(), 7);
+        // positions are in the inflated stream
+        assert_eq!(handler.positions[6], meta.end_position);
+        assert_eq!(handler.data[6], b"IS");
+    }
+
     #[test]
     fn ele_sequences_known_lengths() {
         //(0008,9121) @ position 0x376 / 886
-        let mut bytes = read_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
+        let bytes = read_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
         let mut handler = TestHandler::default();
         //handler.print = true;
-        let result = parse(&mut handler, &mut bytes);
+        let result = parse(&mut handler, &bytes);
         assert!(result.is_ok());
         assert_eq!(165, handler.attributes.len());
     }
@@ -112,11 +162,44 @@ mod tests {
     #[test]
     fn ile_sequences_undefined_lengths() {
         //(0008,9121) @ position 0x376 / 886
-        let mut bytes = read_file("tests/fixtures/IM00001.implicit_little_endian.dcm");
+        let bytes = read_file("tests/fixtures/IM00001.implicit_little_endian.dcm");
         let mut handler = TestHandler::default();
         //handler.print = true;
-        let result = parse(&mut handler, &mut bytes);
+        let result = parse(&mut handler, &bytes);
         assert!(result.is_ok());
         assert_eq!(102, handler.attributes.len());
     }
+
+    #[test]
+    fn pixel_data_fragment_cancel_stops_parse() {
+        let bytes = read_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
+        let mut handler = FirstFragmentHandler::default();
+        let result = parse(&mut handler, &bytes);
+        assert!(result.is_ok());
+        assert_eq!(handler.fragment_count, 1);
+    }
+
+    // Handler that cancels the parse once the first pixel data fragment
+    // is complete
+    #[derive(Default)]
+    struct FirstFragmentHandler {
+        fragment_count: usize,
+    }
+
+    impl Handler for FirstFragmentHandler {
+        fn pixel_data_fragment(
+            &mut self,
+            _attribute: &Attribute,
+            _fragment_number: usize,
+            _data: &[u8],
+            complete: bool,
+        ) -> HandlerResult {
+            if complete {
+                self.fragment_count += 1;
+                HandlerResult::Cancel
+            } else {
+                HandlerResult::Continue
+            }
+        }
+    }
 }
@@ -1,63 +1,339 @@
-/*use crate::data_set_parser::DataSetParser;
-use crate::encoding::Encoding;
-use crate::encoding::ImplicitLittleEndian;
+use crate::data_set_parser::DynDataSetParser;
 use crate::handler::Handler;
-use crate::meta_information;
 use crate::meta_information::MetaInformation;
+use crate::meta_information::MetaInformationParser;
+use crate::prefix;
 use crate::value_parser::ParseError;
 use crate::value_parser::ParseResult;
+use crate::value_parser::ParseState;
 
-#[derive(Debug, Default)]
+/// Streaming parser for a DICOM P10 Instance.  Bytes can be provided in
+/// chunks of any size.  The P10 Header is parsed first and once it is
+/// complete, the DataSet is parsed with the Encoding of the transfer syntax
+/// in the P10 Header.
+///
+/// Like DataSetParser, parse() returns the number of bytes consumed which
+/// may be less than the number of bytes provided.  The bytes not consumed
+/// must be provided again (along with any new bytes) on the next call.
+///
+/// A Cancelled parse can be resumed the same way.  The prefix and P10 Header
+/// are not parsed again and the parse continues from the attribute that
+/// triggered the cancel (see DataSetParser::parse())
+#[derive(Default)]
 pub struct P10Parser {
+    // number of bytes consumed since the beginning of the stream
     bytes_consumed: usize,
-    meta: Option<MetaInformation>,
-    parser: Option<Box<DataSetParser<dyn Encoding>>>,
+    // parses the meta information until it is complete
+    meta_information_parser: MetaInformationParser,
+    meta_information: Option<MetaInformation>,
+    parser: Option<DynDataSetParser>,
+    // look up the VRs of implicit little endian attributes in the dictionary
+    #[cfg(feature = "dictionary")]
+    use_dictionary: bool,
 }
 
 impl P10Parser {
-    pub fn parse<'a, T: Handler>(
+    /// Creates a P10Parser that parses implicit little endian DataSets with
+    /// the ImplicitLittleEndianDictionary encoding so the VRs of attributes
+    /// in the data dictionary are inferred
+    #[cfg(feature = "dictionary")]
+    pub fn with_dictionary() -> P10Parser {
+        P10Parser {
+            use_dictionary: true,
+            ..P10Parser::default()
+        }
+    }
+
+    /// Returns the MetaInformation once the P10 Header has been fully parsed
+    pub fn meta_information(&self) -> Option<&MetaInformation> {
+        self.meta_information.as_ref()
+    }
+
+    /// Parses the next chunk of bytes from the DICOM P10 Instance.  The end
+    /// of the P10 Header is given by its group length attribute so the
+    /// DataSet is parsed as soon as the header bytes have been provided.  If
+    /// the group length is missing or wrong, the header ends at the first
+    /// attribute not in group 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - The Handler to invoke when parsing the P10 Header and
+    ///   DataSet
+    /// * `bytes`   - The next bytes from the DICOM P10 Instance
+    pub fn parse(
         &mut self,
-        handler: &'a mut T,
+        handler: &mut dyn Handler,
         bytes: &[u8],
     ) -> Result<ParseResult, ParseError> {
-        match self.meta {
-            None => {
-                let meta = meta_information::parse(handler, bytes)?;
-                self.meta = Some(meta);
-                self.bytes_consumed = meta.end_position;
-                self.parser = match &meta.transfer_syntax_uid[..] {
-                    "1.2.840.10008.1.2" => {
-                        Some(Box::new(DataSetParser::<ImplicitLittleEndian>::default()))
-                    }
+        let mut remaining_bytes = bytes;
+        let mut bytes_consumed = 0;
+
+        if self.meta_information.is_none() {
+            // validate the prefix once we have enough bytes
+            if self.bytes_consumed == 0 {
+                if bytes.len() < 132 {
+                    return Ok(ParseResult::incomplete(0));
                 }
+                prefix::validate(bytes)?;
+                self.bytes_consumed += 132;
+                bytes_consumed += 132;
+                remaining_bytes = &remaining_bytes[132..];
             }
-            Some(_) => {}
-        }
 
-        println!("{:?}", self.meta);
+            // parse the meta information
+            let parse_result = self.parse_meta_information(handler, remaining_bytes)?;
+            bytes_consumed += parse_result.bytes_consumed;
+            remaining_bytes = &remaining_bytes[parse_result.bytes_consumed..];
+
+            // return if the meta information is not complete yet
+            if parse_result.state != ParseState::Completed {
+                return Ok(ParseResult {
+                    bytes_consumed,
+                    state: parse_result.state,
+                });
+            }
+        }
 
-        Err(ParseError {
-            reason: "unexpected EOF",
-            position: self.bytes_consumed,
+        // parse the DataSet
+        let parser = self.parser.as_mut().unwrap();
+        let parse_result = parser.parse(handler, remaining_bytes, self.bytes_consumed)?;
+        self.bytes_consumed += parse_result.bytes_consumed;
+        Ok(ParseResult {
+            bytes_consumed: bytes_consumed + parse_result.bytes_consumed,
+            state: parse_result.state,
         })
     }
+
+    // Parses the meta information returning Completed once the end of the
+    // P10 Header is reached
+    fn parse_meta_information(
+        &mut self,
+        handler: &mut dyn Handler,
+        bytes: &[u8],
+    ) -> Result<ParseResult, ParseError> {
+        let parse_result =
+            self.meta_information_parser
+                .parse(handler, bytes, self.bytes_consumed)?;
+        self.bytes_consumed += parse_result.bytes_consumed;
+        if parse_result.state != ParseState::Completed {
+            return Ok(parse_result);
+        }
+
+        // the meta information is complete, create the DataSet parser for
+        // its transfer syntax
+        let meta_information = self
+            .meta_information_parser
+            .meta_information(self.bytes_consumed)?;
+        self.parser = Some(self.data_set_parser(&meta_information.transfer_syntax_uid));
+        self.meta_information = Some(meta_information);
+
+        Ok(parse_result)
+    }
+
+    // Creates the DataSet parser for the transfer syntax
+    fn data_set_parser(&self, transfer_syntax_uid: &str) -> DynDataSetParser {
+        #[cfg(feature = "dictionary")]
+        if self.use_dictionary {
+            return DynDataSetParser::from_transfer_syntax_uid_with_dictionary(transfer_syntax_uid);
+        }
+        DynDataSetParser::from_transfer_syntax_uid(transfer_syntax_uid)
+    }
 }
+
 #[cfg(test)]
 mod tests {
 
     use super::P10Parser;
+    use crate::meta_information::tests::make_p10_header_with_transfer_syntax;
     use crate::test::tests::read_file;
+    use crate::test::tests::ResumeHandler;
     use crate::test::tests::TestHandler;
+    use crate::value_parser::ParseState;
+
+    fn parse_file(filepath: &str) -> (P10Parser, TestHandler) {
+        let bytes = read_file(filepath);
+        let mut handler = TestHandler::default();
+        //handler.print = true;
+        let mut parser = P10Parser::default();
+        match parser.parse(&mut handler, &bytes) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, bytes.len());
+                assert_eq!(result.state, ParseState::Completed);
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        (parser, handler)
+    }
 
     #[test]
     fn explicit_little_endian() {
-        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let (parser, handler) = parse_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        assert_eq!(265, handler.attributes.len());
+        let meta = parser.meta_information().unwrap();
+        assert_eq!(meta.transfer_syntax_uid, "1.2.840.10008.1.2.1");
+    }
+
+    #[test]
+    fn implicit_little_endian() {
+        let (_parser, handler) = parse_file("tests/fixtures/CT1_UNC.implicit_little_endian.dcm");
+        assert_eq!(265, handler.attributes.len());
+    }
+
+    #[cfg(feature = "dictionary")]
+    #[test]
+    fn with_dictionary_infers_implicit_vrs() {
+        use crate::tag::Tag;
+        use crate::vr::VR;
+
+        let bytes = read_file("tests/fixtures/CT1_UNC.implicit_little_endian.dcm");
+        let mut handler = TestHandler::default();
+        let mut parser = P10Parser::with_dictionary();
+        match parser.parse(&mut handler, &bytes) {
+            Ok(result) => assert_eq!(result.state, ParseState::Completed),
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        let patient_name = handler
+            .attributes
+            .iter()
+            .find(|attribute| attribute.tag == Tag::new(0x0010, 0x0010))
+            .unwrap();
+        assert_eq!(patient_name.vr, Some(VR::PN));
+        assert!(patient_name.vr_inferred);
+
+        let (_parser, handler) = parse_file("tests/fixtures/CT1_UNC.implicit_little_endian.dcm");
+        assert!(handler
+            .attributes
+            .iter()
+            .all(|attribute| !attribute.vr_inferred));
+    }
+
+    #[test]
+    fn explicit_big_endian() {
+        let (_parser, handler) = parse_file("tests/fixtures/CT1_UNC.explicit_big_endian.dcm");
+        assert_eq!(265, handler.attributes.len());
+    }
+
+    #[test]
+    fn deflated_explicit_little_endian() {
+        let mut bytes = make_p10_header_with_transfer_syntax("1.2.840.10008.1.2.1.99");
+        let data_set = vec![0x08, 0x00, 0x05, 0x00, b'C', b'S', 2, 0, b'I', b'S'];
+        bytes.extend_from_slice(&miniz_oxide::deflate::compress_to_vec(&data_set, 6));
         let mut handler = TestHandler::default();
-        //handler.print = true;
         let mut parser = P10Parser::default();
         let result = parser.parse(&mut handler, &bytes);
         assert!(result.is_ok());
-        assert_eq!(265, handler.attributes.len());
+        assert_eq!(handler.attributes.len(), 7);
+        assert!(parser.meta_information().unwrap().is_deflated());
+    }
+
+    #[test]
+    fn streaming_parse_ok() {
+        let bytes = read_file("tests/fixtures/IM00001.implicit_little_endian.dcm");
+        let mut handler = TestHandler::default();
+        let mut parser = P10Parser::default();
+        let mut offset = 0;
+        let mut end = 0;
+        while end < bytes.len() {
+            end = std::cmp::min(end + 97, bytes.len());
+            match parser.parse(&mut handler, &bytes[offset..end]) {
+                Ok(parse_result) => offset += parse_result.bytes_consumed,
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+        assert_eq!(offset, bytes.len());
+        assert_eq!(102, handler.attributes.len());
+        assert_eq!(
+            parser.meta_information().unwrap().transfer_syntax_uid,
+            "1.2.840.10008.1.2"
+        );
+    }
+
+    #[test]
+    fn incomplete_meta_information_returns_incomplete() {
+        let bytes = read_file("tests/fixtures/CT1_UNC.explicit_little_endian.dcm");
+        let mut handler = TestHandler::default();
+        let mut parser = P10Parser::default();
+        match parser.parse(&mut handler, &bytes[..200]) {
+            Ok(result) => {
+                assert_eq!(result.state, ParseState::Incomplete);
+                assert!(parser.meta_information().is_none());
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+    }
+
+    #[test]
+    fn meta_information_completes_at_group_length() {
+        let bytes = make_p10_header_with_transfer_syntax("1.2.840.10008.1.2.1");
+        let mut handler = TestHandler::default();
+        let mut parser = P10Parser::default();
+        match parser.parse(&mut handler, &bytes) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, bytes.len());
+                let meta = parser.meta_information().unwrap();
+                assert_eq!(meta.end_position, bytes.len());
+                assert_eq!(meta.group_length as usize, bytes.len() - 144);
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        }
+        assert_eq!(handler.attributes.len(), 6);
+    }
+
+    #[test]
+    fn streaming_parse_without_group_length_ok() {
+        let mut bytes = make_p10_header_with_transfer_syntax("1.2.840.10008.1.2.1");
+        // remove the group length attribute
+        bytes.drain(132..144);
+        let header_length = bytes.len();
+        bytes.extend_from_slice(&[0x08, 0x00, 0x05, 0x00, b'C', b'S', 2, 0, b'I', b'S']);
+        let mut handler = TestHandler::default();
+        let mut parser = P10Parser::default();
+        let mut offset = 0;
+        for end in 1..=bytes.len() {
+            match parser.parse(&mut handler, &bytes[offset..end]) {
+                Ok(result) => offset += result.bytes_consumed,
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+        assert_eq!(offset, bytes.len());
+        assert_eq!(
+            parser.meta_information().unwrap().end_position,
+            header_length
+        );
+        assert_eq!(handler.attributes.len(), 6);
+    }
+
+    #[test]
+    fn invalid_prefix_returns_error() {
+        let bytes = vec![0; 256];
+        let mut handler = TestHandler::default();
+        let mut parser = P10Parser::default();
+        assert!(parser.parse(&mut handler, &bytes).is_err());
+    }
+
+    #[test]
+    fn resume_after_cancel_completes() {
+        let bytes = read_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
+        let (_parser, expected) =
+            parse_file("tests/fixtures/CT0012.fragmented_no_bot_jpeg_ls.80.dcm");
+        let mut handler = ResumeHandler::default();
+        let mut parser = P10Parser::default();
+        let mut offset = 0;
+        loop {
+            match parser.parse(&mut handler, &bytes[offset..]) {
+                Ok(result) => {
+                    offset += result.bytes_consumed;
+                    if result.state != ParseState::Cancelled {
+                        assert_eq!(result.state, ParseState::Completed);
+                        break;
+                    }
+                }
+                Err(_error) => panic!("Let's play Global Thermonuclear War"),
+            }
+        }
+        assert_eq!(offset, bytes.len());
+        assert_eq!(handler.handler.attributes, expected.attributes);
+        assert_eq!(handler.handler.positions, expected.positions);
+        assert_eq!(handler.handler.data, expected.data);
     }
 }
-*/
@@ -1,4 +1,5 @@
 use crate::value_parser::ParseError;
+use crate::value_parser::ParseErrorKind;
 
 /// Detects the presence of a valid DICOM P10 Header Prefix.  A valid
 /// prefix consists of 132 bytes with the string "DICM" at location
@@ -6,18 +7,12 @@ use crate::value_parser::ParseError;
 pub fn validate(bytes: &[u8]) -> Result<(), ParseError> {
     // check length
     if bytes.len() < 132 {
-        return Err(ParseError {
-            reason: "must have at least 132 bytes to validate",
-            position: bytes.len(),
-        });
+        return Err(ParseError::new(ParseErrorKind::UnexpectedEOF, bytes.len()));
     }
 
     // check for DICM
     if &bytes[128..132] != b"DICM" {
-        return Err(ParseError {
-            reason: "DICOM not found at position 128",
-            position: 128,
-        });
+        return Err(ParseError::new(ParseErrorKind::InvalidPrefix, 128));
     }
 
     Ok(())
@@ -26,49 +21,48 @@ pub fn validate(bytes: &[u8]) -> Result<(), ParseError> {
 #[cfg(test)]
 mod tests {
     use super::validate;
+    use crate::value_parser::ParseErrorKind;
 
     #[test]
     fn zero_preamble_valid_prefix_returns_true() {
-        let mut bytes: Vec<u8> = vec![];
-        bytes.resize(134, 0);
-        bytes[128] = 'D' as u8;
-        bytes[129] = 'I' as u8;
-        bytes[130] = 'C' as u8;
-        bytes[131] = 'M' as u8;
+        let mut bytes: Vec<u8> = vec![0; 134];
+        bytes[128] = b'D';
+        bytes[129] = b'I';
+        bytes[130] = b'C';
+        bytes[131] = b'M';
 
         let result = validate(&bytes);
-        assert_eq!(result.is_ok(), true);
+        assert!(result.is_ok());
     }
 
     #[test]
     fn non_zero_preamble_valid_prefix_returns_true() {
-        let mut bytes: Vec<u8> = vec![];
-        bytes.resize(134, 0);
+        let mut bytes: Vec<u8> = vec![0; 134];
         bytes[0] = 1;
-        bytes[128] = 'D' as u8;
-        bytes[129] = 'I' as u8;
-        bytes[130] = 'C' as u8;
-        bytes[131] = 'M' as u8;
+        bytes[128] = b'D';
+        bytes[129] = b'I';
+        bytes[130] = b'C';
+        bytes[131] = b'M';
 
         let result = validate(&bytes);
-        assert_eq!(result.is_ok(), true);
+        assert!(result.is_ok());
     }
 
     #[test]
     fn zero_preamble_invalid_prefix_returns_error() {
-        let mut bytes: Vec<u8> = vec![];
-        bytes.resize(134, 0);
+        let bytes: Vec<u8> = vec![0; 134];
 
         let result = validate(&bytes);
-        assert_eq!(result.is_err(), true);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind, ParseErrorKind::InvalidPrefix);
     }
 
     #[test]
     fn short_buffer_returns_error() {
-        let mut bytes: Vec<u8> = vec![];
-        bytes.resize(128, 0);
+        let bytes: Vec<u8> = vec![0; 128];
 
         let result = validate(&bytes);
-        assert_eq!(result.is_err(), true);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().kind, ParseErrorKind::UnexpectedEOF);
     }
 }
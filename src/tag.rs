@@ -1,13 +1,13 @@
 use crate::encoding::Encoding;
 use std::fmt;
 
-#[derive(Default, PartialEq, Eq, PartialOrd, Clone, Copy)]
+#[derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct Tag {
     pub group: u16,
     pub element: u16,
 }
 impl Tag {
-    pub fn new(group: u16, element: u16) -> Tag {
+    pub const fn new(group: u16, element: u16) -> Tag {
         Tag { group, element }
     }
 
@@ -19,7 +19,7 @@ impl Tag {
 
     #[allow(clippy::trivially_copy_pass_by_ref)]
     pub fn is_private(&self) -> bool {
-        (self.group % 2) > 0
+        (self.group & 1) == 1
     }
 }
 
@@ -56,12 +56,12 @@ mod tests {
     #[test]
     fn tag_is_private_returns_false() {
         let tag = Tag::new(8, 10);
-        assert_eq!(tag.is_private(), false);
+        assert!(!tag.is_private());
     }
 
     #[test]
     fn tag_is_private_returns_true() {
         let tag = Tag::new(9, 10);
-        assert_eq!(tag.is_private(), true);
+        assert!(tag.is_private());
     }
 }
@@ -0,0 +1,311 @@
+//! Expressions selecting attributes in a DataSet including nested ones, e.g.
+//! `(0008,1115)[*].(0020,000E)` or, with the dictionary feature,
+//! `ReferencedSeriesSequence[0].SeriesInstanceUID`.
+
+use crate::handler::path::{AttributePath, PathItem};
+use crate::tag::Tag;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned when a TagPath cannot be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum TagPathError {
+    /// The TagPath or one of its segments is empty
+    Empty,
+    /// A tag is not in the (gggg,eeee) format
+    InvalidTag(String),
+    /// A keyword is not in the data dictionary (or the dictionary feature is
+    /// not enabled)
+    UnknownKeyword(String),
+    /// An item selector is not [n] or [*] or is on the last segment
+    InvalidItem(String),
+}
+
+impl fmt::Display for TagPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TagPathError::Empty => write!(f, "empty tag path segment"),
+            TagPathError::InvalidTag(tag) => write!(f, "invalid tag '{}'", tag),
+            TagPathError::UnknownKeyword(keyword) => write!(f, "unknown keyword '{}'", keyword),
+            TagPathError::InvalidItem(item) => write!(f, "invalid item selector '{}'", item),
+        }
+    }
+}
+
+impl std::error::Error for TagPathError {}
+
+/// Selects the items of a sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemSelector {
+    /// The item with the index, starting at zero
+    Index(usize),
+    /// All items (*)
+    All,
+}
+
+impl ItemSelector {
+    /// Returns true if the item with the index is selected
+    pub fn matches(&self, index: usize) -> bool {
+        match *self {
+            ItemSelector::Index(selected) => selected == index,
+            ItemSelector::All => true,
+        }
+    }
+}
+
+/// A sequence attribute and the items in it a TagPath passes through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagPathItem {
+    /// The tag of the sequence attribute
+    pub tag: Tag,
+    /// The items of the sequence selected
+    pub item: ItemSelector,
+}
+
+/// Selects attributes by tag and the sequence items they are nested in.
+/// Segments are separated by '.' and are a tag in the (gggg,eeee) format or
+/// a keyword (with the dictionary feature).  Segments for sequences are
+/// followed by an item selector, [n] for the item with index n (starting at
+/// zero) or [*] for all items.  A sequence segment without an item selector
+/// selects all items.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TagPath {
+    /// The sequences items from the root DataSet to the attributes, empty for
+    /// attributes in the root DataSet
+    pub items: Vec<TagPathItem>,
+    /// The tag of the attributes
+    pub tag: Tag,
+}
+
+impl TagPath {
+    /// Parses a TagPath such as (0008,1115)[*].(0020,000E)
+    pub fn parse(string: &str) -> Result<TagPath, TagPathError> {
+        let segments: Vec<&str> = string.split('.').collect();
+        let mut items = vec![];
+        for (index, segment) in segments.iter().enumerate() {
+            let segment = segment.trim();
+            let (tag, item) = match segment.find('[') {
+                Some(start) => (&segment[..start], Some(&segment[start..])),
+                None => (segment, None),
+            };
+            let tag = parse_tag(tag)?;
+            if index == segments.len() - 1 {
+                if let Some(item) = item {
+                    return Err(TagPathError::InvalidItem(String::from(item)));
+                }
+                return Ok(TagPath { items, tag });
+            }
+            let item = match item {
+                Some(item) => parse_item(item)?,
+                None => ItemSelector::All,
+            };
+            items.push(TagPathItem { tag, item });
+        }
+        // split() always returns at least one segment
+        Err(TagPathError::Empty)
+    }
+
+    /// Returns true if the sequence items match the first items of this
+    /// TagPath
+    pub fn matches_items(&self, items: &[PathItem]) -> bool {
+        items.len() <= self.items.len()
+            && self.items.iter().zip(items).all(|(selector, item)| {
+                selector.tag == item.tag && selector.item.matches(item.item.index)
+            })
+    }
+
+    /// Returns true if the attribute at the path is selected by this TagPath
+    pub fn matches(&self, path: &AttributePath) -> bool {
+        self.items.len() == path.items.len()
+            && self.tag == path.tag
+            && self.matches_items(&path.items)
+    }
+
+    /// Returns true if the attribute at the path is a sequence that
+    /// attributes selected by this TagPath are nested in
+    pub fn is_ancestor(&self, path: &AttributePath) -> bool {
+        self.items.len() > path.items.len()
+            && self.items[path.items.len()].tag == path.tag
+            && self.matches_items(&path.items)
+    }
+}
+
+impl FromStr for TagPath {
+    type Err = TagPathError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        TagPath::parse(string)
+    }
+}
+
+/// Formats the TagPath with tags in the (gggg,eeee) format and an item
+/// selector for every sequence
+impl fmt::Display for TagPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in &self.items {
+            match item.item {
+                ItemSelector::Index(index) => write!(f, "{:?}[{}].", item.tag, index)?,
+                ItemSelector::All => write!(f, "{:?}[*].", item.tag)?,
+            }
+        }
+        write!(f, "{:?}", self.tag)
+    }
+}
+
+fn parse_tag(string: &str) -> Result<Tag, TagPathError> {
+    if string.is_empty() {
+        return Err(TagPathError::Empty);
+    }
+    if !string.starts_with('(') {
+        return lookup_keyword(string);
+    }
+    let invalid_tag = || TagPathError::InvalidTag(String::from(string));
+    let numbers = string
+        .strip_prefix('(')
+        .and_then(|string| string.strip_suffix(')'))
+        .ok_or_else(invalid_tag)?;
+    let mut numbers = numbers.split(',');
+    let mut number = || {
+        numbers
+            .next()
+            .filter(|number| number.len() == 4 && number.bytes().all(|b| b.is_ascii_hexdigit()))
+            .and_then(|number| u16::from_str_radix(number, 16).ok())
+            .ok_or_else(invalid_tag)
+    };
+    let tag = Tag::new(number()?, number()?);
+    match numbers.next() {
+        Some(_) => Err(invalid_tag()),
+        None => Ok(tag),
+    }
+}
+
+#[cfg(feature = "dictionary")]
+fn lookup_keyword(keyword: &str) -> Result<Tag, TagPathError> {
+    crate::dictionary::lookup_keyword(keyword)
+        .map(|entry| entry.tag)
+        .ok_or_else(|| TagPathError::UnknownKeyword(String::from(keyword)))
+}
+
+#[cfg(not(feature = "dictionary"))]
+fn lookup_keyword(keyword: &str) -> Result<Tag, TagPathError> {
+    Err(TagPathError::UnknownKeyword(String::from(keyword)))
+}
+
+fn parse_item(string: &str) -> Result<ItemSelector, TagPathError> {
+    let invalid_item = || TagPathError::InvalidItem(String::from(string));
+    let item = string
+        .strip_prefix('[')
+        .and_then(|string| string.strip_suffix(']'))
+        .ok_or_else(invalid_item)?;
+    if item == "*" {
+        Ok(ItemSelector::All)
+    } else {
+        item.parse()
+            .map(ItemSelector::Index)
+            .map_err(|_| invalid_item())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ItemSelector, TagPath, TagPathError, TagPathItem};
+    use crate::handler::path::{AttributePath, PathItem};
+    use crate::handler::SequenceItem;
+    use crate::tag::Tag;
+
+    fn make_path(items: &[(Tag, usize)], tag: Tag) -> AttributePath {
+        AttributePath {
+            items: items
+                .iter()
+                .map(|(tag, index)| PathItem {
+                    tag: *tag,
+                    item: SequenceItem {
+                        index: *index,
+                        ..SequenceItem::default()
+                    },
+                })
+                .collect(),
+            tag,
+        }
+    }
+
+    #[test]
+    fn parses_tag_path() {
+        let tag_path = TagPath::parse("(0040,A730)[2].(0040,a730)[*].(0008,0100)").unwrap();
+        assert_eq!(
+            tag_path.items,
+            vec![
+                TagPathItem {
+                    tag: Tag::new(0x0040, 0xA730),
+                    item: ItemSelector::Index(2),
+                },
+                TagPathItem {
+                    tag: Tag::new(0x0040, 0xA730),
+                    item: ItemSelector::All,
+                },
+            ]
+        );
+        assert_eq!(tag_path.tag, Tag::new(0x0008, 0x0100));
+        assert_eq!(
+            tag_path.to_string(),
+            "(0040,A730)[2].(0040,A730)[*].(0008,0100)"
+        );
+        // sequences without an item selector select all items
+        let tag_path: TagPath = "(0008,1115).(0020,000E)".parse().unwrap();
+        assert_eq!(tag_path.items[0].item, ItemSelector::All);
+    }
+
+    #[test]
+    fn invalid_tag_path_returns_error() {
+        assert_eq!(TagPath::parse(""), Err(TagPathError::Empty));
+        assert_eq!(TagPath::parse("(0008,1115)."), Err(TagPathError::Empty));
+        for tag in &[
+            "(0008,01)",
+            "(0008,0100",
+            "(0008,0100,0000)",
+            "(gggg,0100)",
+            "(+123,0010)",
+        ] {
+            assert_eq!(
+                TagPath::parse(tag),
+                Err(TagPathError::InvalidTag(String::from(*tag)))
+            );
+        }
+        assert!(matches!(
+            TagPath::parse("(0008,1115)[x].(0020,000E)"),
+            Err(TagPathError::InvalidItem(_))
+        ));
+        assert!(matches!(
+            TagPath::parse("(0008,1115)[0]"),
+            Err(TagPathError::InvalidItem(_))
+        ));
+        assert!(matches!(
+            TagPath::parse("NotAKeyword"),
+            Err(TagPathError::UnknownKeyword(_))
+        ));
+    }
+
+    #[cfg(feature = "dictionary")]
+    #[test]
+    fn parses_keywords() {
+        let tag_path = TagPath::parse("ReferencedSeriesSequence[*].SeriesInstanceUID").unwrap();
+        assert_eq!(tag_path.to_string(), "(0008,1115)[*].(0020,000E)");
+    }
+
+    #[test]
+    fn matches_attribute_paths() {
+        let sequence = Tag::new(0x0008, 0x1115);
+        let series = Tag::new(0x0020, 0x000E);
+        let tag_path = TagPath::parse("(0008,1115)[1].(0020,000E)").unwrap();
+        assert!(tag_path.matches(&make_path(&[(sequence, 1)], series)));
+        assert!(!tag_path.matches(&make_path(&[(sequence, 0)], series)));
+        assert!(!tag_path.matches(&make_path(&[], series)));
+        assert!(!tag_path.matches(&make_path(&[(sequence, 1), (sequence, 1)], series)));
+        assert!(tag_path.is_ancestor(&make_path(&[], sequence)));
+        assert!(!tag_path.is_ancestor(&make_path(&[], series)));
+        assert!(!tag_path.is_ancestor(&make_path(&[(sequence, 1)], series)));
+
+        let tag_path = TagPath::parse("(0008,1115)[*].(0020,000E)").unwrap();
+        assert!(tag_path.matches(&make_path(&[(sequence, 7)], series)));
+    }
+}
@@ -2,7 +2,7 @@
 pub mod tests {
 
     use crate::attribute::Attribute;
-    use crate::handler::{Handler, HandlerResult};
+    use crate::handler::{Handler, HandlerResult, SequenceItem};
     use crate::meta_information;
     use crate::meta_information::MetaInformation;
     use std::fs::File;
@@ -15,6 +15,7 @@ pub mod tests {
     #[derive(Default)]
     pub struct TestHandler {
         pub attributes: Vec<Attribute>,
+        pub positions: Vec<usize>,
         pub data: Vec<Vec<u8>>,
         pub depth: usize,
         pub print: bool,
@@ -38,10 +39,11 @@ pub mod tests {
                 );
             }
             self.attributes.push(*attribute);
+            self.positions.push(position);
             HandlerResult::Continue
         }
 
-        fn data(&mut self, _attribute: &Attribute, data: &[u8], complete: bool) {
+        fn data(&mut self, _attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
             if self.print {
                 println!(
                     "{:-<width$}+ data of len {:?} complete={}",
@@ -52,6 +54,7 @@ pub mod tests {
                 );
             }
             self.data.push(data.to_vec());
+            HandlerResult::Continue
         }
 
         fn start_sequence(&mut self, _attribute: &Attribute) {
@@ -61,7 +64,7 @@ pub mod tests {
             self.depth += 1;
         }
 
-        fn start_sequence_item(&mut self, _attribute: &Attribute) {
+        fn start_sequence_item(&mut self, _attribute: &Attribute, _item: &SequenceItem) {
             if self.print {
                 println!("{:-<width$}{{", "-", width = (self.depth * 2));
             }
@@ -122,6 +125,90 @@ pub mod tests {
         }
     }
 
+    /// Implementation of Handler that cancels the parse the first time each
+    /// attribute is delivered and after each complete value and forwards
+    /// everything else to a TestHandler.  Used to test that a cancelled parse
+    /// can be resumed without losing or repeating any callbacks
+    #[derive(Default)]
+    pub struct ResumeHandler {
+        pub handler: TestHandler,
+        pub cancel_count: usize,
+        // true if the next call to attribute() is the redelivery of an
+        // attribute that was cancelled
+        resumed: bool,
+    }
+
+    impl ResumeHandler {
+        fn cancel_if_complete(&mut self, complete: bool) -> HandlerResult {
+            if complete {
+                self.cancel_count += 1;
+                HandlerResult::Cancel
+            } else {
+                HandlerResult::Continue
+            }
+        }
+    }
+
+    impl Handler for ResumeHandler {
+        fn attribute(
+            &mut self,
+            attribute: &Attribute,
+            position: usize,
+            data_offset: usize,
+        ) -> HandlerResult {
+            if !self.resumed {
+                self.resumed = true;
+                self.cancel_count += 1;
+                return HandlerResult::Cancel;
+            }
+            self.resumed = false;
+            self.handler.attribute(attribute, position, data_offset)
+        }
+
+        fn data(&mut self, attribute: &Attribute, data: &[u8], complete: bool) -> HandlerResult {
+            self.handler.data(attribute, data, complete);
+            self.cancel_if_complete(complete)
+        }
+
+        fn start_sequence(&mut self, attribute: &Attribute) {
+            self.handler.start_sequence(attribute)
+        }
+
+        fn start_sequence_item(&mut self, attribute: &Attribute, item: &SequenceItem) {
+            self.handler.start_sequence_item(attribute, item)
+        }
+
+        fn end_sequence_item(&mut self, attribute: &Attribute) {
+            self.handler.end_sequence_item(attribute)
+        }
+
+        fn end_sequence(&mut self, attribute: &Attribute) {
+            self.handler.end_sequence(attribute)
+        }
+
+        fn basic_offset_table(
+            &mut self,
+            attribute: &Attribute,
+            data: &[u8],
+            complete: bool,
+        ) -> HandlerResult {
+            self.handler.basic_offset_table(attribute, data, complete);
+            self.cancel_if_complete(complete)
+        }
+
+        fn pixel_data_fragment(
+            &mut self,
+            attribute: &Attribute,
+            fragment_number: usize,
+            data: &[u8],
+            complete: bool,
+        ) -> HandlerResult {
+            self.handler
+                .pixel_data_fragment(attribute, fragment_number, data, complete);
+            self.cancel_if_complete(complete)
+        }
+    }
+
     pub fn read_file(filepath: &str) -> Vec<u8> {
         let mut file = File::open(filepath).unwrap();
         let mut buffer = Vec::new();
@@ -130,7 +217,7 @@ pub mod tests {
     }
 
     pub fn read_data_set_bytes_from_file(filepath: &str) -> (MetaInformation, Vec<u8>) {
-        let bytes = read_file(&filepath);
+        let bytes = read_file(filepath);
         let mut handler = TestHandler::default();
 
         let meta = match meta_information::parse(&mut handler, &bytes) {
@@ -139,6 +226,6 @@ pub mod tests {
         };
         //println!("meta.end_position={}", meta.end_position);
         let end_position = meta.end_position;
-        (meta, (&bytes[end_position..]).to_vec())
+        (meta, bytes[end_position..].to_vec())
     }
 }
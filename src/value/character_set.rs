@@ -0,0 +1,534 @@
+use crate::attribute::Attribute;
+use crate::value::{split, ValueError};
+use crate::vr::VR;
+use encoding_rs::Encoding;
+
+/// A character set that can be specified in (0008,0005) Specific Character
+/// Set.  The variants are named after the ISO-IR registration numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterSet {
+    /// The default repertoire (ISO-IR 6, ASCII)
+    Default,
+    /// Latin alphabet No. 1
+    IsoIr100,
+    /// Latin alphabet No. 2
+    IsoIr101,
+    /// Latin alphabet No. 3
+    IsoIr109,
+    /// Latin alphabet No. 4
+    IsoIr110,
+    /// Cyrillic
+    IsoIr144,
+    /// Arabic
+    IsoIr127,
+    /// Greek
+    IsoIr126,
+    /// Hebrew
+    IsoIr138,
+    /// Latin alphabet No. 5
+    IsoIr148,
+    /// Latin alphabet No. 9
+    IsoIr203,
+    /// Japanese JIS X 0201 (Katakana and Romaji)
+    IsoIr13,
+    /// Thai TIS 620-2533
+    IsoIr166,
+    /// Japanese JIS X 0208 (Kanji), code extensions only
+    IsoIr87,
+    /// Japanese JIS X 0212 (Supplementary Kanji), code extensions only
+    IsoIr159,
+    /// Korean KS X 1001 (Hangul and Hanja), code extensions only
+    IsoIr149,
+    /// Simplified Chinese GB 2312, code extensions only
+    IsoIr58,
+    /// Unicode in UTF-8
+    IsoIr192,
+    /// Chinese GB 18030
+    Gb18030,
+    /// Chinese GBK
+    Gbk,
+}
+
+impl CharacterSet {
+    /// Returns the CharacterSet for a defined term of Specific Character Set
+    /// (e.g. ISO_IR 100 or ISO 2022 IR 87) and true if it is a term with
+    /// code extensions
+    pub fn from_term(term: &str) -> Option<(CharacterSet, bool)> {
+        let term = term.trim_matches(' ');
+        let (number, code_extensions) = if term.is_empty() {
+            return Some((CharacterSet::Default, false));
+        } else if let Some(number) = term.strip_prefix("ISO_IR ") {
+            (number, false)
+        } else if let Some(number) = term.strip_prefix("ISO-IR ") {
+            // not a defined term but found in the wild
+            (number, false)
+        } else if let Some(number) = term.strip_prefix("ISO 2022 IR ") {
+            (number, true)
+        } else {
+            return match term {
+                "GB18030" => Some((CharacterSet::Gb18030, false)),
+                "GBK" => Some((CharacterSet::Gbk, false)),
+                _ => None,
+            };
+        };
+        let character_set = match number {
+            "6" => CharacterSet::Default,
+            "100" => CharacterSet::IsoIr100,
+            "101" => CharacterSet::IsoIr101,
+            "109" => CharacterSet::IsoIr109,
+            "110" => CharacterSet::IsoIr110,
+            "144" => CharacterSet::IsoIr144,
+            "127" => CharacterSet::IsoIr127,
+            "126" => CharacterSet::IsoIr126,
+            "138" => CharacterSet::IsoIr138,
+            "148" => CharacterSet::IsoIr148,
+            "203" => CharacterSet::IsoIr203,
+            "13" => CharacterSet::IsoIr13,
+            "166" => CharacterSet::IsoIr166,
+            "192" if !code_extensions => CharacterSet::IsoIr192,
+            "87" | "159" | "149" | "58" if code_extensions => match number {
+                "87" => CharacterSet::IsoIr87,
+                "159" => CharacterSet::IsoIr159,
+                "149" => CharacterSet::IsoIr149,
+                _ => CharacterSet::IsoIr58,
+            },
+            _ => return None,
+        };
+        Some((character_set, code_extensions))
+    }
+
+    // returns the graphic sets designated to G0 and G1 by the character set
+    fn designations(self) -> (Option<Graphic>, Option<Graphic>) {
+        let g1 = |encoding| (Some(Graphic::Ascii), Some(Graphic::SingleByte(encoding)));
+        match self {
+            CharacterSet::Default => (Some(Graphic::Ascii), None),
+            CharacterSet::IsoIr100 => (Some(Graphic::Ascii), Some(Graphic::Latin1)),
+            CharacterSet::IsoIr101 => g1(encoding_rs::ISO_8859_2),
+            CharacterSet::IsoIr109 => g1(encoding_rs::ISO_8859_3),
+            CharacterSet::IsoIr110 => g1(encoding_rs::ISO_8859_4),
+            CharacterSet::IsoIr144 => g1(encoding_rs::ISO_8859_5),
+            CharacterSet::IsoIr127 => g1(encoding_rs::ISO_8859_6),
+            CharacterSet::IsoIr126 => g1(encoding_rs::ISO_8859_7),
+            CharacterSet::IsoIr138 => g1(encoding_rs::ISO_8859_8),
+            CharacterSet::IsoIr148 => g1(encoding_rs::WINDOWS_1254),
+            CharacterSet::IsoIr203 => g1(encoding_rs::ISO_8859_15),
+            // the Shift_JIS single byte range is JIS X 0201
+            CharacterSet::IsoIr13 => g1(encoding_rs::SHIFT_JIS),
+            CharacterSet::IsoIr166 => g1(encoding_rs::WINDOWS_874),
+            CharacterSet::IsoIr87 => (Some(Graphic::Jis0208), None),
+            CharacterSet::IsoIr159 => (Some(Graphic::Jis0212), None),
+            CharacterSet::IsoIr149 => (None, Some(Graphic::Euc(encoding_rs::EUC_KR))),
+            CharacterSet::IsoIr58 => (None, Some(Graphic::Euc(encoding_rs::GBK))),
+            CharacterSet::IsoIr192 | CharacterSet::Gb18030 | CharacterSet::Gbk => (None, None),
+        }
+    }
+}
+
+// A graphic character set that can be designated to G0 or G1 with ISO 2022
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Graphic {
+    Ascii,
+    Latin1,
+    // single byte character set decoded as a whole
+    SingleByte(&'static Encoding),
+    // multi byte G0 sets, decoded as EUC-JP with the high bits set
+    Jis0208,
+    Jis0212,
+    // multi byte G1 sets whose bytes are already EUC encoded
+    Euc(&'static Encoding),
+}
+
+// The ISO 2022 escape sequences used in DICOM and the graphic set they
+// designate to G0 (true) or G1 (false)
+const ESCAPE_SEQUENCES: &[(&[u8], bool, Graphic)] = &[
+    (b"\x1b(B", true, Graphic::Ascii),
+    (b"\x1b(J", true, Graphic::Ascii),
+    (
+        b"\x1b)I",
+        false,
+        Graphic::SingleByte(encoding_rs::SHIFT_JIS),
+    ),
+    (b"\x1b-A", false, Graphic::Latin1),
+    (
+        b"\x1b-B",
+        false,
+        Graphic::SingleByte(encoding_rs::ISO_8859_2),
+    ),
+    (
+        b"\x1b-C",
+        false,
+        Graphic::SingleByte(encoding_rs::ISO_8859_3),
+    ),
+    (
+        b"\x1b-D",
+        false,
+        Graphic::SingleByte(encoding_rs::ISO_8859_4),
+    ),
+    (
+        b"\x1b-L",
+        false,
+        Graphic::SingleByte(encoding_rs::ISO_8859_5),
+    ),
+    (
+        b"\x1b-G",
+        false,
+        Graphic::SingleByte(encoding_rs::ISO_8859_6),
+    ),
+    (
+        b"\x1b-F",
+        false,
+        Graphic::SingleByte(encoding_rs::ISO_8859_7),
+    ),
+    (
+        b"\x1b-H",
+        false,
+        Graphic::SingleByte(encoding_rs::ISO_8859_8),
+    ),
+    (
+        b"\x1b-M",
+        false,
+        Graphic::SingleByte(encoding_rs::WINDOWS_1254),
+    ),
+    (
+        b"\x1b-b",
+        false,
+        Graphic::SingleByte(encoding_rs::ISO_8859_15),
+    ),
+    (
+        b"\x1b-T",
+        false,
+        Graphic::SingleByte(encoding_rs::WINDOWS_874),
+    ),
+    (b"\x1b$B", true, Graphic::Jis0208),
+    (b"\x1b$(D", true, Graphic::Jis0212),
+    (b"\x1b$)C", false, Graphic::Euc(encoding_rs::EUC_KR)),
+    (b"\x1b$)A", false, Graphic::Euc(encoding_rs::GBK)),
+];
+
+// decodes a run of bytes that use the same graphic set
+fn decode_run(graphic: Option<Graphic>, run: &[u8], string: &mut String) {
+    let decode = |encoding: &'static Encoding, bytes: &[u8], string: &mut String| {
+        string.push_str(&encoding.decode_without_bom_handling(bytes).0)
+    };
+    match graphic {
+        Some(Graphic::Ascii) => string.push_str(&String::from_utf8_lossy(run)),
+        Some(Graphic::Latin1) => string.extend(run.iter().map(|b| *b as char)),
+        Some(Graphic::SingleByte(encoding)) | Some(Graphic::Euc(encoding)) => {
+            decode(encoding, run, string)
+        }
+        Some(Graphic::Jis0208) => {
+            let euc: Vec<u8> = run.iter().map(|b| b | 0x80).collect();
+            decode(encoding_rs::EUC_JP, &euc, string)
+        }
+        Some(Graphic::Jis0212) => {
+            let mut euc = Vec::with_capacity(run.len() / 2 * 3);
+            for pair in run.chunks(2) {
+                euc.push(0x8F);
+                euc.extend(pair.iter().map(|b| b | 0x80));
+            }
+            decode(encoding_rs::EUC_JP, &euc, string)
+        }
+        None => string.extend(run.iter().map(|_| char::REPLACEMENT_CHARACTER)),
+    }
+}
+
+/// The character sets specified by (0008,0005) Specific Character Set.
+/// Values with multiple character sets are decoded using ISO 2022 code
+/// extensions (escape sequences).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecificCharacterSet {
+    character_sets: Vec<CharacterSet>,
+    code_extensions: bool,
+}
+
+impl Default for SpecificCharacterSet {
+    fn default() -> SpecificCharacterSet {
+        SpecificCharacterSet {
+            character_sets: vec![CharacterSet::Default],
+            code_extensions: false,
+        }
+    }
+}
+
+impl SpecificCharacterSet {
+    /// Parses the value of (0008,0005) Specific Character Set.  Returns an
+    /// error if a term is not a defined term or if multiple terms are
+    /// specified without code extensions.
+    pub fn parse(value: &str) -> Result<SpecificCharacterSet, ValueError> {
+        let invalid = || ValueError::InvalidFormat(String::from(value));
+        let value = value.trim_end_matches([' ', '\0']);
+        let mut character_sets = vec![];
+        let mut code_extensions = false;
+        for term in value.split('\\') {
+            let (character_set, extension) = CharacterSet::from_term(term).ok_or_else(invalid)?;
+            character_sets.push(character_set);
+            code_extensions |= extension;
+        }
+        if character_sets.len() > 1 && !code_extensions {
+            return Err(invalid());
+        }
+        Ok(SpecificCharacterSet {
+            character_sets,
+            code_extensions,
+        })
+    }
+
+    /// Returns the character sets in the order they were specified
+    pub fn character_sets(&self) -> &[CharacterSet] {
+        &self.character_sets
+    }
+
+    /// Decodes the value field of a text attribute to a String.  Bytes that
+    /// are not valid in the character set are replaced with U+FFFD.  The
+    /// default repertoire is decoded as UTF-8 which is a superset of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `vr`    - The VR of the attribute, the character sets are reset at
+    ///   the ^ and = delimiters of PN values
+    /// * `bytes` - The value field
+    pub fn decode(&self, vr: Option<VR>, bytes: &[u8]) -> String {
+        let first = self.character_sets[0];
+        if !self.code_extensions {
+            return match first {
+                CharacterSet::IsoIr100 => bytes.iter().map(|b| *b as char).collect(),
+                CharacterSet::IsoIr192 | CharacterSet::Default => {
+                    String::from_utf8_lossy(bytes).into_owned()
+                }
+                CharacterSet::Gb18030 => encoding_rs::GB18030
+                    .decode_without_bom_handling(bytes)
+                    .0
+                    .into_owned(),
+                CharacterSet::Gbk => encoding_rs::GBK
+                    .decode_without_bom_handling(bytes)
+                    .0
+                    .into_owned(),
+                _ => {
+                    let mut string = String::new();
+                    decode_run(first.designations().1, bytes, &mut string);
+                    string
+                }
+            };
+        }
+        self.decode_iso_2022(vr, bytes)
+    }
+
+    // decodes a value that may switch character sets with escape sequences
+    fn decode_iso_2022(&self, vr: Option<VR>, bytes: &[u8]) -> String {
+        let (initial_g0, initial_g1) = self.character_sets[0].designations();
+        let initial_g0 = initial_g0.or(Some(Graphic::Ascii));
+        let (mut g0, mut g1) = (initial_g0, initial_g1);
+        let mut string = String::with_capacity(bytes.len());
+        let mut run: Vec<u8> = vec![];
+        let mut run_graphic = g0;
+        let mut index = 0;
+        while index < bytes.len() {
+            let byte = bytes[index];
+            if byte == 0x1b {
+                decode_run(run_graphic, &run, &mut string);
+                run.clear();
+                match ESCAPE_SEQUENCES
+                    .iter()
+                    .find(|(sequence, _, _)| bytes[index..].starts_with(sequence))
+                {
+                    Some((sequence, is_g0, graphic)) => {
+                        if *is_g0 {
+                            g0 = Some(*graphic);
+                        } else {
+                            g1 = Some(*graphic);
+                        }
+                        index += sequence.len();
+                    }
+                    None => {
+                        string.push(char::REPLACEMENT_CHARACTER);
+                        index += 1;
+                    }
+                }
+                continue;
+            }
+
+            // control characters and the delimiters are in the default
+            // repertoire and reset the character sets
+            let multi_byte = matches!(g0, Some(Graphic::Jis0208) | Some(Graphic::Jis0212));
+            let delimiter = byte < 0x20
+                || (!multi_byte
+                    && (byte == b'\\' || (vr == Some(VR::PN) && (byte == b'^' || byte == b'='))));
+            let graphic = if delimiter {
+                g0 = initial_g0;
+                g1 = initial_g1;
+                Some(Graphic::Ascii)
+            } else if byte < 0x80 {
+                g0
+            } else {
+                g1
+            };
+            if graphic != run_graphic {
+                decode_run(run_graphic, &run, &mut string);
+                run.clear();
+                run_graphic = graphic;
+            }
+            run.push(byte);
+            index += 1;
+        }
+        decode_run(run_graphic, &run, &mut string);
+        string
+    }
+}
+
+/// Returns true if values of the VR are decoded using the Specific Character
+/// Set.  Other string VRs only use the default repertoire.
+pub fn uses_character_set(vr: VR) -> bool {
+    matches!(
+        vr,
+        VR::SH | VR::LO | VR::ST | VR::LT | VR::UT | VR::PN | VR::UC
+    )
+}
+
+/// Decodes the values of a SH, LO, ST, LT, UT, PN or UC attribute using the
+/// Specific Character Set.  The padding is removed and the values are split
+/// the same way as strings().
+pub fn texts(
+    attribute: &Attribute,
+    bytes: &[u8],
+    specific_character_set: &SpecificCharacterSet,
+) -> Result<Vec<String>, ValueError> {
+    if let Some(vr) = attribute.vr {
+        if !uses_character_set(vr) {
+            return Err(ValueError::InvalidVR(vr));
+        }
+    }
+    let string = specific_character_set.decode(attribute.vr, bytes);
+    Ok(split(attribute.vr, &string)
+        .into_iter()
+        .map(String::from)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tag::Tag;
+
+    fn make_attribute(vr: VR, length: usize) -> Attribute {
+        Attribute {
+            tag: Tag::new(0x0010, 0x0010),
+            vr: Some(vr),
+            length,
+            vr_inferred: false,
+        }
+    }
+
+    fn decode_pn(specific_character_set: &str, bytes: &[u8]) -> Vec<String> {
+        let specific_character_set = SpecificCharacterSet::parse(specific_character_set).unwrap();
+        texts(
+            &make_attribute(VR::PN, bytes.len()),
+            bytes,
+            &specific_character_set,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_defined_terms() {
+        let specific_character_set = SpecificCharacterSet::parse("ISO_IR 100 ").unwrap();
+        assert_eq!(
+            specific_character_set.character_sets(),
+            &[CharacterSet::IsoIr100]
+        );
+        let specific_character_set = SpecificCharacterSet::parse("\\ISO 2022 IR 87").unwrap();
+        assert_eq!(
+            specific_character_set.character_sets(),
+            &[CharacterSet::Default, CharacterSet::IsoIr87]
+        );
+        let specific_character_set = SpecificCharacterSet::parse("ISO-IR 100").unwrap();
+        assert_eq!(
+            specific_character_set.character_sets(),
+            &[CharacterSet::IsoIr100]
+        );
+        assert!(SpecificCharacterSet::parse("ISO_IR 999").is_err());
+        assert!(SpecificCharacterSet::parse("ISO_IR 87").is_err());
+        assert!(SpecificCharacterSet::parse("ISO_IR 100\\ISO_IR 144").is_err());
+    }
+
+    #[test]
+    fn decodes_single_byte_character_sets() {
+        assert_eq!(
+            decode_pn("ISO_IR 100", b"Buc^J\xe9r\xf4me"),
+            vec!["Buc^Jérôme"]
+        );
+        assert_eq!(
+            decode_pn("ISO_IR 126", b"\xc4\xe9\xef\xed\xf5\xf3\xe9\xef\xf2"),
+            vec!["Διονυσιος"]
+        );
+        assert_eq!(
+            decode_pn("ISO_IR 144", b"\xbb\xee\xda\xe1\xd5\xdc\xd1\xe3\xe0\xd3"),
+            vec!["Люксембург"]
+        );
+        assert_eq!(
+            decode_pn("ISO_IR 13", b"\xd4\xcf\xc0\xde^\xc0\xdb\xb3"),
+            vec!["ﾔﾏﾀﾞ^ﾀﾛｳ"]
+        );
+    }
+
+    #[test]
+    fn decodes_unicode_and_chinese() {
+        assert_eq!(
+            decode_pn("ISO_IR 192", "Wang^XiaoDong=王^小东".as_bytes()),
+            vec!["Wang^XiaoDong=王^小东"]
+        );
+        assert_eq!(
+            decode_pn("GB18030", b"Wang^XiaoDong=\xcd\xf5^\xd0\xa1\xb6\xab"),
+            vec!["Wang^XiaoDong=王^小东"]
+        );
+    }
+
+    #[test]
+    fn decodes_iso_2022_japanese() {
+        let bytes =
+            b"Yamada^Tarou=\x1b$B;3ED\x1b(B^\x1b$BB@O:\x1b(B=\x1b$B$d$^$@\x1b(B^\x1b$B$?$m$&\x1b(B";
+        assert_eq!(
+            decode_pn("\\ISO 2022 IR 87", bytes),
+            vec!["Yamada^Tarou=山田^太郎=やまだ^たろう"]
+        );
+    }
+
+    #[test]
+    fn decodes_iso_2022_korean() {
+        let bytes = b"Hong^Gildong=\x1b$)C\xfb\xf3^\x1b$)C\xd1\xce\xd4\xd7=\x1b$)C\xc8\xab^\x1b$)C\xb1\xe6\xb5\xbf";
+        assert_eq!(
+            decode_pn("\\ISO 2022 IR 149", bytes),
+            vec!["Hong^Gildong=洪^吉洞=홍^길동"]
+        );
+    }
+
+    #[test]
+    fn delimiters_reset_the_character_sets() {
+        // G1 is switched to Greek then reset to Latin-1 by the backslash
+        let bytes = b"\x1b-F\xc4\\\xc4";
+        assert_eq!(
+            decode_pn("ISO 2022 IR 100\\ISO 2022 IR 126", bytes),
+            vec!["Δ", "Ä"]
+        );
+    }
+
+    #[test]
+    fn invalid_bytes_are_replaced() {
+        assert_eq!(decode_pn("", b"A\xff"), vec!["A\u{FFFD}"]);
+        assert_eq!(
+            decode_pn("\\ISO 2022 IR 87", b"A\x1b%G"),
+            vec!["A\u{FFFD}%G"]
+        );
+    }
+
+    #[test]
+    fn texts_only_decodes_text_vrs() {
+        assert_eq!(
+            texts(
+                &make_attribute(VR::UI, 4),
+                b"1.2\0",
+                &SpecificCharacterSet::default()
+            ),
+            Err(ValueError::InvalidVR(VR::UI))
+        );
+    }
+}
@@ -0,0 +1,575 @@
+use crate::value::ValueError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A DA value.  The month and day are optional to support the partial
+/// precision forms (YYYY and YYYYMM) used in queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DicomDate {
+    pub year: u16,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+}
+
+/// A TM value.  The minute, second and fraction are optional to support the
+/// partial precision forms (HH, HHMM and HHMMSS).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DicomTime {
+    pub hour: u8,
+    pub minute: Option<u8>,
+    pub second: Option<u8>,
+    /// The fractional seconds in microseconds
+    pub microsecond: Option<u32>,
+}
+
+/// A DT value.  The time and UTC offset are optional.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DicomDateTime {
+    pub date: DicomDate,
+    pub time: Option<DicomTime>,
+    /// The offset from UTC in minutes
+    pub offset: Option<i16>,
+}
+
+fn invalid(value: &str) -> ValueError {
+    ValueError::InvalidFormat(String::from(value))
+}
+
+// parses a string of ASCII digits
+fn digits(value: &str) -> Option<u32> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl DicomDate {
+    /// Parses a DA value in the YYYYMMDD, YYYYMM or YYYY forms or the
+    /// ACR-NEMA YYYY.MM.DD form
+    pub fn parse(value: &str) -> Result<DicomDate, ValueError> {
+        let string = value.trim();
+        // the components are sliced by byte position
+        if !string.is_ascii() {
+            return Err(invalid(value));
+        }
+        let bytes = string.as_bytes();
+        let parts = if bytes.len() == 10 && bytes[4] == b'.' && bytes[7] == b'.' {
+            // ACR-NEMA
+            [&string[0..4], &string[5..7], &string[8..10]]
+        } else {
+            match bytes.len() {
+                4 => [string, "", ""],
+                6 => [&string[0..4], &string[4..6], ""],
+                8 => [&string[0..4], &string[4..6], &string[6..8]],
+                _ => return Err(invalid(value)),
+            }
+        };
+
+        let year = digits(parts[0]).ok_or_else(|| invalid(value))? as u16;
+        let optional = |part: &str| -> Result<Option<u8>, ValueError> {
+            if part.is_empty() {
+                return Ok(None);
+            }
+            match digits(part) {
+                Some(number) => Ok(Some(number as u8)),
+                None => Err(invalid(value)),
+            }
+        };
+        let date = DicomDate {
+            year,
+            month: optional(parts[1])?,
+            day: optional(parts[2])?,
+        };
+
+        if let Some(month) = date.month {
+            if !(1..=12).contains(&month) {
+                return Err(invalid(value));
+            }
+            if let Some(day) = date.day {
+                if day < 1 || day > days_in_month(year, month) {
+                    return Err(invalid(value));
+                }
+            }
+        }
+        Ok(date)
+    }
+}
+
+impl DicomTime {
+    /// Parses a TM value in the HHMMSS.FFFFFF form (with any of the trailing
+    /// components omitted) or the ACR-NEMA HH:MM:SS.FFFFFF form
+    pub fn parse(value: &str) -> Result<DicomTime, ValueError> {
+        let string = value.trim();
+        let (main, fraction) = match string.find('.') {
+            Some(index) => (&string[..index], Some(&string[index + 1..])),
+            None => (string, None),
+        };
+
+        let parts: Vec<&str> = if main.contains(':') {
+            // ACR-NEMA
+            main.split(':').collect()
+        } else if main.len() % 2 == 0 {
+            (0..main.len())
+                .step_by(2)
+                .filter_map(|index| main.get(index..index + 2))
+                .collect()
+        } else {
+            return Err(invalid(value));
+        };
+        if parts.is_empty() || parts.len() > 3 || parts.iter().any(|part| part.len() != 2) {
+            return Err(invalid(value));
+        }
+
+        let mut numbers = [None; 3];
+        for (number, part) in numbers.iter_mut().zip(parts.iter()) {
+            *number = Some(digits(part).ok_or_else(|| invalid(value))? as u8);
+        }
+
+        // fractional seconds require seconds
+        let microsecond = match fraction {
+            Some(fraction) if parts.len() == 3 && fraction.len() <= 6 => {
+                let number = digits(fraction).ok_or_else(|| invalid(value))?;
+                Some(number * 10u32.pow(6 - fraction.len() as u32))
+            }
+            Some(_) => return Err(invalid(value)),
+            None => None,
+        };
+
+        let time = DicomTime {
+            hour: numbers[0].unwrap(),
+            minute: numbers[1],
+            second: numbers[2],
+            microsecond,
+        };
+        // a second of 60 is allowed for leap seconds
+        if time.hour > 23 || time.minute.unwrap_or(0) > 59 || time.second.unwrap_or(0) > 60 {
+            return Err(invalid(value));
+        }
+        Ok(time)
+    }
+}
+
+impl DicomDateTime {
+    /// Parses a DT value in the YYYYMMDDHHMMSS.FFFFFF&ZZXX form with any of
+    /// the trailing date and time components and the UTC offset omitted
+    pub fn parse(value: &str) -> Result<DicomDateTime, ValueError> {
+        let string = value.trim();
+        // the components are sliced by byte position
+        if !string.is_ascii() {
+            return Err(invalid(value));
+        }
+
+        // the UTC offset is the last 5 characters if they start with + or -
+        let suffix = string
+            .len()
+            .checked_sub(5)
+            .filter(|index| *index >= 4)
+            .and_then(|index| string.get(index..).map(|suffix| (index, suffix)));
+        let (string, offset) = match suffix {
+            Some((index, suffix)) if suffix.starts_with('+') || suffix.starts_with('-') => {
+                let hours = suffix.get(1..3).and_then(digits);
+                let minutes = suffix.get(3..).and_then(digits);
+                let (hours, minutes) = match (hours, minutes) {
+                    (Some(hours), Some(minutes)) if hours <= 14 && minutes <= 59 => {
+                        (hours, minutes)
+                    }
+                    _ => return Err(invalid(value)),
+                };
+                let offset = (hours * 60 + minutes) as i16;
+                let offset = if suffix.starts_with('-') {
+                    -offset
+                } else {
+                    offset
+                };
+                (&string[..index], Some(offset))
+            }
+            _ => (string, None),
+        };
+
+        let main_length = string.find('.').unwrap_or(string.len());
+        let date_length = main_length.min(8);
+        let date = DicomDate::parse(&string[..date_length])?;
+        let time = if main_length > 8 {
+            if date.day.is_none() {
+                return Err(invalid(value));
+            }
+            Some(DicomTime::parse(&string[8..])?)
+        } else if main_length < string.len() {
+            // fractional seconds without a time
+            return Err(invalid(value));
+        } else {
+            None
+        };
+
+        Ok(DicomDateTime { date, time, offset })
+    }
+}
+
+impl FromStr for DicomDate {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<DicomDate, ValueError> {
+        DicomDate::parse(value)
+    }
+}
+
+impl FromStr for DicomTime {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<DicomTime, ValueError> {
+        DicomTime::parse(value)
+    }
+}
+
+impl FromStr for DicomDateTime {
+    type Err = ValueError;
+
+    fn from_str(value: &str) -> Result<DicomDateTime, ValueError> {
+        DicomDateTime::parse(value)
+    }
+}
+
+impl fmt::Display for DicomDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DicomTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}", self.hour)?;
+        if let Some(minute) = self.minute {
+            write!(f, "{:02}", minute)?;
+        }
+        if let Some(second) = self.second {
+            write!(f, "{:02}", second)?;
+        }
+        if let Some(microsecond) = self.microsecond {
+            let fraction = format!("{:06}", microsecond);
+            let fraction = fraction.trim_end_matches('0');
+            write!(f, ".{}", if fraction.is_empty() { "0" } else { fraction })?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DicomDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.date)?;
+        if let Some(time) = self.time {
+            write!(f, "{}", time)?;
+        }
+        if let Some(offset) = self.offset {
+            let sign = if offset < 0 { '-' } else { '+' };
+            let offset = offset.abs();
+            write!(f, "{}{:02}{:02}", sign, offset / 60, offset % 60)?;
+        }
+        Ok(())
+    }
+}
+
+/// Implemented by values that can be matched with a DicomRange.  A partial
+/// precision value covers a period of time (e.g. 2020 is all of the year
+/// 2020), these functions return the comparable keys for the beginning and
+/// end of that period
+pub trait RangeBound {
+    type Key: Ord;
+
+    /// Returns the key for the beginning of the period covered by the value
+    fn earliest(&self) -> Self::Key;
+    /// Returns the key for the end of the period covered by the value
+    fn latest(&self) -> Self::Key;
+}
+
+impl RangeBound for DicomDate {
+    type Key = (u16, u8, u8);
+
+    fn earliest(&self) -> (u16, u8, u8) {
+        (self.year, self.month.unwrap_or(1), self.day.unwrap_or(1))
+    }
+
+    fn latest(&self) -> (u16, u8, u8) {
+        let month = self.month.unwrap_or(12);
+        let day = self.day.unwrap_or_else(|| days_in_month(self.year, month));
+        (self.year, month, day)
+    }
+}
+
+impl RangeBound for DicomTime {
+    type Key = (u8, u8, u8, u32);
+
+    fn earliest(&self) -> (u8, u8, u8, u32) {
+        (
+            self.hour,
+            self.minute.unwrap_or(0),
+            self.second.unwrap_or(0),
+            self.microsecond.unwrap_or(0),
+        )
+    }
+
+    fn latest(&self) -> (u8, u8, u8, u32) {
+        (
+            self.hour,
+            self.minute.unwrap_or(59),
+            self.second.unwrap_or(59),
+            self.microsecond.unwrap_or(999_999),
+        )
+    }
+}
+
+/// The UTC offset is not applied when comparing DicomDateTime values
+impl RangeBound for DicomDateTime {
+    type Key = ((u16, u8, u8), (u8, u8, u8, u32));
+
+    fn earliest(&self) -> Self::Key {
+        let time = self.time.map_or((0, 0, 0, 0), |time| time.earliest());
+        (self.date.earliest(), time)
+    }
+
+    fn latest(&self) -> Self::Key {
+        let time = self
+            .time
+            .map_or((23, 59, 59, 999_999), |time| time.latest());
+        (self.date.latest(), time)
+    }
+}
+
+/// A range of DA, TM or DT values as used in queries (e.g. 20200101-20201231).
+/// Either the start or the end may be omitted (e.g. 20200101- or -20201231).
+/// A single value is a range that starts and ends with that value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DicomRange<T> {
+    pub start: Option<T>,
+    pub end: Option<T>,
+}
+
+impl<T: FromStr<Err = ValueError> + RangeBound + Copy> DicomRange<T> {
+    /// Parses a range of values.  The hyphen separating the start and the end
+    /// is found by trying each one since DT values may include a negative UTC
+    /// offset
+    pub fn parse(value: &str) -> Result<DicomRange<T>, ValueError> {
+        let string = value.trim();
+        for (index, _) in string.match_indices('-') {
+            let start = &string[..index];
+            let end = &string[index + 1..];
+            if start.is_empty() && end.is_empty() {
+                break;
+            }
+            let start = if start.is_empty() {
+                None
+            } else {
+                match start.parse() {
+                    Ok(start) => Some(start),
+                    Err(_) => continue,
+                }
+            };
+            let end = if end.is_empty() {
+                None
+            } else {
+                match end.parse() {
+                    Ok(end) => Some(end),
+                    Err(_) => continue,
+                }
+            };
+            return Ok(DicomRange { start, end });
+        }
+
+        // not a range, just a single value
+        let single: T = string.parse().map_err(|_| invalid(value))?;
+        Ok(DicomRange {
+            start: Some(single),
+            end: Some(single),
+        })
+    }
+
+    /// Returns true if the period covered by the value is within the range
+    pub fn contains(&self, value: &T) -> bool {
+        let after_start = match &self.start {
+            Some(start) => start.earliest() <= value.earliest(),
+            None => true,
+        };
+        let before_end = match &self.end {
+            Some(end) => value.latest() <= end.latest(),
+            None => true,
+        };
+        after_start && before_end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DicomDate, DicomDateTime, DicomRange, DicomTime};
+
+    fn date(year: u16, month: Option<u8>, day: Option<u8>) -> DicomDate {
+        DicomDate { year, month, day }
+    }
+
+    #[test]
+    fn date_parses_full_and_partial_forms() {
+        assert_eq!(
+            DicomDate::parse("20200229").unwrap(),
+            date(2020, Some(2), Some(29))
+        );
+        assert_eq!(
+            DicomDate::parse("202002").unwrap(),
+            date(2020, Some(2), None)
+        );
+        assert_eq!(DicomDate::parse("2020").unwrap(), date(2020, None, None));
+        assert_eq!(
+            DicomDate::parse("2020.02.29").unwrap(),
+            date(2020, Some(2), Some(29))
+        );
+    }
+
+    #[test]
+    fn invalid_dates_return_error() {
+        assert!(DicomDate::parse("20190229").is_err());
+        assert!(DicomDate::parse("20201301").is_err());
+        assert!(DicomDate::parse("20200100").is_err());
+        assert!(DicomDate::parse("2020010").is_err());
+        assert!(DicomDate::parse("2020a101").is_err());
+        assert!(DicomDate::parse("").is_err());
+    }
+
+    #[test]
+    fn time_parses_full_and_partial_forms() {
+        let time = DicomTime::parse("070907.0705").unwrap();
+        assert_eq!(time.hour, 7);
+        assert_eq!(time.minute, Some(9));
+        assert_eq!(time.second, Some(7));
+        assert_eq!(time.microsecond, Some(70500));
+        let time = DicomTime::parse("1010").unwrap();
+        assert_eq!(time.minute, Some(10));
+        assert_eq!(time.second, None);
+        let time = DicomTime::parse("10").unwrap();
+        assert_eq!(time.minute, None);
+        let time = DicomTime::parse("23:59:60.5").unwrap();
+        assert_eq!(time.second, Some(60));
+        assert_eq!(time.microsecond, Some(500000));
+    }
+
+    #[test]
+    fn invalid_times_return_error() {
+        assert!(DicomTime::parse("2400").is_err());
+        assert!(DicomTime::parse("1260").is_err());
+        assert!(DicomTime::parse("123").is_err());
+        assert!(DicomTime::parse("1230.5").is_err());
+        assert!(DicomTime::parse("123000.1234567").is_err());
+        assert!(DicomTime::parse("12:3").is_err());
+    }
+
+    #[test]
+    fn date_time_parses_with_offset() {
+        let date_time = DicomDateTime::parse("20200102030405.123456-0500").unwrap();
+        assert_eq!(date_time.date, date(2020, Some(1), Some(2)));
+        assert_eq!(
+            date_time.time,
+            Some(DicomTime {
+                hour: 3,
+                minute: Some(4),
+                second: Some(5),
+                microsecond: Some(123456),
+            })
+        );
+        assert_eq!(date_time.offset, Some(-300));
+        let date_time = DicomDateTime::parse("2020+0130").unwrap();
+        assert_eq!(date_time.date, date(2020, None, None));
+        assert_eq!(date_time.time, None);
+        assert_eq!(date_time.offset, Some(90));
+    }
+
+    #[test]
+    fn invalid_date_times_return_error() {
+        assert!(DicomDateTime::parse("202001010").is_err());
+        assert!(DicomDateTime::parse("2020.5").is_err());
+        assert!(DicomDateTime::parse("20200101+1500").is_err());
+    }
+
+    #[test]
+    fn non_ascii_values_return_error() {
+        assert!(DicomDate::parse("200é1234").is_err());
+        assert!(DicomDate::parse("2020.é.01").is_err());
+        assert!(DicomTime::parse("12é4").is_err());
+        assert!(DicomDateTime::parse("200é1234").is_err());
+        assert!(DicomDateTime::parse("20200101é23").is_err());
+        assert!(DicomDateTime::parse("2020010112+é100").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for value in &["20200102", "2020", "202001"] {
+            assert_eq!(DicomDate::parse(value).unwrap().to_string(), *value);
+        }
+        for value in &["07", "0709", "070907.0705", "235960.0"] {
+            assert_eq!(DicomTime::parse(value).unwrap().to_string(), *value);
+        }
+        for value in &["20200102030405.1-0500", "2020", "2020010203+0000"] {
+            assert_eq!(DicomDateTime::parse(value).unwrap().to_string(), *value);
+        }
+    }
+
+    #[test]
+    fn date_range_contains() {
+        let range = DicomRange::<DicomDate>::parse("20200101-20201231").unwrap();
+        assert!(range.contains(&DicomDate::parse("20200615").unwrap()));
+        assert!(range.contains(&DicomDate::parse("2020").unwrap()));
+        assert!(!range.contains(&DicomDate::parse("20210101").unwrap()));
+        let range = DicomRange::<DicomDate>::parse("-2019").unwrap();
+        assert_eq!(range.start, None);
+        assert!(range.contains(&DicomDate::parse("20191231").unwrap()));
+        let range = DicomRange::<DicomDate>::parse("20200101-").unwrap();
+        assert_eq!(range.end, None);
+        assert!(range.contains(&DicomDate::parse("30000101").unwrap()));
+    }
+
+    #[test]
+    fn single_value_range_matches_its_period() {
+        let range = DicomRange::<DicomDate>::parse("202002").unwrap();
+        assert!(range.contains(&DicomDate::parse("20200229").unwrap()));
+        assert!(!range.contains(&DicomDate::parse("20200301").unwrap()));
+    }
+
+    #[test]
+    fn time_range_contains() {
+        let range = DicomRange::<DicomTime>::parse("08-1230").unwrap();
+        assert!(range.contains(&DicomTime::parse("080000").unwrap()));
+        assert!(range.contains(&DicomTime::parse("123059.999").unwrap()));
+        assert!(!range.contains(&DicomTime::parse("1231").unwrap()));
+    }
+
+    #[test]
+    fn date_time_range_with_negative_offsets() {
+        let range =
+            DicomRange::<DicomDateTime>::parse("20200101-0500-20200102120000-0500").unwrap();
+        assert_eq!(range.start.unwrap().offset, Some(-300));
+        assert_eq!(range.end.unwrap().offset, Some(-300));
+        assert!(!range.contains(&DicomDateTime::parse("20200102").unwrap()));
+        assert!(range.contains(&DicomDateTime::parse("2020010206").unwrap()));
+    }
+
+    #[test]
+    fn invalid_range_returns_error() {
+        assert!(DicomRange::<DicomDate>::parse("-").is_err());
+        assert!(DicomRange::<DicomDate>::parse("2020-20a1").is_err());
+    }
+}
@@ -0,0 +1,350 @@
+//! Helpers to decode the value field of an attribute into rust types.  The
+//! bytes must be the complete value field (see AccumulatingHandler).
+//! Attributes parsed with an implicit VR encoding have no VR and are decoded
+//! as the type requested by the caller.
+
+use crate::attribute::Attribute;
+use crate::encoding::Encoding;
+use crate::tag::Tag;
+use crate::vr::VR;
+use std::fmt;
+use std::str;
+
+pub mod character_set;
+pub mod date_time;
+pub mod number;
+pub mod person_name;
+
+/// Error returned when a value cannot be decoded
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueError {
+    /// The VR of the attribute cannot be decoded as the requested type
+    InvalidVR(VR),
+    /// The length of the value field is not a multiple of the size of the
+    /// requested type
+    InvalidLength(usize),
+    /// The value field of a string VR is not valid UTF-8
+    InvalidString,
+    /// The value does not have the format required by its VR
+    InvalidFormat(String),
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueError::InvalidVR(vr) => write!(f, "VR {:?} cannot be decoded as this type", vr),
+            ValueError::InvalidLength(length) => write!(f, "invalid value length {}", length),
+            ValueError::InvalidString => write!(f, "invalid string value"),
+            ValueError::InvalidFormat(value) => write!(f, "invalid value format '{}'", value),
+        }
+    }
+}
+
+impl std::error::Error for ValueError {}
+
+// checks the VR of the attribute is one of the expected VRs.  Attributes
+// without a VR (implicit VR encodings) are always accepted
+fn check_vr(attribute: &Attribute, vrs: &[VR]) -> Result<(), ValueError> {
+    match attribute.vr {
+        Some(vr) if !vrs.contains(&vr) => Err(ValueError::InvalidVR(vr)),
+        _ => Ok(()),
+    }
+}
+
+// returns an iterator that decodes each size bytes of the value field
+fn numbers<'a, N: 'a>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+    vrs: &[VR],
+    size: usize,
+    decode: fn(&[u8]) -> N,
+) -> Result<impl Iterator<Item = N> + 'a, ValueError> {
+    check_vr(attribute, vrs)?;
+    if bytes.len() % size != 0 {
+        return Err(ValueError::InvalidLength(bytes.len()));
+    }
+    Ok(bytes.chunks_exact(size).map(decode))
+}
+
+/// Decodes the values of a US or OW attribute
+pub fn u16s<'a, T: Encoding>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<impl Iterator<Item = u16> + 'a, ValueError> {
+    numbers(attribute, bytes, &[VR::US, VR::OW], 2, T::u16)
+}
+
+/// Decodes the values of a SS attribute
+pub fn i16s<'a, T: Encoding>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<impl Iterator<Item = i16> + 'a, ValueError> {
+    numbers(attribute, bytes, &[VR::SS], 2, |x| T::u16(x) as i16)
+}
+
+/// Decodes the values of a UL or OL attribute
+pub fn u32s<'a, T: Encoding>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<impl Iterator<Item = u32> + 'a, ValueError> {
+    numbers(attribute, bytes, &[VR::UL, VR::OL], 4, T::u32)
+}
+
+/// Decodes the values of a SL attribute
+pub fn i32s<'a, T: Encoding>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<impl Iterator<Item = i32> + 'a, ValueError> {
+    numbers(attribute, bytes, &[VR::SL], 4, |x| T::u32(x) as i32)
+}
+
+/// Decodes the values of a UV or OV attribute
+pub fn u64s<'a, T: Encoding>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<impl Iterator<Item = u64> + 'a, ValueError> {
+    numbers(attribute, bytes, &[VR::UV, VR::OV], 8, T::u64)
+}
+
+/// Decodes the values of a SV attribute
+pub fn i64s<'a, T: Encoding>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<impl Iterator<Item = i64> + 'a, ValueError> {
+    numbers(attribute, bytes, &[VR::SV], 8, |x| T::u64(x) as i64)
+}
+
+/// Decodes the values of a FL or OF attribute
+pub fn f32s<'a, T: Encoding>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<impl Iterator<Item = f32> + 'a, ValueError> {
+    numbers(attribute, bytes, &[VR::FL, VR::OF], 4, |x| {
+        f32::from_bits(T::u32(x))
+    })
+}
+
+/// Decodes the values of a FD or OD attribute
+pub fn f64s<'a, T: Encoding>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<impl Iterator<Item = f64> + 'a, ValueError> {
+    numbers(attribute, bytes, &[VR::FD, VR::OD], 8, |x| {
+        f64::from_bits(T::u64(x))
+    })
+}
+
+/// Decodes the values of an AT attribute
+pub fn tags<'a, T: Encoding>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<impl Iterator<Item = Tag> + 'a, ValueError> {
+    numbers(attribute, bytes, &[VR::AT], 4, Tag::from_bytes::<T>)
+}
+
+/// Returns true if the VR is a string VR
+pub fn is_string_vr(vr: VR) -> bool {
+    matches!(
+        vr,
+        VR::AE
+            | VR::AS
+            | VR::CS
+            | VR::DA
+            | VR::DS
+            | VR::DT
+            | VR::IS
+            | VR::LO
+            | VR::LT
+            | VR::PN
+            | VR::SH
+            | VR::ST
+            | VR::TM
+            | VR::UC
+            | VR::UI
+            | VR::UR
+            | VR::UT
+    )
+}
+
+/// Decodes the values of a string attribute.  The padding at the end of the
+/// value field (NUL for UI, space for the others) is removed and the value
+/// field is split into its values on backslash except for LT, ST, UT and UR
+/// which have a single value.  Leading and trailing spaces are removed from
+/// each value except for LT, ST and UT where leading spaces are significant.
+pub fn strings<'a>(attribute: &Attribute, bytes: &'a [u8]) -> Result<Vec<&'a str>, ValueError> {
+    if let Some(vr) = attribute.vr {
+        if !is_string_vr(vr) {
+            return Err(ValueError::InvalidVR(vr));
+        }
+    }
+    let string = str::from_utf8(bytes).map_err(|_| ValueError::InvalidString)?;
+    Ok(split(attribute.vr, string))
+}
+
+// removes the padding from a decoded string value and splits it into its
+// values (see strings())
+fn split(vr: Option<VR>, string: &str) -> Vec<&str> {
+    let padding = if vr == Some(VR::UI) { '\0' } else { ' ' };
+    let string = string.trim_end_matches(padding);
+    if string.is_empty() {
+        return vec![];
+    }
+
+    match vr {
+        Some(VR::LT) | Some(VR::ST) | Some(VR::UT) => vec![string],
+        Some(VR::UR) => vec![string.trim_matches(' ')],
+        _ => string.split('\\').map(|x| x.trim_matches(' ')).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::{ExplicitBigEndian, ExplicitLittleEndian};
+
+    fn make_attribute(vr: VR, length: usize) -> Attribute {
+        Attribute {
+            tag: Tag::new(0x0028, 0x0010),
+            vr: Some(vr),
+            length,
+            vr_inferred: false,
+        }
+    }
+
+    #[test]
+    fn u16s_decodes_both_endians() {
+        let bytes = [1, 2, 3, 4];
+        let attribute = make_attribute(VR::US, bytes.len());
+        let values: Vec<u16> = u16s::<ExplicitLittleEndian>(&attribute, &bytes)
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![0x0201, 0x0403]);
+        let values: Vec<u16> = u16s::<ExplicitBigEndian>(&attribute, &bytes)
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![0x0102, 0x0304]);
+    }
+
+    #[test]
+    fn signed_values_decode() {
+        let attribute = make_attribute(VR::SS, 2);
+        let values: Vec<i16> = i16s::<ExplicitLittleEndian>(&attribute, &[0xFE, 0xFF])
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![-2]);
+        let attribute = make_attribute(VR::SL, 4);
+        let values: Vec<i32> = i32s::<ExplicitLittleEndian>(&attribute, &[0xFF; 4])
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![-1]);
+        let attribute = make_attribute(VR::SV, 8);
+        let values: Vec<i64> = i64s::<ExplicitBigEndian>(&attribute, &[0xFF; 8])
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![-1]);
+    }
+
+    #[test]
+    fn unsigned_values_decode() {
+        let attribute = make_attribute(VR::OL, 8);
+        let bytes = [1, 0, 0, 0, 2, 0, 0, 0];
+        let values: Vec<u32> = u32s::<ExplicitLittleEndian>(&attribute, &bytes)
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![1, 2]);
+        let attribute = make_attribute(VR::UV, 8);
+        let values: Vec<u64> = u64s::<ExplicitLittleEndian>(&attribute, &bytes)
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![0x0000_0002_0000_0001]);
+    }
+
+    #[test]
+    fn floats_decode() {
+        let attribute = make_attribute(VR::FL, 4);
+        let bytes = 1.5f32.to_le_bytes();
+        let values: Vec<f32> = f32s::<ExplicitLittleEndian>(&attribute, &bytes)
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![1.5]);
+        let attribute = make_attribute(VR::OD, 8);
+        let bytes = (-2.25f64).to_be_bytes();
+        let values: Vec<f64> = f64s::<ExplicitBigEndian>(&attribute, &bytes)
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![-2.25]);
+    }
+
+    #[test]
+    fn tags_decode() {
+        let attribute = make_attribute(VR::AT, 4);
+        let values: Vec<Tag> = tags::<ExplicitLittleEndian>(&attribute, &[0x20, 0x00, 0x0D, 0x00])
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![Tag::new(0x0020, 0x000D)]);
+    }
+
+    #[test]
+    fn wrong_vr_returns_error() {
+        let attribute = make_attribute(VR::FL, 4);
+        assert_eq!(
+            u16s::<ExplicitLittleEndian>(&attribute, &[0; 4]).err(),
+            Some(ValueError::InvalidVR(VR::FL))
+        );
+        assert_eq!(
+            strings(&attribute, &[0; 4]),
+            Err(ValueError::InvalidVR(VR::FL))
+        );
+    }
+
+    #[test]
+    fn wrong_length_returns_error() {
+        let attribute = make_attribute(VR::UL, 3);
+        assert_eq!(
+            u32s::<ExplicitLittleEndian>(&attribute, &[0; 3]).err(),
+            Some(ValueError::InvalidLength(3))
+        );
+    }
+
+    #[test]
+    fn implicit_vr_decodes_as_requested_type() {
+        let mut attribute = make_attribute(VR::US, 2);
+        attribute.vr = None;
+        let values: Vec<u16> = u16s::<ExplicitLittleEndian>(&attribute, &[2, 0])
+            .unwrap()
+            .collect();
+        assert_eq!(values, vec![2]);
+    }
+
+    #[test]
+    fn strings_split_and_trim() {
+        let attribute = make_attribute(VR::CS, 16);
+        assert_eq!(
+            strings(&attribute, b"ORIGINAL\\ PRIMARY ").unwrap(),
+            vec!["ORIGINAL", "PRIMARY"]
+        );
+        assert_eq!(strings(&attribute, b"  ").unwrap(), Vec::<&str>::new());
+        assert_eq!(strings(&attribute, b"A\\\\B").unwrap(), vec!["A", "", "B"]);
+    }
+
+    #[test]
+    fn ui_padding_is_nul() {
+        let attribute = make_attribute(VR::UI, 8);
+        assert_eq!(strings(&attribute, b"1.2.840\0").unwrap(), vec!["1.2.840"]);
+    }
+
+    #[test]
+    fn text_is_not_split() {
+        let attribute = make_attribute(VR::LT, 10);
+        assert_eq!(strings(&attribute, b"  a\\b    ").unwrap(), vec!["  a\\b"]);
+    }
+
+    #[test]
+    fn invalid_utf8_returns_error() {
+        let attribute = make_attribute(VR::LO, 2);
+        assert_eq!(
+            strings(&attribute, &[0xFF, 0xFE]),
+            Err(ValueError::InvalidString)
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use crate::attribute::Attribute;
+use crate::value::{check_vr, strings, ValueError};
+use crate::vr::VR;
+
+/// The maximum length of a DS value
+const DS_MAX_LENGTH: usize = 16;
+
+/// The maximum length of an IS value
+const IS_MAX_LENGTH: usize = 12;
+
+/// Controls how DS and IS values are parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Only values conforming to PS3.5 are accepted
+    Strict,
+    /// Values that are commonly found in the wild are also accepted: any
+    /// length, surrounding whitespace and NUL, a comma as the decimal
+    /// separator and (for IS) integral decimal values such as "12.0"
+    Lenient,
+}
+
+fn invalid(value: &str) -> ValueError {
+    ValueError::InvalidFormat(String::from(value))
+}
+
+// returns true if the string is a PS3.5 decimal number:
+// [+-]? (digits [. digits?] | . digits) ([eE] [+-]? digits)?
+fn is_decimal(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut index = 0;
+    let digits = |index: &mut usize| {
+        let start = *index;
+        while *index < bytes.len() && bytes[*index].is_ascii_digit() {
+            *index += 1;
+        }
+        *index - start
+    };
+
+    if index < bytes.len() && (bytes[index] == b'+' || bytes[index] == b'-') {
+        index += 1;
+    }
+    let mut mantissa_digits = digits(&mut index);
+    if index < bytes.len() && bytes[index] == b'.' {
+        index += 1;
+        mantissa_digits += digits(&mut index);
+    }
+    if mantissa_digits == 0 {
+        return false;
+    }
+    if index < bytes.len() && (bytes[index] == b'e' || bytes[index] == b'E') {
+        index += 1;
+        if index < bytes.len() && (bytes[index] == b'+' || bytes[index] == b'-') {
+            index += 1;
+        }
+        if digits(&mut index) == 0 {
+            return false;
+        }
+    }
+    index == bytes.len()
+}
+
+// returns true if the string is a PS3.5 integer number: [+-]? digits
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+// removes the leading and trailing characters allowed in lenient mode
+fn lenient_trim(value: &str) -> &str {
+    value.trim_matches(|c: char| c.is_whitespace() || c == '\0')
+}
+
+/// Parses a single DS value
+pub fn parse_ds(value: &str, mode: Mode) -> Result<f64, ValueError> {
+    let number = match mode {
+        Mode::Strict => {
+            if value.len() > DS_MAX_LENGTH {
+                return Err(invalid(value));
+            }
+            value.trim_matches(' ').to_string()
+        }
+        Mode::Lenient => lenient_trim(value).replace(',', "."),
+    };
+    if !is_decimal(&number) {
+        return Err(invalid(value));
+    }
+    let number: f64 = number.parse().map_err(|_| invalid(value))?;
+    if !number.is_finite() {
+        return Err(invalid(value));
+    }
+    Ok(number)
+}
+
+/// Parses a single IS value.  Strict mode requires the value to be in the
+/// range -2^31 to 2^31-1 as specified in PS3.5
+pub fn parse_is(value: &str, mode: Mode) -> Result<i64, ValueError> {
+    match mode {
+        Mode::Strict => {
+            let number = value.trim_matches(' ');
+            if value.len() > IS_MAX_LENGTH || !is_integer(number) {
+                return Err(invalid(value));
+            }
+            let number: i64 = number.parse().map_err(|_| invalid(value))?;
+            if number < i64::from(i32::MIN) || number > i64::from(i32::MAX) {
+                return Err(invalid(value));
+            }
+            Ok(number)
+        }
+        Mode::Lenient => {
+            let number = lenient_trim(value);
+            if is_integer(number) {
+                return number.parse().map_err(|_| invalid(value));
+            }
+            // accept decimal values without a fractional part
+            let decimal = parse_ds(number, mode)?;
+            if decimal.fract() != 0.0 || decimal.abs() >= i64::MAX as f64 {
+                return Err(invalid(value));
+            }
+            Ok(decimal as i64)
+        }
+    }
+}
+
+/// Decodes the values of a DS attribute (e.g. PixelSpacing).  Empty values
+/// are an error as they have no numeric value.
+pub fn decimal_strings(
+    attribute: &Attribute,
+    bytes: &[u8],
+    mode: Mode,
+) -> Result<Vec<f64>, ValueError> {
+    check_vr(attribute, &[VR::DS])?;
+    strings(attribute, bytes)?
+        .iter()
+        .map(|value| parse_ds(value, mode))
+        .collect()
+}
+
+/// Decodes the values of an IS attribute.  Empty values are an error as
+/// they have no numeric value.
+pub fn integer_strings(
+    attribute: &Attribute,
+    bytes: &[u8],
+    mode: Mode,
+) -> Result<Vec<i64>, ValueError> {
+    check_vr(attribute, &[VR::IS])?;
+    strings(attribute, bytes)?
+        .iter()
+        .map(|value| parse_is(value, mode))
+        .collect()
+}
+
+// removes trailing zeros from the fractional part of a number
+fn trim_fraction(value: &str) -> &str {
+    if value.contains('.') {
+        value.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        value
+    }
+}
+
+/// Formats a number as a valid DS value of at most 16 bytes.  The shortest
+/// representation that round trips is used when it fits, otherwise the
+/// number is rounded to the most significant digits that fit in either
+/// fixed or exponential notation.  Infinity and NaN cannot be represented
+/// and return an error.
+pub fn format_ds(value: f64) -> Result<String, ValueError> {
+    if !value.is_finite() {
+        return Err(invalid(&value.to_string()));
+    }
+    let shortest = value.to_string();
+    if shortest.len() <= DS_MAX_LENGTH {
+        return Ok(shortest);
+    }
+
+    for precision in (0..DS_MAX_LENGTH).rev() {
+        let exponential = format!("{:.*e}", precision, value);
+        let (mantissa, exponent) = match exponential.split_once('e') {
+            Some(parts) => parts,
+            None => continue,
+        };
+        let exponent: i32 = exponent.parse().map_err(|_| invalid(&exponential))?;
+        let exponential = format!("{}e{}", trim_fraction(mantissa), exponent);
+
+        // fixed notation with the same number of significant digits
+        let decimals = precision as i32 - exponent;
+        let fixed = if decimals >= 0 {
+            let fixed = format!("{:.*}", decimals as usize, value);
+            Some(trim_fraction(&fixed).to_string())
+        } else {
+            None
+        };
+
+        let candidate = match fixed {
+            Some(fixed) if fixed.len() <= exponential.len() => fixed,
+            _ => exponential,
+        };
+        if candidate.len() <= DS_MAX_LENGTH {
+            return Ok(candidate);
+        }
+    }
+    Err(invalid(&shortest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tag::Tag;
+
+    fn make_attribute(vr: VR, length: usize) -> Attribute {
+        Attribute {
+            tag: Tag::new(0x0028, 0x0030),
+            vr: Some(vr),
+            length,
+            vr_inferred: false,
+        }
+    }
+
+    #[test]
+    fn strict_ds_accepts_conformant_values() {
+        assert_eq!(parse_ds("0.5", Mode::Strict), Ok(0.5));
+        assert_eq!(parse_ds(" +1.5e3 ", Mode::Strict), Ok(1500.0));
+        assert_eq!(parse_ds("-.25", Mode::Strict), Ok(-0.25));
+        assert_eq!(parse_ds("7.", Mode::Strict), Ok(7.0));
+        assert_eq!(parse_ds("1E-2", Mode::Strict), Ok(0.01));
+    }
+
+    #[test]
+    fn strict_ds_rejects_nonconformant_values() {
+        assert!(parse_ds("0,5", Mode::Strict).is_err());
+        assert!(parse_ds("1.23456789012345678", Mode::Strict).is_err());
+        assert!(parse_ds("inf", Mode::Strict).is_err());
+        assert!(parse_ds("NaN", Mode::Strict).is_err());
+        assert!(parse_ds("1e", Mode::Strict).is_err());
+        assert!(parse_ds(".", Mode::Strict).is_err());
+        assert!(parse_ds("", Mode::Strict).is_err());
+        assert!(parse_ds("\t1", Mode::Strict).is_err());
+    }
+
+    #[test]
+    fn lenient_ds_accepts_values_in_the_wild() {
+        assert_eq!(parse_ds("0,5", Mode::Lenient), Ok(0.5));
+        assert_eq!(
+            parse_ds("1.23456789012345678", Mode::Lenient),
+            Ok(1.234_567_890_123_456_7)
+        );
+        assert_eq!(parse_ds("\t2.5\0", Mode::Lenient), Ok(2.5));
+        assert!(parse_ds("1e999", Mode::Lenient).is_err());
+        assert!(parse_ds("abc", Mode::Lenient).is_err());
+    }
+
+    #[test]
+    fn strict_is_accepts_conformant_values() {
+        assert_eq!(parse_is(" +42", Mode::Strict), Ok(42));
+        assert_eq!(parse_is("-2147483648", Mode::Strict), Ok(-2147483648));
+        assert!(parse_is("2147483648", Mode::Strict).is_err());
+        assert!(parse_is("12.0", Mode::Strict).is_err());
+        assert!(parse_is("1e3", Mode::Strict).is_err());
+        assert!(parse_is("", Mode::Strict).is_err());
+    }
+
+    #[test]
+    fn lenient_is_accepts_values_in_the_wild() {
+        assert_eq!(parse_is("12.0", Mode::Lenient), Ok(12));
+        assert_eq!(parse_is("1e3", Mode::Lenient), Ok(1000));
+        assert_eq!(parse_is("9876543210", Mode::Lenient), Ok(9876543210));
+        assert!(parse_is("12.5", Mode::Lenient).is_err());
+    }
+
+    #[test]
+    fn decimal_strings_decodes_multiple_values() {
+        let attribute = make_attribute(VR::DS, 16);
+        assert_eq!(
+            decimal_strings(&attribute, b"0.661\\0.661 ", Mode::Strict),
+            Ok(vec![0.661, 0.661])
+        );
+        assert_eq!(
+            decimal_strings(&attribute, b"", Mode::Strict),
+            Ok(Vec::<f64>::new())
+        );
+        assert!(decimal_strings(&attribute, b"1\\\\2 ", Mode::Lenient).is_err());
+        assert_eq!(
+            decimal_strings(&make_attribute(VR::IS, 2), b"1 ", Mode::Strict),
+            Err(ValueError::InvalidVR(VR::IS))
+        );
+    }
+
+    #[test]
+    fn integer_strings_decodes_multiple_values() {
+        let attribute = make_attribute(VR::IS, 6);
+        assert_eq!(
+            integer_strings(&attribute, b"1\\-2\\3 ", Mode::Strict),
+            Ok(vec![1, -2, 3])
+        );
+    }
+
+    #[test]
+    fn format_ds_fits_in_16_bytes() {
+        assert_eq!(format_ds(0.5).unwrap(), "0.5");
+        assert_eq!(format_ds(-12.0).unwrap(), "-12");
+        assert_eq!(format_ds(1.0 / 3.0).unwrap(), "0.33333333333333");
+        assert_eq!(format_ds(-1.0 / 3.0).unwrap(), "-0.3333333333333");
+        assert_eq!(format_ds(1e300).unwrap(), "1e300");
+        assert_eq!(format_ds(1.5e-300).unwrap(), "1.5e-300");
+        assert_eq!(format_ds(123456789012345678.0).unwrap(), "1.23456789012e17");
+        assert!(format_ds(f64::NAN).is_err());
+        assert!(format_ds(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn format_ds_values_are_valid_and_round_trip_closely() {
+        let values = [
+            std::f64::consts::PI,
+            -std::f64::consts::E * 1e-7,
+            6.02214076e23,
+            -1.797_693_134_862_315_7e300,
+            f64::MIN_POSITIVE,
+            0.1 + 0.2,
+        ];
+        for value in values.iter() {
+            let formatted = format_ds(*value).unwrap();
+            assert!(formatted.len() <= 16);
+            let parsed = parse_ds(&formatted, Mode::Strict).unwrap();
+            assert!(((parsed - value) / value).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use crate::attribute::Attribute;
+use crate::value::{check_vr, strings, ValueError};
+use crate::vr::VR;
+use std::fmt;
+
+/// The five components of a person name component group.  Components that
+/// are not present are empty.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PersonNameGroup<'a> {
+    pub family: &'a str,
+    pub given: &'a str,
+    pub middle: &'a str,
+    pub prefix: &'a str,
+    pub suffix: &'a str,
+}
+
+impl<'a> PersonNameGroup<'a> {
+    /// Parses a component group with up to five components separated by ^
+    pub fn parse(value: &'a str) -> Result<PersonNameGroup<'a>, ValueError> {
+        let mut components = [""; 5];
+        for (index, component) in value.split('^').enumerate() {
+            if index >= components.len() {
+                return Err(ValueError::InvalidFormat(String::from(value)));
+            }
+            components[index] = component.trim_matches(' ');
+        }
+        Ok(PersonNameGroup {
+            family: components[0],
+            given: components[1],
+            middle: components[2],
+            prefix: components[3],
+            suffix: components[4],
+        })
+    }
+
+    /// Returns the components in the order they are encoded
+    pub fn components(&self) -> [&'a str; 5] {
+        [
+            self.family,
+            self.given,
+            self.middle,
+            self.prefix,
+            self.suffix,
+        ]
+    }
+
+    /// Returns true if all of the components are empty
+    pub fn is_empty(&self) -> bool {
+        self.components()
+            .iter()
+            .all(|component| component.is_empty())
+    }
+}
+
+/// A PN value with its alphabetic, ideographic and phonetic component
+/// groups.  Groups that are not present or empty are None.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PersonName<'a> {
+    pub alphabetic: Option<PersonNameGroup<'a>>,
+    pub ideographic: Option<PersonNameGroup<'a>>,
+    pub phonetic: Option<PersonNameGroup<'a>>,
+}
+
+impl<'a> PersonName<'a> {
+    /// Parses a single PN value with up to three component groups separated
+    /// by =
+    pub fn parse(value: &'a str) -> Result<PersonName<'a>, ValueError> {
+        let mut groups = [None; 3];
+        for (index, group) in value.split('=').enumerate() {
+            if index >= groups.len() {
+                return Err(ValueError::InvalidFormat(String::from(value)));
+            }
+            let group = PersonNameGroup::parse(group)?;
+            if !group.is_empty() {
+                groups[index] = Some(group);
+            }
+        }
+        Ok(PersonName {
+            alphabetic: groups[0],
+            ideographic: groups[1],
+            phonetic: groups[2],
+        })
+    }
+
+    /// Returns true if all of the component groups are empty
+    pub fn is_empty(&self) -> bool {
+        self.alphabetic.is_none() && self.ideographic.is_none() && self.phonetic.is_none()
+    }
+}
+
+/// Formats the group with trailing empty components (and their ^) removed
+impl fmt::Display for PersonNameGroup<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components = self.components();
+        let count = components
+            .iter()
+            .rposition(|component| !component.is_empty())
+            .map_or(0, |index| index + 1);
+        write!(f, "{}", components[..count].join("^"))
+    }
+}
+
+/// Formats the DICOM string with trailing empty groups (and their =) removed
+impl fmt::Display for PersonName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups = [self.alphabetic, self.ideographic, self.phonetic];
+        let count = groups
+            .iter()
+            .rposition(|group| group.is_some())
+            .map_or(0, |index| index + 1);
+        for (index, group) in groups[..count].iter().enumerate() {
+            if index > 0 {
+                write!(f, "=")?;
+            }
+            if let Some(group) = group {
+                write!(f, "{}", group)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the values of a PN attribute.  The bytes are decoded as UTF-8 so
+/// the ideographic and phonetic groups of values using other character sets
+/// must be decoded to UTF-8 first.
+pub fn person_names<'a>(
+    attribute: &Attribute,
+    bytes: &'a [u8],
+) -> Result<Vec<PersonName<'a>>, ValueError> {
+    check_vr(attribute, &[VR::PN])?;
+    strings(attribute, bytes)?
+        .into_iter()
+        .map(PersonName::parse)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tag::Tag;
+
+    fn make_attribute(length: usize) -> Attribute {
+        Attribute {
+            tag: Tag::new(0x0010, 0x0010),
+            vr: Some(VR::PN),
+            length,
+            vr_inferred: false,
+        }
+    }
+
+    #[test]
+    fn parses_all_components() {
+        let name = PersonName::parse("Adams^John Robert Quincy^^Rev.^B.A. M.Div.").unwrap();
+        let alphabetic = name.alphabetic.unwrap();
+        assert_eq!(alphabetic.family, "Adams");
+        assert_eq!(alphabetic.given, "John Robert Quincy");
+        assert_eq!(alphabetic.middle, "");
+        assert_eq!(alphabetic.prefix, "Rev.");
+        assert_eq!(alphabetic.suffix, "B.A. M.Div.");
+        assert_eq!(name.ideographic, None);
+        assert_eq!(name.phonetic, None);
+    }
+
+    #[test]
+    fn parses_component_groups() {
+        let name = PersonName::parse("Yamada^Tarou=山田^太郎=やまだ^たろう").unwrap();
+        assert_eq!(name.alphabetic.unwrap().family, "Yamada");
+        assert_eq!(name.ideographic.unwrap().family, "山田");
+        assert_eq!(name.phonetic.unwrap().given, "たろう");
+
+        let name = PersonName::parse("=山田^太郎").unwrap();
+        assert_eq!(name.alphabetic, None);
+        assert_eq!(name.ideographic.unwrap().given, "太郎");
+    }
+
+    #[test]
+    fn too_many_components_or_groups_return_error() {
+        assert!(PersonName::parse("a^b^c^d^e^f").is_err());
+        assert!(PersonName::parse("a=b=c=d").is_err());
+    }
+
+    #[test]
+    fn formats_dicom_string() {
+        for value in &[
+            "Adams^John Robert Quincy^^Rev.^B.A. M.Div.",
+            "Yamada^Tarou=山田^太郎=やまだ^たろう",
+            "=山田^太郎",
+            "Smith",
+            "",
+        ] {
+            assert_eq!(PersonName::parse(value).unwrap().to_string(), *value);
+        }
+        // trailing empty components and groups are removed
+        assert_eq!(
+            PersonName::parse("Smith^^^^==").unwrap().to_string(),
+            "Smith"
+        );
+        assert!(PersonName::parse("^^=").unwrap().is_empty());
+    }
+
+    #[test]
+    fn person_names_decodes_multiple_values() {
+        let bytes = b"CompressedSamples^CT1\\Doe^Jane ";
+        let names = person_names(&make_attribute(bytes.len()), bytes).unwrap();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names[0].alphabetic.unwrap().given, "CT1");
+        assert_eq!(names[1].alphabetic.unwrap().family, "Doe");
+    }
+}
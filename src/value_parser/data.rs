@@ -1,6 +1,7 @@
 use crate::attribute::Attribute;
 use crate::encoding::Encoding;
 use crate::handler::Handler;
+use crate::handler::HandlerResult;
 use crate::value_parser::ParseError;
 use crate::value_parser::ParseResult;
 use crate::value_parser::ValueParser;
@@ -11,6 +12,10 @@ use std::marker::PhantomData;
 pub struct DataParser<T: Encoding> {
     pub phantom: PhantomData<T>,
     total_bytes_consumed: usize,
+    // true if the handler skipped the rest of the value field
+    skip: bool,
+    // true once the complete value has been delivered to the handler
+    complete: bool,
 }
 
 impl<T: 'static + Encoding> ValueParser<T> for DataParser<T> {
@@ -21,6 +26,12 @@ impl<T: 'static + Encoding> ValueParser<T> for DataParser<T> {
         bytes: &[u8],
         _position: usize,
     ) -> Result<ParseResult, ParseError> {
+        // nothing left to do if the handler cancelled after the complete
+        // value was delivered and the parse is being resumed
+        if self.complete {
+            return Ok(ParseResult::completed(0));
+        }
+
         // get slice of bytes based on how many we have already parsed for this value field
         let bytes_remaining = if bytes.len() > attribute.length - self.total_bytes_consumed {
             &bytes[..attribute.length - self.total_bytes_consumed]
@@ -28,12 +39,30 @@ impl<T: 'static + Encoding> ValueParser<T> for DataParser<T> {
             bytes
         };
 
-        // notify handler of data
+        // wait for more bytes if there are none for the value field
         let complete = self.total_bytes_consumed + bytes_remaining.len() == attribute.length;
-        handler.data(attribute, bytes_remaining, complete);
+        if bytes_remaining.is_empty() && !complete {
+            return Ok(ParseResult::incomplete(0));
+        }
+
+        // notify handler of data unless it skipped the value field
+        let handler_result = if self.skip {
+            HandlerResult::Skip
+        } else {
+            handler.data(attribute, bytes_remaining, complete)
+        };
 
-        // update our internal counter of bytes consumed
+        // update our internal state
         self.total_bytes_consumed += bytes_remaining.len();
+        self.complete = complete;
+
+        match handler_result {
+            HandlerResult::Continue => {}
+            HandlerResult::Skip => self.skip = true,
+            HandlerResult::Cancel => {
+                return Ok(ParseResult::cancelled(bytes_remaining.len()));
+            }
+        }
 
         // Return complete if we have parsed all bytes for this value or incomplete if not
         if self.total_bytes_consumed == attribute.length {
@@ -50,6 +79,7 @@ mod tests {
     use super::DataParser;
     use crate::attribute::Attribute;
     use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::{Handler, HandlerResult};
     use crate::test::tests::TestHandler;
     use crate::value_parser::ParseState;
     use crate::value_parser::ValueParser;
@@ -58,8 +88,10 @@ mod tests {
     fn data_parser_completes() {
         let mut parser = DataParser::<ExplicitLittleEndian>::default();
         let mut handler = TestHandler::default();
-        let mut attribute = Attribute::default();
-        attribute.length = 255;
+        let attribute = Attribute {
+            length: 255,
+            ..Attribute::default()
+        };
         let bytes = [0; 255];
         match parser.parse(&mut handler, &attribute, &bytes, 0) {
             Ok(result) => {
@@ -67,7 +99,7 @@ mod tests {
                 assert_eq!(result.state, ParseState::Completed);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
             }
         };
     }
@@ -76,8 +108,10 @@ mod tests {
     fn data_parser_streaming_completes() {
         let mut parser = DataParser::<ExplicitLittleEndian>::default();
         let mut handler = TestHandler::default();
-        let mut attribute = Attribute::default();
-        attribute.length = 255;
+        let attribute = Attribute {
+            length: 255,
+            ..Attribute::default()
+        };
         let bytes = [0; 255];
         match parser.parse(&mut handler, &attribute, &bytes[0..100], 0) {
             Ok(result) => {
@@ -85,7 +119,7 @@ mod tests {
                 assert_eq!(result.state, ParseState::Incomplete);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
             }
         };
         match parser.parse(&mut handler, &attribute, &bytes[100..], 0) {
@@ -94,8 +128,71 @@ mod tests {
                 assert_eq!(result.state, ParseState::Completed);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
+            }
+        };
+    }
+
+    // Handler that returns a fixed result from data() and counts the calls
+    struct DataResultHandler {
+        result: HandlerResult,
+        data_count: usize,
+    }
+
+    impl Handler for DataResultHandler {
+        fn data(&mut self, _attribute: &Attribute, _data: &[u8], _complete: bool) -> HandlerResult {
+            self.data_count += 1;
+            self.result
+        }
+    }
+
+    #[test]
+    fn data_parser_skip_completes_without_data() {
+        let mut parser = DataParser::<ExplicitLittleEndian>::default();
+        let mut handler = DataResultHandler {
+            result: HandlerResult::Skip,
+            data_count: 0,
+        };
+        let attribute = Attribute {
+            length: 255,
+            ..Attribute::default()
+        };
+        let bytes = [0; 255];
+        match parser.parse(&mut handler, &attribute, &bytes[0..100], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, 100);
+                assert_eq!(result.state, ParseState::Incomplete);
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+        match parser.parse(&mut handler, &attribute, &bytes[100..], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, bytes.len() - 100);
+                assert_eq!(result.state, ParseState::Completed);
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+        assert_eq!(handler.data_count, 1);
+    }
+
+    #[test]
+    fn data_parser_cancel_returns_cancelled() {
+        let mut parser = DataParser::<ExplicitLittleEndian>::default();
+        let mut handler = DataResultHandler {
+            result: HandlerResult::Cancel,
+            data_count: 0,
+        };
+        let attribute = Attribute {
+            length: 255,
+            ..Attribute::default()
+        };
+        let bytes = [0; 255];
+        match parser.parse(&mut handler, &attribute, &bytes[0..100], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, 100);
+                assert_eq!(result.state, ParseState::Cancelled);
             }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
         };
     }
 }
@@ -1,6 +1,7 @@
 use crate::attribute::Attribute;
 use crate::encoding::Encoding;
 use crate::handler::Handler;
+use crate::handler::HandlerResult;
 use crate::value_parser::ParseError;
 use crate::value_parser::ParseResult;
 use crate::value_parser::ValueParser;
@@ -9,6 +10,10 @@ use std::marker::PhantomData;
 #[derive(Default)]
 pub struct DataUndefinedLengthParser<T: Encoding> {
     pub phantom: PhantomData<T>,
+    // true if the handler skipped the rest of the value field
+    skip: bool,
+    // true once the complete value has been delivered to the handler
+    complete: bool,
 }
 
 impl<T: 'static + Encoding> ValueParser<T> for DataUndefinedLengthParser<T> {
@@ -19,6 +24,12 @@ impl<T: 'static + Encoding> ValueParser<T> for DataUndefinedLengthParser<T> {
         bytes: &[u8],
         _position: usize,
     ) -> Result<ParseResult, ParseError> {
+        // nothing left to do if the handler cancelled after the complete
+        // value was delivered and the parse is being resumed
+        if self.complete {
+            return Ok(ParseResult::completed(0));
+        }
+
         // we return immediately if we don't have at least 8 bytes of data
         // since it takes 8 bytes for the end of data marker
         if bytes.len() < 8 {
@@ -28,15 +39,34 @@ impl<T: 'static + Encoding> ValueParser<T> for DataUndefinedLengthParser<T> {
         // scan for end of marker
         let (length, end_of_data_marker_found) = find_end_of_data_marker::<T>(bytes);
 
-        // notify handler of data
-        handler.data(attribute, &bytes[..length], end_of_data_marker_found);
+        // notify handler of data unless it skipped the value field
+        let handler_result = if self.skip {
+            HandlerResult::Skip
+        } else {
+            handler.data(attribute, &bytes[..length], end_of_data_marker_found)
+        };
+
+        // include the size of the end of data marker in the number of
+        // bytes consumed
+        let bytes_consumed = if end_of_data_marker_found {
+            length + 8
+        } else {
+            length
+        };
+        self.complete = end_of_data_marker_found;
+
+        match handler_result {
+            HandlerResult::Continue => {}
+            HandlerResult::Skip => self.skip = true,
+            HandlerResult::Cancel => {
+                return Ok(ParseResult::cancelled(bytes_consumed));
+            }
+        }
 
         if end_of_data_marker_found {
-            // include the size of the end of data marker in the number of
-            // bytes consumed
-            Ok(ParseResult::completed(length + 8))
+            Ok(ParseResult::completed(bytes_consumed))
         } else {
-            Ok(ParseResult::incomplete(length))
+            Ok(ParseResult::incomplete(bytes_consumed))
         }
     }
 }
@@ -68,6 +98,7 @@ mod tests {
     use super::DataUndefinedLengthParser;
     use crate::attribute::Attribute;
     use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::{Handler, HandlerResult};
     use crate::test::tests::TestHandler;
     use crate::value_parser::ParseState;
     use crate::value_parser::ValueParser;
@@ -75,7 +106,7 @@ mod tests {
     fn make_undefined_length_value() -> Vec<u8> {
         let mut bytes = vec![0; 250];
         // end with sequence item delimeter
-        bytes.extend_from_slice(&vec![0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
 
         bytes
     }
@@ -84,8 +115,10 @@ mod tests {
     fn full_parse_completes() {
         let mut parser = DataUndefinedLengthParser::<ExplicitLittleEndian>::default();
         let mut handler = TestHandler::default();
-        let mut attribute = Attribute::default();
-        attribute.length = 0xFFFF_FFFF;
+        let attribute = Attribute {
+            length: 0xFFFF_FFFF,
+            ..Attribute::default()
+        };
         let bytes = make_undefined_length_value();
         match parser.parse(&mut handler, &attribute, &bytes, 0) {
             Ok(result) => {
@@ -93,7 +126,7 @@ mod tests {
                 assert_eq!(result.state, ParseState::Completed);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
             }
         };
     }
@@ -102,8 +135,10 @@ mod tests {
     fn dul_streaming_parse_completes() {
         let mut parser = DataUndefinedLengthParser::<ExplicitLittleEndian>::default();
         let mut handler = TestHandler::default();
-        let mut attribute = Attribute::default();
-        attribute.length = 0xFFFF_FFFF;
+        let attribute = Attribute {
+            length: 0xFFFF_FFFF,
+            ..Attribute::default()
+        };
         let bytes = make_undefined_length_value();
         match parser.parse(&mut handler, &attribute, &bytes[0..100], 0) {
             Ok(result1) => {
@@ -120,13 +155,52 @@ mod tests {
                         assert_eq!(result2.state, ParseState::Completed);
                     }
                     Err(_error) => {
-                        assert!(false); // should not happen
+                        panic!("Let's play Global Thermonuclear War");
                     }
                 };
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
+            }
+        };
+    }
+
+    // Handler that skips the value field and counts the calls to data()
+    #[derive(Default)]
+    struct SkipDataHandler {
+        data_count: usize,
+    }
+
+    impl Handler for SkipDataHandler {
+        fn data(&mut self, _attribute: &Attribute, _data: &[u8], _complete: bool) -> HandlerResult {
+            self.data_count += 1;
+            HandlerResult::Skip
+        }
+    }
+
+    #[test]
+    fn dul_streaming_skip_completes() {
+        let mut parser = DataUndefinedLengthParser::<ExplicitLittleEndian>::default();
+        let mut handler = SkipDataHandler::default();
+        let attribute = Attribute {
+            length: 0xFFFF_FFFF,
+            ..Attribute::default()
+        };
+        let bytes = make_undefined_length_value();
+        let bytes_consumed = match parser.parse(&mut handler, &attribute, &bytes[0..100], 0) {
+            Ok(result) => {
+                assert_eq!(result.state, ParseState::Incomplete);
+                result.bytes_consumed
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+        match parser.parse(&mut handler, &attribute, &bytes[bytes_consumed..], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, bytes.len() - bytes_consumed);
+                assert_eq!(result.state, ParseState::Completed);
             }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
         };
+        assert_eq!(handler.data_count, 1);
     }
 }
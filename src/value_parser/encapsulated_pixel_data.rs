@@ -1,9 +1,11 @@
 use crate::attribute::Attribute;
 use crate::encoding::Encoding;
 use crate::handler::Handler;
+use crate::handler::HandlerResult;
 use crate::tag;
 use crate::tag::Tag;
 use crate::value_parser::ParseError;
+use crate::value_parser::ParseErrorKind;
 use crate::value_parser::ParseResult;
 use crate::value_parser::ValueParser;
 use std::marker::PhantomData;
@@ -13,15 +15,18 @@ pub struct EncapsulatedPixelDataParser<T: Encoding> {
     total_bytes_consumed: usize,
     remaining_byte_count: usize,
     item_number: usize,
+    // true if the handler skipped the rest of the pixel data
+    skip: bool,
 }
 
-impl<T: 'static + Encoding> EncapsulatedPixelDataParser<T> {
-    pub fn default() -> EncapsulatedPixelDataParser<T> {
+impl<T: 'static + Encoding> Default for EncapsulatedPixelDataParser<T> {
+    fn default() -> EncapsulatedPixelDataParser<T> {
         EncapsulatedPixelDataParser {
             phantom: PhantomData,
             total_bytes_consumed: 0,
             remaining_byte_count: 0,
             item_number: 0,
+            skip: false,
         }
     }
 }
@@ -47,34 +52,37 @@ impl<T: 'static + Encoding> ValueParser<T> for EncapsulatedPixelDataParser<T> {
                     return Ok(ParseResult::incomplete(bytes_consumed));
                 }
                 let (tag, length) = parse_tag_and_length::<T>(remaining_bytes);
-                self.total_bytes_consumed += 8;
-                bytes_consumed += 8;
-                remaining_bytes = &remaining_bytes[8..];
 
                 // if sequence delimtation item, we are done
                 if tag == tag::SEQUENCEDELIMITATIONITEM {
-                    return Ok(ParseResult::completed(bytes_consumed));
+                    self.total_bytes_consumed += 8;
+                    return Ok(ParseResult::completed(bytes_consumed + 8));
                 }
 
                 // make sure we have a sequence item
                 if tag != tag::ITEM {
-                    return Err(ParseError {
-                        reason: "expected Item tag FFFE,E000",
-                        position: position + bytes_consumed,
-                    });
+                    return Err(ParseError::with_tag(
+                        ParseErrorKind::InvalidItemTag(tag),
+                        attribute.tag,
+                        position + bytes_consumed,
+                    ));
                 }
 
                 // make sure item length is not undefined
                 if length == 0xFFFF_FFFF {
-                    return Err(ParseError {
-                        reason: "expected defined length",
-                        position: position + bytes_consumed,
-                    });
+                    return Err(ParseError::with_tag(
+                        ParseErrorKind::UndefinedLengthItem,
+                        attribute.tag,
+                        position + bytes_consumed,
+                    ));
                 }
 
                 // set remaining_byte_count so we know how much data to stream
                 // to the handler
                 self.remaining_byte_count = length;
+                self.total_bytes_consumed += 8;
+                bytes_consumed += 8;
+                remaining_bytes = &remaining_bytes[8..];
             }
 
             // get slice of bytes based on how many we have already parsed for this value field
@@ -86,11 +94,13 @@ impl<T: 'static + Encoding> ValueParser<T> for EncapsulatedPixelDataParser<T> {
 
             // invoke appropriate function on handler (basic offset table is always first)
             let complete = self.remaining_byte_count == value_bytes.len();
-            if self.item_number == 0 {
-                handler.basic_offset_table(attribute, value_bytes, complete);
+            let handler_result = if self.skip {
+                HandlerResult::Skip
+            } else if self.item_number == 0 {
+                handler.basic_offset_table(attribute, value_bytes, complete)
             } else {
-                handler.pixel_data_fragment(attribute, self.item_number, value_bytes, complete);
-            }
+                handler.pixel_data_fragment(attribute, self.item_number, value_bytes, complete)
+            };
 
             // update counters
             self.total_bytes_consumed += value_bytes.len();
@@ -102,6 +112,16 @@ impl<T: 'static + Encoding> ValueParser<T> for EncapsulatedPixelDataParser<T> {
             if self.remaining_byte_count == 0 {
                 self.item_number += 1;
             }
+
+            // return cancelled if the handler cancelled.  The bytes delivered
+            // to the handler are included in the bytes consumed
+            match handler_result {
+                HandlerResult::Continue => {}
+                HandlerResult::Skip => self.skip = true,
+                HandlerResult::Cancel => {
+                    return Ok(ParseResult::cancelled(bytes_consumed));
+                }
+            }
         }
 
         Ok(ParseResult::incomplete(bytes_consumed))
@@ -120,8 +140,10 @@ mod tests {
     use super::EncapsulatedPixelDataParser;
     use crate::attribute::Attribute;
     use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::{Handler, HandlerResult};
     use crate::tag::Tag;
     use crate::test::tests::TestHandler;
+    use crate::value_parser::ParseErrorKind;
     use crate::value_parser::ParseState;
     use crate::value_parser::ValueParser;
     use crate::vr::VR;
@@ -129,15 +151,15 @@ mod tests {
     fn make_encapsulated_pixel_data_value_with_empty_bot() -> Vec<u8> {
         let mut bytes = vec![];
         // Basic Offset Table (Empty)
-        bytes.extend_from_slice(&vec![0xFE, 0xFF, 0x00, 0xE0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 0, 0, 0, 0]);
         // Fragment #1 (250 zeros)
-        bytes.extend_from_slice(&vec![0xFE, 0xFF, 0x00, 0xE0, 250, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 250, 0, 0, 0]);
         bytes.extend_from_slice(&vec![0; 250]);
         // Fragment #2 (150 zeros)
-        bytes.extend_from_slice(&vec![0xFE, 0xFF, 0x00, 0xE0, 150, 0, 0, 0]);
-        bytes.extend_from_slice(&vec![0; 150]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 150, 0, 0, 0]);
+        bytes.extend_from_slice(&[0; 150]);
         // end with sequence item delimeter
-        bytes.extend_from_slice(&vec![0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]);
 
         bytes
     }
@@ -147,10 +169,12 @@ mod tests {
         let mut parser = EncapsulatedPixelDataParser::<ExplicitLittleEndian>::default();
         let mut handler = TestHandler::default();
         let bytes = make_encapsulated_pixel_data_value_with_empty_bot();
-        let mut attribute = Attribute::default();
-        attribute.tag = Tag::new(0x7fe0, 0x0010);
-        attribute.vr = Some(VR::OB);
-        attribute.length = 0xFFFF_FFFF;
+        let attribute = Attribute {
+            tag: Tag::new(0x7fe0, 0x0010),
+            vr: Some(VR::OB),
+            length: 0xFFFF_FFFF,
+            ..Attribute::default()
+        };
 
         match parser.parse(&mut handler, &attribute, &bytes[..], 0) {
             Ok(result) => {
@@ -158,7 +182,7 @@ mod tests {
                 assert_eq!(result.state, ParseState::Completed);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
             }
         };
     }
@@ -169,10 +193,12 @@ mod tests {
         let mut handler = TestHandler::default();
         //handler.print = true;
         let bytes = make_encapsulated_pixel_data_value_with_empty_bot();
-        let mut attribute = Attribute::default();
-        attribute.tag = Tag::new(0x7fe0, 0x0010);
-        attribute.vr = Some(VR::OB);
-        attribute.length = 0xFFFF_FFFF;
+        let attribute = Attribute {
+            tag: Tag::new(0x7fe0, 0x0010),
+            vr: Some(VR::OB),
+            length: 0xFFFF_FFFF,
+            ..Attribute::default()
+        };
 
         match parser.parse(&mut handler, &attribute, &bytes[0..100], 0) {
             Ok(result) => {
@@ -180,7 +206,7 @@ mod tests {
                 assert_eq!(result.state, ParseState::Incomplete);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
             }
         };
         match parser.parse(&mut handler, &attribute, &bytes[100..], 0) {
@@ -189,8 +215,167 @@ mod tests {
                 assert_eq!(result.state, ParseState::Completed);
             }
             Err(_error) => {
-                assert!(false); // should not happen
+                panic!("Let's play Global Thermonuclear War");
+            }
+        };
+    }
+
+    #[test]
+    fn undefined_length_item_returns_error() {
+        let mut parser = EncapsulatedPixelDataParser::<ExplicitLittleEndian>::default();
+        let mut handler = TestHandler::default();
+        let bytes = [0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF];
+        let attribute = Attribute {
+            tag: Tag::new(0x7fe0, 0x0010),
+            vr: Some(VR::OB),
+            length: 0xFFFF_FFFF,
+            ..Attribute::default()
+        };
+
+        match parser.parse(&mut handler, &attribute, &bytes[..], 100) {
+            Ok(_result) => panic!("Let's play Global Thermonuclear War"),
+            Err(error) => {
+                assert_eq!(error.kind, ParseErrorKind::UndefinedLengthItem);
+                assert_eq!(error.tag, Some(Tag::new(0x7fe0, 0x0010)));
+                assert_eq!(error.position, 100);
+            }
+        };
+    }
+
+    #[test]
+    fn invalid_item_tag_returns_error() {
+        let mut parser = EncapsulatedPixelDataParser::<ExplicitLittleEndian>::default();
+        let mut handler = TestHandler::default();
+        let bytes = [0x08, 0x00, 0x05, 0x00, 0, 0, 0, 0];
+        let attribute = Attribute {
+            tag: Tag::new(0x7fe0, 0x0010),
+            vr: Some(VR::OB),
+            length: 0xFFFF_FFFF,
+            ..Attribute::default()
+        };
+
+        match parser.parse(&mut handler, &attribute, &bytes[..], 100) {
+            Ok(_result) => panic!("Let's play Global Thermonuclear War"),
+            Err(error) => {
+                assert_eq!(
+                    error.kind,
+                    ParseErrorKind::InvalidItemTag(Tag::new(0x0008, 0x0005))
+                );
+                assert_eq!(error.position, 100);
+            }
+        };
+    }
+
+    // Handler that cancels when the basic offset table or a given pixel
+    // data fragment is complete
+    struct FragmentCancelHandler {
+        cancel_fragment_number: usize,
+        fragments: Vec<usize>,
+    }
+
+    impl Handler for FragmentCancelHandler {
+        fn basic_offset_table(
+            &mut self,
+            _attribute: &Attribute,
+            _data: &[u8],
+            complete: bool,
+        ) -> HandlerResult {
+            if complete && self.cancel_fragment_number == 0 {
+                HandlerResult::Cancel
+            } else {
+                HandlerResult::Continue
+            }
+        }
+
+        fn pixel_data_fragment(
+            &mut self,
+            _attribute: &Attribute,
+            fragment_number: usize,
+            _data: &[u8],
+            complete: bool,
+        ) -> HandlerResult {
+            self.fragments.push(fragment_number);
+            if complete && fragment_number == self.cancel_fragment_number {
+                HandlerResult::Cancel
+            } else {
+                HandlerResult::Continue
+            }
+        }
+    }
+
+    fn make_pixel_data_attribute() -> Attribute {
+        Attribute {
+            tag: Tag::new(0x7fe0, 0x0010),
+            vr: Some(VR::OB),
+            length: 0xFFFF_FFFF,
+            ..Attribute::default()
+        }
+    }
+
+    #[test]
+    fn basic_offset_table_cancel_returns_cancelled() {
+        let mut parser = EncapsulatedPixelDataParser::<ExplicitLittleEndian>::default();
+        let mut handler = FragmentCancelHandler {
+            cancel_fragment_number: 0,
+            fragments: vec![],
+        };
+        let bytes = make_encapsulated_pixel_data_value_with_empty_bot();
+        let attribute = make_pixel_data_attribute();
+
+        match parser.parse(&mut handler, &attribute, &bytes[..], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, 8);
+                assert_eq!(result.state, ParseState::Cancelled);
+                assert!(handler.fragments.is_empty());
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+    }
+
+    #[test]
+    fn pixel_data_fragment_cancel_returns_cancelled() {
+        let mut parser = EncapsulatedPixelDataParser::<ExplicitLittleEndian>::default();
+        let mut handler = FragmentCancelHandler {
+            cancel_fragment_number: 1,
+            fragments: vec![],
+        };
+        let bytes = make_encapsulated_pixel_data_value_with_empty_bot();
+        let attribute = make_pixel_data_attribute();
+
+        match parser.parse(&mut handler, &attribute, &bytes[..], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, 8 + 8 + 250);
+                assert_eq!(result.state, ParseState::Cancelled);
+                assert_eq!(handler.fragments, vec![1]);
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+    }
+
+    #[test]
+    fn streaming_pixel_data_fragment_cancel_returns_cancelled() {
+        let mut parser = EncapsulatedPixelDataParser::<ExplicitLittleEndian>::default();
+        let mut handler = FragmentCancelHandler {
+            cancel_fragment_number: 1,
+            fragments: vec![],
+        };
+        let bytes = make_encapsulated_pixel_data_value_with_empty_bot();
+        let attribute = make_pixel_data_attribute();
+
+        match parser.parse(&mut handler, &attribute, &bytes[0..100], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, 100);
+                assert_eq!(result.state, ParseState::Incomplete);
+            }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
+        };
+        match parser.parse(&mut handler, &attribute, &bytes[100..], 0) {
+            Ok(result) => {
+                assert_eq!(result.bytes_consumed, 8 + 8 + 250 - 100);
+                assert_eq!(result.state, ParseState::Cancelled);
+                assert_eq!(handler.fragments, vec![1, 1]);
             }
+            Err(_error) => panic!("Let's play Global Thermonuclear War"),
         };
     }
 }
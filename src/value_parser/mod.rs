@@ -1,18 +1,122 @@
 use crate::attribute::Attribute;
 use crate::encoding::Encoding;
+use crate::handler::path::PathItem;
 use crate::handler::Handler;
+use crate::tag::Tag;
 
 use std::fmt;
 
+/// The kind of error encountered while parsing
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    /// The bytes ended before the parse was complete
+    UnexpectedEOF,
+    /// An Item tag (FFFE,E000) was expected but the contained tag was found
+    InvalidItemTag(Tag),
+    /// An item in encapsulated pixel data has an undefined length
+    UndefinedLengthItem,
+    /// The DICOM P10 Header Prefix ("DICM" at position 128) was not found
+    InvalidPrefix,
+    /// The DICOM P10 Header could not be interpreted
+    InvalidMetaInformation,
+    /// A required attribute of the DICOM P10 Header is missing or empty
+    MissingMetaInformation,
+    /// The transfer syntax UID does not identify a supported encoding
+    UnsupportedTransferSyntax(String),
+    /// The bytes of a deflated DataSet could not be inflated
+    InvalidDeflateStream,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnexpectedEOF => f.write_str("unexpected EOF"),
+            ParseErrorKind::InvalidItemTag(tag) => {
+                write!(f, "expected Item tag (FFFE,E000) but found {:?}", tag)
+            }
+            ParseErrorKind::UndefinedLengthItem => {
+                f.write_str("undefined length item in encapsulated pixel data")
+            }
+            ParseErrorKind::InvalidPrefix => f.write_str("DICM prefix not found"),
+            ParseErrorKind::InvalidMetaInformation => f.write_str("invalid meta information"),
+            ParseErrorKind::MissingMetaInformation => f.write_str("missing meta information"),
+            ParseErrorKind::UnsupportedTransferSyntax(transfer_syntax_uid) => {
+                write!(f, "unsupported transfer syntax {:?}", transfer_syntax_uid)
+            }
+            ParseErrorKind::InvalidDeflateStream => f.write_str("invalid deflate stream"),
+        }
+    }
+}
+
 /// Contains information about an error encountered while parsing
+#[derive(Debug, Clone, PartialEq)]
 pub struct ParseError {
-    /// A string explaining the reason for the parse failure
-    pub reason: &'static str,
+    /// The kind of error
+    pub kind: ParseErrorKind,
+    /// The tag of the Attribute being parsed when the error occured (if any)
+    pub tag: Option<Tag>,
+    /// The sequence items from the root DataSet to the Attribute being
+    /// parsed, empty if the error did not occur in a sequence item
+    pub items: Vec<PathItem>,
     /// The position relative to the beginning of the stream that the error
     /// occured at
     pub position: usize,
 }
 
+impl ParseError {
+    /// Creates a ParseError that is not associated with an Attribute
+    pub fn new(kind: ParseErrorKind, position: usize) -> ParseError {
+        ParseError {
+            kind,
+            tag: None,
+            items: vec![],
+            position,
+        }
+    }
+
+    /// Creates a ParseError that occured while parsing the Attribute
+    /// with the given tag
+    pub fn with_tag(kind: ParseErrorKind, tag: Tag, position: usize) -> ParseError {
+        ParseError {
+            kind,
+            tag: Some(tag),
+            items: vec![],
+            position,
+        }
+    }
+
+    /// Returns this ParseError as occuring in the given sequence item.  Used
+    /// by the sequence parsers to record the path of errors in nested items
+    pub fn in_item(mut self, item: PathItem) -> ParseError {
+        self.items.insert(0, item);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if !self.items.is_empty() || self.tag.is_some() {
+            f.write_str(" in ")?;
+        }
+        for (index, path_item) in self.items.iter().enumerate() {
+            if index > 0 {
+                f.write_str(".")?;
+            }
+            write!(f, "{:?}[{}]", path_item.tag, path_item.item.index)?;
+        }
+        if let Some(tag) = self.tag {
+            if !self.items.is_empty() {
+                f.write_str(".")?;
+            }
+            write!(f, "{:?}", tag)?;
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// Enum describing the current state of the parser
 #[derive(PartialEq, Copy, Clone, Debug)]
 pub enum ParseState {
@@ -70,7 +174,7 @@ pub trait ValueParser<T: Encoding + fmt::Debug> {
     /// * `attribute` - The Attribute associated with this value field
     /// * `bytes`     - The raw bytes of the value field
     /// * `position`  - The position since the beginning of the parse stream
-    ///                 of the value field.
+    ///   of the value field.
     fn parse(
         &mut self,
         handler: &mut dyn Handler,
@@ -85,3 +189,35 @@ pub mod data_undefined_length;
 pub mod encapsulated_pixel_data;
 pub mod sequence;
 pub mod sequence_item_data;
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, ParseErrorKind};
+    use crate::tag::Tag;
+
+    #[test]
+    fn display_without_tag() {
+        let error = ParseError::new(ParseErrorKind::UnexpectedEOF, 10);
+        assert_eq!(format!("{}", error), "unexpected EOF at position 10");
+    }
+
+    #[test]
+    fn display_with_tag() {
+        let error = ParseError::with_tag(
+            ParseErrorKind::InvalidItemTag(Tag::new(0x0008, 0x0005)),
+            Tag::new(0x0008, 0x1140),
+            20,
+        );
+        assert_eq!(
+            format!("{}", error),
+            "expected Item tag (FFFE,E000) but found (0008,0005) in (0008,1140) at position 20"
+        );
+    }
+
+    #[test]
+    fn is_std_error() {
+        let error: Box<dyn std::error::Error> =
+            Box::new(ParseError::new(ParseErrorKind::InvalidPrefix, 128));
+        assert_eq!(error.to_string(), "DICM prefix not found at position 128");
+    }
+}
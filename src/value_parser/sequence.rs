@@ -1,10 +1,13 @@
 use crate::attribute::Attribute;
 use crate::encoding::Encoding;
+use crate::handler::path::PathItem;
 use crate::handler::Handler;
+use crate::handler::SequenceItem;
 use crate::tag;
 use crate::tag::Tag;
 use crate::value_parser::sequence_item_data::SequenceItemDataParser;
 use crate::value_parser::ParseError;
+use crate::value_parser::ParseErrorKind;
 use crate::value_parser::ParseResult;
 use crate::value_parser::ParseState;
 use crate::value_parser::ValueParser;
@@ -13,6 +16,10 @@ use crate::value_parser::ValueParser;
 pub struct SequenceParser<T: Encoding> {
     parser: Option<Box<dyn ValueParser<T>>>,
     total_bytes_consumed: usize,
+    // number of sequence items started so far
+    item_count: usize,
+    // the sequence item being parsed
+    item: SequenceItem,
 }
 
 impl<T: 'static + Encoding> ValueParser<T> for SequenceParser<T> {
@@ -35,7 +42,9 @@ impl<T: 'static + Encoding> ValueParser<T> for SequenceParser<T> {
         let mut bytes_consumed = 0;
 
         // iterate over remaining bytes
-        while !remaining_bytes.is_empty() {
+        // keep going while an item is in progress even if there are no bytes
+        // left so an item completed before a cancel is ended when resumed
+        while !remaining_bytes.is_empty() || self.parser.is_some() {
             match &mut self.parser {
                 None => {
                     // read the tag and length or return incomplete if not enough
@@ -48,36 +57,52 @@ impl<T: 'static + Encoding> ValueParser<T> for SequenceParser<T> {
                     // if we have undefined length, return completed if we have a sequence
                     // delimitation item (which marks the end of the sequence)
                     if attribute.length == 0xFFFF_FFFF && tag == tag::SEQUENCEDELIMITATIONITEM {
+                        handler.end_sequence(attribute);
                         return Ok(ParseResult::completed(bytes_consumed + 8));
                     }
 
                     // verify we have a sequence item tag and return error if not
                     if tag != tag::ITEM {
-                        return Err(ParseError {
-                            reason: "expected Item tag FFFE,E000",
-                            position,
-                        });
+                        return Err(ParseError::with_tag(
+                            ParseErrorKind::InvalidItemTag(tag),
+                            attribute.tag,
+                            position + bytes_consumed,
+                        ));
                     }
 
+                    self.item = SequenceItem {
+                        index: self.item_count,
+                        position: position + bytes_consumed,
+                        length,
+                    };
+
                     // update internal state
                     bytes_consumed += 8;
                     self.total_bytes_consumed += 8;
+                    self.item_count += 1;
                     remaining_bytes = &remaining_bytes[8..];
 
                     // notify handle that we are starting a new sequence item
-                    handler.start_sequence_item(attribute);
+                    handler.start_sequence_item(attribute, &self.item);
 
                     // create a new SequenceItemDataParser for this sequence item
                     self.parser = Some(Box::new(SequenceItemDataParser::<T>::new(length)));
                 }
                 Some(parser) => {
                     // we have a parser so forward the remaining bytes to it to be parsed
-                    let parse_result = parser.parse(
-                        handler,
-                        attribute,
-                        remaining_bytes,
-                        position + bytes_consumed,
-                    )?;
+                    // and record the sequence item in any error
+                    let item = PathItem {
+                        tag: attribute.tag,
+                        item: self.item,
+                    };
+                    let parse_result = parser
+                        .parse(
+                            handler,
+                            attribute,
+                            remaining_bytes,
+                            position + bytes_consumed,
+                        )
+                        .map_err(|error| error.in_item(item))?;
 
                     // update internal state
                     bytes_consumed += parse_result.bytes_consumed;
@@ -113,6 +138,8 @@ impl<T: 'static + Encoding> ValueParser<T> for SequenceParser<T> {
     }
 }
 
+// callers map the unit error to their own ParseError with a position
+#[allow(clippy::result_unit_err)]
 pub fn parse_sequence_item<T: Encoding>(bytes: &[u8]) -> Result<usize, ()> {
     let item_tag = Tag::from_bytes::<T>(&bytes[0..4]);
     let length = T::u32(&bytes[4..8]) as usize;
@@ -129,3 +156,59 @@ pub fn parse_tag_and_length<T: Encoding>(bytes: &[u8]) -> (Tag, usize) {
     let length = T::u32(&bytes[4..8]) as usize;
     (tag, length)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::data_set_parser::DataSetParser;
+    use crate::encoding::ExplicitLittleEndian;
+    use crate::handler::path::PathItem;
+    use crate::handler::SequenceItem;
+    use crate::tag::Tag;
+    use crate::test::tests::TestHandler;
+    use crate::value_parser::ParseErrorKind;
+
+    #[test]
+    fn nested_invalid_item_tag_returns_path() {
+        let mut bytes = vec![];
+        // (0008,1115) with undefined length
+        bytes.extend_from_slice(&[
+            0x08, 0x00, 0x15, 0x11, b'S', b'Q', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF,
+        ]);
+        // item with undefined length
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x00, 0xE0, 0xFF, 0xFF, 0xFF, 0xFF]);
+        // (0008,1140) with undefined length containing a (0008,0005) instead
+        // of an item
+        bytes.extend_from_slice(&[
+            0x08, 0x00, 0x40, 0x11, b'S', b'Q', 0, 0, 0xFF, 0xFF, 0xFF, 0xFF,
+        ]);
+        bytes.extend_from_slice(&[0x08, 0x00, 0x05, 0x00, 0, 0, 0, 0]);
+
+        let mut parser = DataSetParser::<ExplicitLittleEndian>::default();
+        let mut handler = TestHandler::default();
+        let error = match parser.parse(&mut handler, &bytes, 0) {
+            Ok(_) => panic!("Let's play Global Thermonuclear War"),
+            Err(error) => error,
+        };
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::InvalidItemTag(Tag::new(0x0008, 0x0005))
+        );
+        assert_eq!(error.tag, Some(Tag::new(0x0008, 0x1140)));
+        assert_eq!(
+            error.items,
+            vec![PathItem {
+                tag: Tag::new(0x0008, 0x1115),
+                item: SequenceItem {
+                    index: 0,
+                    position: 12,
+                    length: 0xFFFF_FFFF
+                }
+            }]
+        );
+        assert_eq!(error.position, 32);
+        assert_eq!(
+            error.to_string(),
+            "expected Item tag (FFFE,E000) but found (0008,0005) in (0008,1115)[0].(0008,1140) at position 32"
+        );
+    }
+}
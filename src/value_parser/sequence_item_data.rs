@@ -6,6 +6,7 @@ use crate::handler::Handler;
 use crate::tag;
 use crate::value_parser::ParseError;
 use crate::value_parser::ParseResult;
+use crate::value_parser::ParseState;
 use crate::value_parser::ValueParser;
 
 pub struct SequenceItemDataParser<T: Encoding> {
@@ -63,6 +64,9 @@ impl<T: 'static + Encoding> ValueParser<T> for SequenceItemDataParser<T> {
             // if the parse was cancelled due to hitting the item delimitation item,
             // we are complete
             Ok(ParseResult::completed(parse_result.bytes_consumed + 8))
+        } else if parse_result.state == ParseState::Cancelled {
+            // the caller's handler cancelled so the item is not complete yet
+            Ok(parse_result)
         } else if self.total_bytes_consumed == self.item_length {
             // if we have a known length and have consumed all the bytes, we are complete
             Ok(ParseResult::completed(parse_result.bytes_consumed))
@@ -88,15 +92,15 @@ mod tests {
 
     fn make_sequence_item_undefined_length() -> Vec<u8> {
         let mut bytes = vec![];
-        bytes.extend_from_slice(&vec![0x02, 0x00, 0x00, 0x00, b'U', b'L', 4, 0, 0, 0, 0, 0]);
-        bytes.extend_from_slice(&vec![0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0x02, 0x00, 0x00, 0x00, b'U', b'L', 4, 0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0xFE, 0xFF, 0x0D, 0xE0, 0, 0, 0, 0]);
 
         bytes
     }
 
     fn make_sequence_item_known_length() -> Vec<u8> {
         let mut bytes = vec![];
-        bytes.extend_from_slice(&vec![0x02, 0x00, 0x00, 0x00, b'U', b'L', 4, 0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&[0x02, 0x00, 0x00, 0x00, b'U', b'L', 4, 0, 0, 0, 0, 0]);
 
         bytes
     }
@@ -110,6 +114,7 @@ mod tests {
             tag: Tag::new(0x0008, 0x0008),
             vr: None,
             length: 0,
+            vr_inferred: false,
         };
         match parser.parse(&mut handler, &attribute, &bytes[..], 0) {
             Ok(result) => {
@@ -129,6 +134,7 @@ mod tests {
             tag: Tag::new(0x0008, 0x0008),
             vr: None,
             length: 0,
+            vr_inferred: false,
         };
         match parser.parse(&mut handler, &attribute, &bytes[..1], 0) {
             Ok(result) => {
@@ -148,6 +154,7 @@ mod tests {
             tag: Tag::new(0x0008, 0x0008),
             vr: None,
             length: 0,
+            vr_inferred: false,
         };
         match parser.parse(&mut handler, &attribute, &bytes[..], 0) {
             Ok(result) => {
@@ -167,6 +174,7 @@ mod tests {
             tag: Tag::new(0x0008, 0x0008),
             vr: None,
             length: 0,
+            vr_inferred: false,
         };
         match parser.parse(&mut handler, &attribute, &bytes[0..1], 0) {
             Ok(result) => {
@@ -188,6 +196,7 @@ mod tests {
             tag: Tag::new(0x0008, 0x0008),
             vr: None,
             length: 0,
+            vr_inferred: false,
         };
         match parser.parse(&mut handler, &attribute, &bytes[0..13], 0) {
             Ok(result) => {
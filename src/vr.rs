@@ -80,11 +80,25 @@ impl VR {
         }
     }
 
+    /// Returns true if the VR has a 32 bit length field (preceded by two
+    /// reserved bytes) in explicit VR encodings per PS3.5 Table 7.1-1
     pub fn explicit_length_is_u32(vr: VR) -> bool {
-        match vr {
-            VR::OW | VR::OB | VR::SQ | VR::OF | VR::UT | VR::UN => true,
-            _ => false,
-        }
+        matches!(
+            vr,
+            VR::OB
+                | VR::OD
+                | VR::OF
+                | VR::OL
+                | VR::OV
+                | VR::OW
+                | VR::SQ
+                | VR::SV
+                | VR::UC
+                | VR::UN
+                | VR::UR
+                | VR::UT
+                | VR::UV
+        )
     }
 }
 
@@ -111,10 +125,62 @@ mod tests {
 
     #[test]
     fn explicit_length_is_u32_returns_true() {
-        assert_eq!(true, VR::explicit_length_is_u32(VR::OW));
+        assert!(VR::explicit_length_is_u32(VR::OW));
     }
     #[test]
     fn explicit_length_is_u32_returns_false() {
-        assert_eq!(false, VR::explicit_length_is_u32(VR::CS));
+        assert!(!VR::explicit_length_is_u32(VR::CS));
+    }
+    #[test]
+    fn explicit_length_is_u32_returns_true_for_all_u32_vrs() {
+        for vr in [
+            VR::OB,
+            VR::OD,
+            VR::OF,
+            VR::OL,
+            VR::OV,
+            VR::OW,
+            VR::SQ,
+            VR::SV,
+            VR::UC,
+            VR::UN,
+            VR::UR,
+            VR::UT,
+            VR::UV,
+        ]
+        .iter()
+        {
+            assert!(VR::explicit_length_is_u32(*vr), "{:?}", vr);
+        }
+    }
+    #[test]
+    fn explicit_length_is_u32_returns_false_for_all_u16_vrs() {
+        for vr in [
+            VR::AE,
+            VR::AS,
+            VR::AT,
+            VR::CS,
+            VR::DA,
+            VR::DS,
+            VR::DT,
+            VR::FD,
+            VR::FL,
+            VR::IS,
+            VR::LO,
+            VR::LT,
+            VR::PN,
+            VR::SH,
+            VR::SL,
+            VR::SS,
+            VR::ST,
+            VR::TM,
+            VR::UI,
+            VR::UL,
+            VR::US,
+        ]
+        .iter()
+        {
+            assert!(!VR::explicit_length_is_u32(*vr), "{:?}", vr);
+        }
     }
 }